@@ -4,15 +4,25 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode},
 };
 use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::Duration;
-use whis_core::{Settings, TranscriptionProvider};
+use whis_core::settings::CustomEndpointConfig;
+use whis_core::{FallbackProvider, Settings, TranscriptionProvider};
 
 /// Configuration for transcription, including provider, API key, and language
 pub struct TranscriptionConfig {
     pub provider: TranscriptionProvider,
     pub api_key: String,
     pub language: Option<String>,
+    /// Model override for a cloud provider (None = the provider's default)
+    pub model: Option<String>,
+    /// Providers tried per chunk when the primary provider fails
+    pub fallbacks: Vec<FallbackProvider>,
+    /// Endpoint for the custom provider (set when it's the primary or a fallback)
+    pub custom_endpoint: Option<CustomEndpointConfig>,
+    /// Stream local providers (sliding-window decoding) instead of chunking
+    pub local_streaming: bool,
 }
 
 /// Load transcription config with optional language override
@@ -31,6 +41,7 @@ pub fn load_transcription_config_with_language(
 
     // Handle different provider types:
     // - Cloud providers: require API key
+    // - Custom endpoint: requires base URL, API key optional
     // - LocalWhisper: requires model path
     let api_key = match &provider {
         TranscriptionProvider::LocalWhisper => {
@@ -67,6 +78,27 @@ pub fn load_transcription_config_with_language(
                 }
             }
         }
+        TranscriptionProvider::Custom => {
+            // Custom endpoint: base URL is required, API key is optional
+            if settings
+                .transcription
+                .custom_endpoint
+                .transcription_url()
+                .is_none()
+            {
+                eprintln!("Error: No custom endpoint URL configured.");
+                eprintln!("(Required for Custom Endpoint transcription)");
+                eprintln!("\nSet the endpoint with:");
+                eprintln!("  whis config custom-url http://localhost:8000/v1");
+                eprintln!("  whis config custom-model whisper-1\n");
+                eprintln!("Tip: Run 'whis setup cloud' for guided setup.");
+                std::process::exit(1);
+            }
+            settings
+                .transcription
+                .api_key_for(&provider)
+                .unwrap_or_default()
+        }
         _ => {
             // Cloud providers: require API key
             match settings.transcription.api_key_for(&provider) {
@@ -96,10 +128,28 @@ pub fn load_transcription_config_with_language(
         }
     };
 
+    // Model override for the active provider (fallbacks carry their own)
+    let model = settings
+        .transcription
+        .model_override(&provider)
+        .map(str::to_string);
+
+    // Fallbacks that can't be used (missing key, URL, or model) are skipped
+    let fallbacks = settings.transcription.fallback_chain();
+    let uses_custom = provider == TranscriptionProvider::Custom
+        || fallbacks
+            .iter()
+            .any(|f| f.provider == TranscriptionProvider::Custom);
+    let custom_endpoint = uses_custom.then(|| settings.transcription.custom_endpoint.clone());
+
     Ok(TranscriptionConfig {
         provider,
         api_key, // For local-whisper this is model path
         language,
+        model,
+        fallbacks,
+        custom_endpoint,
+        local_streaming: settings.transcription.local_models.streaming,
     })
 }
 
@@ -108,17 +158,17 @@ pub fn load_transcription_config() -> Result<TranscriptionConfig> {
     load_transcription_config_with_language(None)
 }
 
-/// Wait for user to stop recording via Enter key.
+/// Wait for user to stop recording via Enter key, or until `cancel` is set.
 /// In TTY mode: waits for Enter key press.
-/// In non-TTY mode: blocks indefinitely (use --duration for timed recording).
-pub fn wait_for_stop() -> Result<()> {
+/// In non-TTY mode: blocks until cancelled (use --duration for timed recording).
+pub fn wait_for_stop(cancel: &AtomicBool) -> Result<()> {
     std::io::stdout().flush()?;
 
     if std::io::stdin().is_terminal() {
         // TTY mode: wait for Enter key
         enable_raw_mode()?;
 
-        loop {
+        while !cancel.load(Ordering::Relaxed) {
             // Check for Enter key with timeout (50ms polling)
             if event::poll(Duration::from_millis(50))?
                 && let Event::Key(key_event) = event::read()?
@@ -130,10 +180,10 @@ pub fn wait_for_stop() -> Result<()> {
 
         disable_raw_mode()?;
     } else {
-        // Non-TTY mode: wait indefinitely
+        // Non-TTY mode: wait until cancelled (e.g. by auto-stop)
         // Use --duration for timed recording in non-interactive environments
-        loop {
-            thread::sleep(Duration::from_secs(3600));
+        while !cancel.load(Ordering::Relaxed) {
+            thread::sleep(Duration::from_millis(100));
         }
     }
 
@@ -26,13 +26,52 @@ fn parse_duration(s: &str) -> Result<Duration, String> {
     }
 }
 
+/// Format of audio piped to `--file -`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StdinFormat {
+    /// Self-describing container (WAV, MP3, FLAC, OGG Vorbis, ...), probed from the data
+    #[default]
+    Auto,
+    /// Headerless PCM with the given layout
+    Raw(whis_core::RawPcmFormat),
+}
+
+impl std::str::FromStr for StdinFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().eq_ignore_ascii_case("auto") {
+            Ok(Self::Auto)
+        } else {
+            s.parse().map(Self::Raw)
+        }
+    }
+}
+
+impl std::fmt::Display for StdinFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Raw(format) => write!(f, "{format}"),
+        }
+    }
+}
+
 /// Input options for transcription
 #[derive(Args)]
 pub struct InputOptions {
     /// Transcribe an audio file instead of recording from microphone
-    /// Supported formats: WAV
+    /// Supported formats: WAV, MP3, FLAC, OGG Vorbis, M4A, MP4, MKV
+    /// (Opus in OGG/WebM with the opus-decoder build feature)
+    /// Use "-" to read audio piped to stdin
     #[arg(short = 'f', long, value_name = "PATH", value_hint = ValueHint::FilePath)]
     pub file: Option<std::path::PathBuf>,
+
+    /// Format of audio on stdin (with --file -)
+    /// "auto" for WAV/MP3/FLAC/OGG streams, or raw PCM as ENCODING[:RATE[:CHANNELS]]
+    /// e.g. "s16le" (16kHz mono), "s16le:48000:2", "f32le:44100"
+    #[arg(long, value_name = "FORMAT", default_value = "auto", requires = "file")]
+    pub stdin_format: StdinFormat,
 }
 
 /// Processing options for transcription
@@ -55,10 +94,25 @@ pub struct ProcessingOptions {
     #[arg(long)]
     pub no_vad: bool,
 
+    /// Stop recording automatically when you stop talking
+    /// Trailing silence and no-speech timeout come from 'whis config auto-stop-*'
+    #[arg(long)]
+    pub auto_stop: bool,
+
     /// Language code for transcription (e.g., "en", "de", "fr", "auto")
     /// Overrides the configured language for this invocation only
     #[arg(short = 'l', long)]
     pub language: Option<String>,
+
+    /// Label speakers in the transcript ("Speaker 1: ...")
+    /// Supported by deepgram and elevenlabs
+    #[arg(long)]
+    pub diarize: bool,
+
+    /// Translate speech to English
+    /// Native with openai, groq and local-whisper; other providers use the post-processor
+    #[arg(long)]
+    pub translate: bool,
 }
 
 /// Output format for transcription
@@ -71,6 +125,8 @@ pub enum OutputFormat {
     Srt,
     /// WebVTT subtitle format
     Vtt,
+    /// JSON with the text and timed segments
+    Json,
 }
 
 impl OutputFormat {
@@ -79,9 +135,20 @@ impl OutputFormat {
         match path.extension().and_then(|e| e.to_str()) {
             Some("srt") => Some(Self::Srt),
             Some("vtt") => Some(Self::Vtt),
+            Some("json") => Some(Self::Json),
             _ => None,
         }
     }
+
+    /// File extension written for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Txt => "txt",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Json => "json",
+        }
+    }
 }
 
 /// Output options for transcription results
@@ -95,9 +162,82 @@ pub struct OutputOptions {
     #[arg(short = 'o', long, value_name = "PATH", value_hint = ValueHint::FilePath)]
     pub output: Option<std::path::PathBuf>,
 
-    /// Output format (txt, srt, vtt)
+    /// Output format (txt, srt, vtt, json)
+    #[arg(long, value_enum, default_value = "txt")]
+    pub format: OutputFormat,
+}
+
+/// Options for `whis batch`
+#[derive(Args)]
+pub struct BatchOptions {
+    /// Directory or glob pattern of audio files (quote globs: "calls/*.m4a")
+    #[arg(value_name = "DIR|GLOB", value_hint = ValueHint::AnyPath)]
+    pub input: String,
+
+    /// Directory for transcripts and the batch manifest
+    #[arg(long, value_name = "DIR", value_hint = ValueHint::DirPath)]
+    pub out: std::path::PathBuf,
+
+    /// Formats written per file (comma-separated)
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "txt,srt,vtt,json"
+    )]
+    pub format: Vec<OutputFormat>,
+
+    /// Number of files transcribed at the same time
+    #[arg(short = 'j', long, default_value_t = 2)]
+    pub jobs: usize,
+
+    /// Transcribe files again even if the manifest lists them as done
+    #[arg(long)]
+    pub force: bool,
+
+    /// Language code for transcription (e.g., "en", "de", "fr", "auto")
+    #[arg(short = 'l', long)]
+    pub language: Option<String>,
+
+    /// Label speakers in the transcript ("Speaker 1: ...")
+    #[arg(long)]
+    pub diarize: bool,
+
+    /// Translate speech to English
+    #[arg(long)]
+    pub translate: bool,
+}
+
+/// Options for `whis watch`
+#[derive(Args)]
+pub struct WatchOptions {
+    /// Directory to watch for new audio files
+    #[arg(value_name = "DIR", value_hint = ValueHint::DirPath)]
+    pub dir: std::path::PathBuf,
+
+    /// Write transcripts into this directory instead of next to each file
+    #[arg(long, value_name = "DIR", value_hint = ValueHint::DirPath)]
+    pub out: Option<std::path::PathBuf>,
+
+    /// Transcript format
     #[arg(long, value_enum, default_value = "txt")]
     pub format: OutputFormat,
+
+    /// Output preset for transcripts (default: the active preset from settings)
+    #[arg(long = "as", value_name = "PRESET")]
+    pub preset: Option<String>,
+
+    /// Post-process transcripts with LLM (also on when enabled in settings)
+    #[arg(long)]
+    pub post_process: bool,
+
+    /// Language code for transcription (e.g., "en", "de", "fr", "auto")
+    #[arg(short = 'l', long)]
+    pub language: Option<String>,
+
+    /// Ignore files already in the directory when starting
+    #[arg(long)]
+    pub new_only: bool,
 }
 
 #[derive(Parser)]
@@ -138,6 +278,10 @@ pub enum Commands {
         /// Output preset for transcript (run 'whis preset list' to see all)
         #[arg(long = "as", value_name = "PRESET")]
         preset: Option<String>,
+
+        /// Continuous dictation: output each utterance until toggled off
+        #[arg(long)]
+        continuous: bool,
     },
 
     /// Stop the background service
@@ -152,6 +296,10 @@ pub enum Commands {
         /// Output preset for transcript (run 'whis preset list' to see all)
         #[arg(long = "as", value_name = "PRESET")]
         preset: Option<String>,
+
+        /// Continuous dictation: output each utterance until toggled off
+        #[arg(long)]
+        continuous: bool,
     },
 
     /// Check service status
@@ -191,6 +339,18 @@ pub enum Commands {
         #[command(subcommand)]
         action: Option<ModelAction>,
     },
+
+    /// Voice activity detection tools
+    Vad {
+        #[command(subcommand)]
+        action: VadAction,
+    },
+
+    /// Transcribe a directory or glob of audio files
+    Batch(BatchOptions),
+
+    /// Watch a directory and transcribe new audio files as they appear
+    Watch(WatchOptions),
 }
 
 #[derive(Subcommand)]
@@ -227,6 +387,48 @@ pub enum PresetAction {
     },
 }
 
+#[derive(Subcommand)]
+pub enum VadAction {
+    /// Print per-frame speech probabilities for a recording or file
+    Test(VadTestOptions),
+}
+
+/// Options for `whis vad test` (unset tuning values come from settings)
+#[derive(Args, Debug)]
+pub struct VadTestOptions {
+    /// Replay an audio file instead of recording from the microphone
+    #[arg(short, long, value_hint = ValueHint::FilePath)]
+    pub file: Option<std::path::PathBuf>,
+
+    /// How long to record from the microphone (e.g., "10s", "30s")
+    #[arg(short, long, value_parser = parse_duration, default_value = "10s")]
+    pub duration: Duration,
+
+    /// Start from a preset's VAD tuning (run 'whis preset list' to see all)
+    #[arg(long = "as", value_name = "PRESET")]
+    pub preset: Option<String>,
+
+    /// VAD engine (auto, silero, energy)
+    #[arg(long)]
+    pub engine: Option<whis_core::VadEngine>,
+
+    /// Speech probability threshold (0.0-1.0)
+    #[arg(long)]
+    pub threshold: Option<f32>,
+
+    /// Frames (32ms each) kept from before speech is confirmed
+    #[arg(long)]
+    pub prefill: Option<usize>,
+
+    /// Consecutive speech frames required to confirm speech
+    #[arg(long)]
+    pub onset: Option<usize>,
+
+    /// Frames (32ms each) kept after speech stops
+    #[arg(long)]
+    pub hangover: Option<usize>,
+}
+
 #[derive(Subcommand)]
 pub enum ModelAction {
     /// List available models
@@ -0,0 +1,589 @@
+//! Batch Command - transcribe many files into a directory
+//!
+//! `whis batch <dir|glob> --out <dir>` transcribes every supported audio file
+//! with the configured provider, a few files at a time, and writes one
+//! transcript per requested format (`<stem>.txt`, `.srt`, `.vtt`, `.json`).
+//!
+//! Finished files are recorded in `<out>/whis-batch.json`, keyed by the
+//! SHA-256 of their contents, together with the provider, model and options
+//! (language, speaker labels, translation, vocabulary) used. Re-running a
+//! batch skips files whose transcripts are already there and were made the
+//! same way, so an interrupted or partially failed run can simply be started
+//! again. Files with identical contents are transcribed once and each gets
+//! its own copy of the transcripts.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::app;
+use crate::args::{BatchOptions, OutputFormat};
+use crate::commands::record::{self, pipeline};
+
+/// Manifest file written to the output directory
+const MANIFEST_FILE: &str = "whis-batch.json";
+
+/// Files completed by earlier runs
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    /// Completed files keyed by SHA-256 of their contents
+    files: BTreeMap<String, ManifestEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestEntry {
+    /// Input path at the time it was transcribed
+    source: PathBuf,
+    /// Provider that produced the transcript
+    provider: String,
+    /// Model configured for the provider (None = provider default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+    /// Language, diarize, translate and vocabulary (see `options_key`)
+    #[serde(default)]
+    options: String,
+    /// Transcript file names in the output directory
+    outputs: Vec<String>,
+    /// Unix timestamp of completion
+    completed_at: u64,
+}
+
+impl Manifest {
+    fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid batch manifest: {}", path.display()))
+    }
+
+    /// Write via a temp file so an interrupted run never leaves it truncated
+    fn save(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Whether `hash` was transcribed the way `run` asks for and all of
+    /// `outputs` still exist
+    fn is_done(&self, hash: &str, run: &RunKey, outputs: &[String], out_dir: &Path) -> bool {
+        self.files.get(hash).is_some_and(|entry| {
+            entry.provider == run.provider
+                && entry.model == run.model
+                && entry.options == run.options
+                && outputs
+                    .iter()
+                    .all(|name| entry.outputs.contains(name) && out_dir.join(name).exists())
+        })
+    }
+}
+
+/// What a transcript depends on besides the audio
+struct RunKey {
+    provider: String,
+    model: Option<String>,
+    options: String,
+}
+
+/// A file to transcribe
+struct Job {
+    path: PathBuf,
+    hash: String,
+    /// Output file stem (input file name without extension)
+    stem: String,
+    /// Stems of other inputs with the same contents (get the same transcripts)
+    copies: Vec<String>,
+}
+
+/// Settings shared by all jobs
+struct BatchContext {
+    transcription: app::TranscriptionConfig,
+    vocabulary: Vec<String>,
+    diarize: bool,
+    translate: bool,
+    formats: Vec<OutputFormat>,
+    out_dir: PathBuf,
+}
+
+pub fn run(options: BatchOptions) -> Result<()> {
+    let inputs = collect_inputs(&options.input)?;
+
+    fs::create_dir_all(&options.out)
+        .with_context(|| format!("Failed to create {}", options.out.display()))?;
+    let manifest_path = options.out.join(MANIFEST_FILE);
+    let mut manifest = Manifest::load(&manifest_path)?;
+
+    let mut formats: Vec<OutputFormat> = Vec::new();
+    for format in &options.format {
+        if !formats.contains(format) {
+            formats.push(*format);
+        }
+    }
+    let transcription = app::load_transcription_config_with_language(options.language.clone())?;
+
+    if options.diarize && !transcription.provider.supports_diarization() {
+        eprintln!(
+            "Warning: {} does not support speaker labels, ignoring --diarize",
+            transcription.provider.display_name()
+        );
+    }
+
+    let settings = whis_core::Settings::load_cli();
+    let translate = options.translate || settings.transcription.translate_for(None);
+    if translate
+        && (!transcription.provider.supports_translation()
+            || whis_core::is_realtime_provider(&transcription.provider, false))
+    {
+        // Fail before transcribing anything rather than on every file
+        whis_core::resolve_post_processor_config(&None, &settings).with_context(|| {
+            format!(
+                "{} can't translate natively, translation needs a post-processor",
+                transcription.provider.display_name()
+            )
+        })?;
+    }
+
+    // Local models transcribe one file at a time
+    let jobs = if transcription.provider.is_local() {
+        1
+    } else {
+        options.jobs.max(1)
+    };
+
+    let vocabulary = settings.transcription.vocabulary_for(None);
+    let run_key = RunKey {
+        provider: transcription.provider.to_string(),
+        model: settings.transcription.model_for(&transcription.provider),
+        options: options_key(
+            transcription.language.as_deref(),
+            options.diarize,
+            translate,
+            &vocabulary,
+        ),
+    };
+
+    let (pending, skipped) = plan_jobs(
+        &inputs,
+        &manifest,
+        &formats,
+        &options.out,
+        &run_key,
+        options.force,
+    )?;
+    let total = pending.len();
+
+    println!(
+        "Transcribing {} of {} file(s) with {} ({} skipped)",
+        total,
+        inputs.len(),
+        transcription.provider.display_name(),
+        skipped
+    );
+    if total == 0 {
+        return Ok(());
+    }
+
+    let context = Arc::new(BatchContext {
+        transcription,
+        vocabulary,
+        diarize: options.diarize,
+        translate,
+        formats,
+        out_dir: options.out.clone(),
+    });
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let failures = runtime.block_on(async {
+        let mut queue = pending.into_iter();
+        let mut running = tokio::task::JoinSet::new();
+        // Input of each running task, to report a task that panicked
+        let mut running_paths: HashMap<tokio::task::Id, PathBuf> = HashMap::new();
+        let mut failures: Vec<(PathBuf, anyhow::Error)> = Vec::new();
+        let mut finished = 0;
+
+        loop {
+            // Keep up to `jobs` files in flight
+            while running.len() < jobs
+                && let Some(job) = queue.next()
+            {
+                let context = context.clone();
+                let path = job.path.clone();
+                let task = running.spawn(async move {
+                    let started = Instant::now();
+                    let result = transcribe_job(&job, &context).await;
+                    (job, result, started.elapsed())
+                });
+                running_paths.insert(task.id(), path);
+            }
+
+            let Some(joined) = running.join_next_with_id().await else {
+                break;
+            };
+            finished += 1;
+
+            // A panicking job fails its file, not the whole batch
+            let (job, result, elapsed) = match joined {
+                Ok((id, output)) => {
+                    running_paths.remove(&id);
+                    output
+                }
+                Err(err) => {
+                    let path = running_paths.remove(&err.id()).unwrap_or_default();
+                    eprintln!("[{finished}/{total}] {} failed: {err}", file_name(&path));
+                    failures.push((path, anyhow::anyhow!("Transcription task failed: {err}")));
+                    continue;
+                }
+            };
+
+            let name = file_name(&job.path);
+            match result {
+                Ok(outputs) => {
+                    println!(
+                        "[{finished}/{total}] {name} ({:.0}s)",
+                        elapsed.as_secs_f64()
+                    );
+                    manifest.files.insert(
+                        job.hash,
+                        ManifestEntry {
+                            source: job.path,
+                            provider: run_key.provider.clone(),
+                            model: run_key.model.clone(),
+                            options: run_key.options.clone(),
+                            outputs,
+                            completed_at: unix_now(),
+                        },
+                    );
+                    // Saved after every file so an interrupted run resumes here
+                    manifest.save(&manifest_path)?;
+                }
+                Err(err) => {
+                    eprintln!("[{finished}/{total}] {name} failed: {err}");
+                    failures.push((job.path, err));
+                }
+            }
+        }
+
+        anyhow::Ok(failures)
+    })?;
+
+    println!(
+        "Done: {} transcribed, {} skipped, {} failed",
+        total - failures.len(),
+        skipped,
+        failures.len()
+    );
+
+    if !failures.is_empty() {
+        eprintln!();
+        eprintln!("Failed files:");
+        for (path, err) in &failures {
+            eprintln!("  {}: {:#}", path.display(), err);
+        }
+        anyhow::bail!(
+            "{} of {} files failed (run the same command again to retry them)",
+            failures.len(),
+            total
+        );
+    }
+
+    Ok(())
+}
+
+/// Expand a directory or glob pattern into supported audio files (sorted)
+fn collect_inputs(input: &str) -> Result<Vec<PathBuf>> {
+    let dir = Path::new(input);
+    let mut files: Vec<PathBuf> = if dir.is_dir() {
+        fs::read_dir(dir)
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect()
+    } else {
+        glob::glob(input)
+            .with_context(|| format!("Invalid glob pattern: {input}"))?
+            .filter_map(Result::ok)
+            .collect()
+    };
+
+    files.retain(|path| path.is_file() && is_supported(path));
+    files.sort();
+
+    if files.is_empty() {
+        anyhow::bail!(
+            "No audio files found in {input}\nSupported: {}",
+            whis_core::audio::SUPPORTED_EXTENSIONS.join(", ")
+        );
+    }
+    Ok(files)
+}
+
+fn is_supported(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            whis_core::audio::SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str())
+        })
+}
+
+/// Hash the inputs and drop the ones already done
+///
+/// Files with identical contents become one job whose transcripts are also
+/// written under the copies' names. Returns the jobs to run and the number of
+/// files that won't be transcribed (done earlier, or copies). Fails if two
+/// different files would write the same transcript names.
+fn plan_jobs(
+    inputs: &[PathBuf],
+    manifest: &Manifest,
+    formats: &[OutputFormat],
+    out_dir: &Path,
+    run: &RunKey,
+    force: bool,
+) -> Result<(Vec<Job>, usize)> {
+    let mut jobs: Vec<Job> = Vec::new();
+    let mut by_hash: HashMap<String, usize> = HashMap::new();
+    let mut stems: HashMap<String, &Path> = HashMap::new();
+
+    for path in inputs {
+        let hash = hash_file(path)?;
+
+        let stem = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        if let Some(other) = stems.insert(stem.clone(), path) {
+            anyhow::bail!(
+                "{} and {} would both write {}.*\nRename one of them or batch them separately",
+                other.display(),
+                path.display(),
+                stem
+            );
+        }
+
+        // Identical copies only need transcribing once
+        if let Some(&index) = by_hash.get(&hash) {
+            whis_core::verbose!("{} has the same content as an earlier file", path.display());
+            jobs[index].copies.push(stem);
+            continue;
+        }
+
+        by_hash.insert(hash.clone(), jobs.len());
+        jobs.push(Job {
+            path: path.clone(),
+            hash,
+            stem,
+            copies: Vec::new(),
+        });
+    }
+
+    if !force {
+        jobs.retain(|job| {
+            let done = manifest.is_done(&job.hash, run, &output_names_for(job, formats), out_dir);
+            if done {
+                whis_core::verbose!("Skipping {} (already transcribed)", job.path.display());
+            }
+            !done
+        });
+    }
+
+    let skipped = inputs.len() - jobs.len();
+    Ok((jobs, skipped))
+}
+
+/// Transcribe one file and write its transcripts
+///
+/// Returns the names of the files written.
+async fn transcribe_job(job: &Job, context: &BatchContext) -> Result<Vec<String>> {
+    let transcription = record::transcribe_file(
+        &job.path,
+        &context.transcription,
+        context.diarize,
+        &context.vocabulary,
+        context.translate,
+        true,
+    )
+    .await?;
+
+    // Only translation applies here (no LLM cleanup or presets in batches)
+    let processing = pipeline::ProcessingConfig {
+        enabled: false,
+        preset: None,
+        translate: context.translate,
+    };
+    let processed = pipeline::process(transcription, &processing, true).await?;
+
+    let mut names = Vec::new();
+    for stem in std::iter::once(&job.stem).chain(&job.copies) {
+        for format in &context.formats {
+            let name = output_name(stem, *format);
+            let path = context.out_dir.join(&name);
+            fs::write(&path, pipeline::format_text(&processed, *format))
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            names.push(name);
+        }
+    }
+
+    Ok(names)
+}
+
+fn output_name(stem: &str, format: OutputFormat) -> String {
+    format!("{stem}.{}", format.extension())
+}
+
+/// Transcript names of a job, including its copies
+fn output_names_for(job: &Job, formats: &[OutputFormat]) -> Vec<String> {
+    std::iter::once(&job.stem)
+        .chain(&job.copies)
+        .flat_map(|stem| formats.iter().map(move |format| output_name(stem, *format)))
+        .collect()
+}
+
+/// Transcription options a manifest entry is only valid for
+fn options_key(
+    language: Option<&str>,
+    diarize: bool,
+    translate: bool,
+    vocabulary: &[String],
+) -> String {
+    format!("{:?}|{}|{}|{:?}", language, diarize, translate, vocabulary)
+}
+
+/// SHA-256 of a file's contents (hex)
+fn hash_file(path: &Path) -> Result<String> {
+    let mut file =
+        fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fresh temp directory with `files` (name, contents) in it
+    fn dir_with(name: &str, files: &[(&str, &str)]) -> (PathBuf, Vec<PathBuf>) {
+        let dir = std::env::temp_dir().join(format!("whis-batch-{}-{name}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        let paths = files
+            .iter()
+            .map(|(file, contents)| {
+                let path = dir.join(file);
+                fs::write(&path, contents).unwrap();
+                path
+            })
+            .collect();
+        (dir, paths)
+    }
+
+    fn run_key(options: &str) -> RunKey {
+        RunKey {
+            provider: "openai".into(),
+            model: None,
+            options: options.into(),
+        }
+    }
+
+    #[test]
+    fn identical_files_become_one_job_with_copies() {
+        let (dir, inputs) = dir_with(
+            "copies",
+            &[("a.wav", "same"), ("b.wav", "same"), ("c.wav", "other")],
+        );
+        let (jobs, skipped) = plan_jobs(
+            &inputs,
+            &Manifest::default(),
+            &[OutputFormat::Txt],
+            &dir,
+            &run_key(""),
+            false,
+        )
+        .unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[0].stem, "a");
+        assert_eq!(jobs[0].copies, ["b"]);
+        assert!(jobs[1].copies.is_empty());
+        assert_eq!(skipped, 1);
+        assert_eq!(
+            output_names_for(&jobs[0], &[OutputFormat::Txt, OutputFormat::Srt]),
+            ["a.txt", "a.srt", "b.txt", "b.srt"]
+        );
+    }
+
+    #[test]
+    fn same_stem_in_different_formats_is_rejected() {
+        let (dir, inputs) = dir_with("stems", &[("memo.m4a", "one"), ("memo.mp3", "two")]);
+        let result = plan_jobs(
+            &inputs,
+            &Manifest::default(),
+            &[OutputFormat::Txt],
+            &dir,
+            &run_key(""),
+            false,
+        );
+        fs::remove_dir_all(&dir).ok();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn skips_only_files_done_with_the_same_options() {
+        let (dir, inputs) = dir_with("skip", &[("a.wav", "audio"), ("a.txt", "transcript")]);
+        let inputs = &inputs[..1];
+        let mut manifest = Manifest::default();
+        manifest.files.insert(
+            hash_file(&inputs[0]).unwrap(),
+            ManifestEntry {
+                source: inputs[0].clone(),
+                provider: "openai".into(),
+                model: None,
+                options: options_key(None, false, false, &[]),
+                outputs: vec!["a.txt".into()],
+                completed_at: 0,
+            },
+        );
+        let plan = |run: &RunKey, formats: &[OutputFormat], force: bool| {
+            plan_jobs(inputs, &manifest, formats, &dir, run, force)
+                .unwrap()
+                .0
+                .len()
+        };
+
+        let same = run_key(&options_key(None, false, false, &[]));
+        let diarized = run_key(&options_key(None, true, false, &[]));
+        let german = run_key(&options_key(Some("de"), false, false, &[]));
+        let vocabulary = run_key(&options_key(None, false, false, &["Whis".into()]));
+        assert_eq!(plan(&same, &[OutputFormat::Txt], false), 0);
+        assert_eq!(plan(&same, &[OutputFormat::Txt], true), 1);
+        assert_eq!(plan(&same, &[OutputFormat::Srt], false), 1);
+        assert_eq!(plan(&diarized, &[OutputFormat::Txt], false), 1);
+        assert_eq!(plan(&german, &[OutputFormat::Txt], false), 1);
+        assert_eq!(plan(&vocabulary, &[OutputFormat::Txt], false), 1);
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result, anyhow};
 use whis_core::defaults::{DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL};
-use whis_core::settings::CliShortcutMode;
-use whis_core::{PostProcessor, Preset, Settings, TranscriptionProvider};
+use whis_core::settings::{CliShortcutMode, CustomAuthStyle};
+use whis_core::{PostProcessor, Preset, Settings, TranscriptionProvider, UploadFormat, VadEngine};
 
 use crate::ui::mask_key;
 
@@ -9,13 +9,30 @@ use crate::ui::mask_key;
 const VALID_KEYS: &[&str] = &[
     "provider",
     "language",
+    "vocabulary",
+    "fallback-providers",
+    "translate",
     "openai-api-key",
     "mistral-api-key",
     "groq-api-key",
     "deepgram-api-key",
     "elevenlabs-api-key",
+    "custom-api-key",
+    "openai-model",
+    "openai-realtime-model",
+    "mistral-model",
+    "groq-model",
+    "deepgram-model",
+    "deepgram-realtime-model",
+    "elevenlabs-model",
+    "custom-url",
+    "custom-model",
+    "custom-auth",
+    "custom-auth-header",
+    "custom-timestamps",
     "whisper-model-path",
     "parakeet-model-path",
+    "local-streaming",
     "post-processor",
     "post-processing-prompt",
     "ollama-url",
@@ -26,8 +43,21 @@ const VALID_KEYS: &[&str] = &[
     "cli-push-to-talk",
     "desktop-key",
     "vad",
+    "vad-engine",
     "vad-threshold",
+    "vad-prefill",
+    "vad-onset",
+    "vad-hangover",
+    "denoise",
+    "denoise-strength",
+    "auto-stop",
+    "auto-stop-silence",
+    "auto-stop-timeout",
+    "continuous",
+    "continuous-file",
     "chunk-size",
+    "chunk-concurrency",
+    "upload-format",
 ];
 
 pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -> Result<()> {
@@ -100,6 +130,43 @@ fn set_config(key: &str, value: &str) -> Result<()> {
                 println!("language = {}", lang_lower);
             }
         }
+        "vocabulary" => {
+            // Comma-separated list; "none" (or empty) clears it
+            settings.transcription.vocabulary = if value_trimmed.eq_ignore_ascii_case("none") {
+                Vec::new()
+            } else {
+                let terms: Vec<String> = value_trimmed.split(',').map(String::from).collect();
+                whis_core::settings::merge_vocabulary(&[&terms])
+            };
+            println!(
+                "vocabulary = {}",
+                format_vocabulary(&settings.transcription.vocabulary)
+            );
+        }
+        "fallback-providers" => {
+            // Comma-separated, tried in order; "none" (or empty) clears it
+            settings.transcription.fallback_providers =
+                if value_trimmed.is_empty() || value_trimmed.eq_ignore_ascii_case("none") {
+                    Vec::new()
+                } else {
+                    value_trimmed
+                        .split(',')
+                        .map(|p| p.trim().parse::<TranscriptionProvider>())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| anyhow!("{}", e))?
+                };
+            println!(
+                "fallback-providers = {}",
+                format_providers(&settings.transcription.fallback_providers)
+            );
+        }
+        "translate" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.translate = enabled;
+            println!("translate = {}", enabled);
+        }
         "openai-api-key" => {
             if !value_trimmed.starts_with("sk-") {
                 anyhow::bail!("Invalid key format. OpenAI keys start with 'sk-'");
@@ -140,6 +207,76 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             );
             println!("elevenlabs-api-key = {}", mask_key(value_trimmed));
         }
+        "custom-api-key" => {
+            // Self-hosted endpoints use arbitrary key formats, so no format check
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid API key: cannot be empty");
+            }
+            settings
+                .transcription
+                .set_api_key(&TranscriptionProvider::Custom, value_trimmed.to_string());
+            println!("custom-api-key = {}", mask_key(value_trimmed));
+        }
+        "openai-model"
+        | "openai-realtime-model"
+        | "mistral-model"
+        | "groq-model"
+        | "deepgram-model"
+        | "deepgram-realtime-model"
+        | "elevenlabs-model" => {
+            let provider = model_key_provider(key);
+            // "default" (or empty) clears the override
+            let model = (!value_trimmed.is_empty()
+                && !value_trimmed.eq_ignore_ascii_case("default"))
+            .then(|| value_trimmed.to_string());
+            settings.transcription.set_model(&provider, model);
+            println!("{} = {}", key, format_model(&settings, &provider));
+            if let Some(model) = settings.transcription.model_override(&provider)
+                && !provider.known_models().contains(&model)
+            {
+                eprintln!(
+                    "Note: '{}' is not a known {} model, it will be sent as-is (known: {})",
+                    model,
+                    provider.display_name(),
+                    provider.known_models().join(", ")
+                );
+            }
+        }
+        "custom-url" => {
+            if !value_trimmed.starts_with("http://") && !value_trimmed.starts_with("https://") {
+                anyhow::bail!("Invalid custom URL: must start with http:// or https://");
+            }
+            settings.transcription.custom_endpoint.base_url = Some(value_trimmed.to_string());
+            println!("custom-url = {}", value_trimmed);
+        }
+        "custom-model" => {
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid custom model: cannot be empty");
+            }
+            settings.transcription.custom_endpoint.model = Some(value_trimmed.to_string());
+            println!("custom-model = {}", value_trimmed);
+        }
+        "custom-auth" => {
+            let auth: CustomAuthStyle = value_trimmed
+                .parse()
+                .map_err(|e: String| anyhow!("{}", e))?;
+            settings.transcription.custom_endpoint.auth = auth;
+            println!("custom-auth = {}", auth);
+        }
+        "custom-auth-header" => {
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid header name: cannot be empty");
+            }
+            settings.transcription.custom_endpoint.auth_header = Some(value_trimmed.to_string());
+            println!("custom-auth-header = {}", value_trimmed);
+        }
+        "custom-timestamps" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.custom_endpoint.timestamps = enabled;
+            println!("custom-timestamps = {}", enabled);
+        }
         "whisper-model-path" => {
             if value_trimmed.is_empty() {
                 anyhow::bail!("Invalid whisper model path: cannot be empty");
@@ -156,6 +293,13 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.transcription.local_models.parakeet_path = Some(expanded_path.clone());
             println!("parakeet-model-path = {}", expanded_path);
         }
+        "local-streaming" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.local_models.streaming = enabled;
+            println!("local-streaming = {}", enabled);
+        }
         "post-processor" => {
             let processor = value_trimmed
                 .parse::<PostProcessor>()
@@ -203,6 +347,19 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.ui.vad.enabled = enabled;
             println!("vad = {}", enabled);
         }
+        "vad-engine" => {
+            let engine: VadEngine = value_trimmed
+                .parse()
+                .map_err(|e: String| anyhow!("{}", e))?;
+            if !engine.is_available() {
+                println!(
+                    "Note: this build has no {} VAD, the energy VAD will be used",
+                    engine
+                );
+            }
+            settings.ui.vad.engine = engine;
+            println!("vad-engine = {}", engine);
+        }
         "vad-threshold" => {
             let threshold = value_trimmed
                 .parse::<f32>()
@@ -213,6 +370,89 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.ui.vad.threshold = threshold;
             println!("vad-threshold = {:.2}", threshold);
         }
+        "vad-prefill" => {
+            let frames = parse_vad_frames(value_trimmed, "prefill", 0..=60)?;
+            settings.ui.vad.prefill_frames = frames;
+            println!("vad-prefill = {}", format_vad_frames(frames));
+        }
+        "vad-onset" => {
+            let frames = parse_vad_frames(value_trimmed, "onset", 1..=10)?;
+            settings.ui.vad.onset_frames = frames;
+            println!("vad-onset = {}", format_vad_frames(frames));
+        }
+        "vad-hangover" => {
+            let frames = parse_vad_frames(value_trimmed, "hangover", 0..=60)?;
+            settings.ui.vad.hangover_frames = frames;
+            println!("vad-hangover = {}", format_vad_frames(frames));
+        }
+        "denoise" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.ui.denoise.enabled = enabled;
+            println!("denoise = {}", enabled);
+        }
+        "denoise-strength" => {
+            let strength = value_trimmed
+                .parse::<f32>()
+                .context("Invalid strength. Use a number between 0.0 and 1.0")?;
+            if !(0.0..=1.0).contains(&strength) {
+                anyhow::bail!("Invalid denoise strength: must be between 0.0 and 1.0");
+            }
+            settings.ui.denoise.strength = strength;
+            println!("denoise-strength = {:.2}", strength);
+        }
+        "auto-stop" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.ui.auto_stop.enabled = enabled;
+            println!("auto-stop = {}", enabled);
+        }
+        "auto-stop-silence" => {
+            let secs = value_trimmed
+                .trim_end_matches('s')
+                .parse::<f32>()
+                .context("Invalid silence. Use a number of seconds (e.g., 1.5)")?;
+            if !(0.5..=10.0).contains(&secs) {
+                anyhow::bail!("Invalid auto-stop silence: must be between 0.5 and 10 seconds");
+            }
+            settings.ui.auto_stop.silence_secs = secs;
+            println!("auto-stop-silence = {:.1}s", secs);
+        }
+        "auto-stop-timeout" => {
+            if matches!(value_trimmed, "off" | "none" | "0") {
+                settings.ui.auto_stop.no_speech_timeout_secs = None;
+                println!("auto-stop-timeout = off");
+            } else {
+                let secs = value_trimmed
+                    .trim_end_matches('s')
+                    .parse::<f32>()
+                    .context("Invalid timeout. Use a number of seconds or 'off'")?;
+                if secs <= 0.0 {
+                    anyhow::bail!("Invalid auto-stop timeout: must be positive (or 'off')");
+                }
+                settings.ui.auto_stop.no_speech_timeout_secs = Some(secs);
+                println!("auto-stop-timeout = {}s", secs);
+            }
+        }
+        "continuous" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.ui.continuous.enabled = enabled;
+            println!("continuous = {}", enabled);
+        }
+        "continuous-file" => {
+            if value_trimmed.is_empty() || matches!(value_trimmed, "none" | "off") {
+                settings.ui.continuous.output_file = None;
+                println!("continuous-file = (not set)");
+            } else {
+                let expanded_path = expand_home_dir(value_trimmed);
+                settings.ui.continuous.output_file = Some(expanded_path.clone().into());
+                println!("continuous-file = {}", expanded_path);
+            }
+        }
         "chunk-size" => {
             let size = value_trimmed
                 .parse::<u64>()
@@ -223,6 +463,34 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.ui.chunk_duration_secs = size;
             println!("chunk-size = {}s", size);
         }
+        "chunk-concurrency" => {
+            let concurrency = value_trimmed
+                .parse::<usize>()
+                .context("Invalid chunk concurrency. Use a number (e.g., 1, 3, 4)")?;
+            if !(1..=8).contains(&concurrency) {
+                anyhow::bail!("Invalid chunk concurrency: must be between 1 and 8");
+            }
+            settings.ui.chunk_concurrency = concurrency;
+            println!("chunk-concurrency = {}", concurrency);
+        }
+        "upload-format" => {
+            if value_trimmed.eq_ignore_ascii_case("auto") {
+                settings.transcription.upload_format = None;
+                println!("upload-format = auto");
+            } else {
+                let format: UploadFormat = value_trimmed
+                    .parse()
+                    .map_err(|e: String| anyhow!("{}", e))?;
+                if !format.is_available() {
+                    println!(
+                        "Note: this build has no {} encoder, uploads will use the automatic choice",
+                        format
+                    );
+                }
+                settings.transcription.upload_format = Some(format);
+                println!("upload-format = {}", format);
+            }
+        }
         "cli-mode" => {
             let mode: CliShortcutMode = value_trimmed
                 .parse()
@@ -273,11 +541,42 @@ fn get_config(key: &str) -> Result<()> {
             "{}",
             settings.transcription.language.as_deref().unwrap_or("auto")
         ),
+        "vocabulary" => println!("{}", format_vocabulary(&settings.transcription.vocabulary)),
+        "fallback-providers" => println!(
+            "{}",
+            format_providers(&settings.transcription.fallback_providers)
+        ),
+        "translate" => println!("{}", settings.transcription.translate),
         "openai-api-key" => print_api_key(&settings, &TranscriptionProvider::OpenAI),
         "mistral-api-key" => print_api_key(&settings, &TranscriptionProvider::Mistral),
         "groq-api-key" => print_api_key(&settings, &TranscriptionProvider::Groq),
         "deepgram-api-key" => print_api_key(&settings, &TranscriptionProvider::Deepgram),
         "elevenlabs-api-key" => print_api_key(&settings, &TranscriptionProvider::ElevenLabs),
+        "custom-api-key" => print_api_key(&settings, &TranscriptionProvider::Custom),
+        "openai-model"
+        | "openai-realtime-model"
+        | "mistral-model"
+        | "groq-model"
+        | "deepgram-model"
+        | "deepgram-realtime-model"
+        | "elevenlabs-model" => {
+            println!("{}", format_model(&settings, &model_key_provider(key)))
+        }
+        "custom-url" => println!(
+            "{}",
+            settings
+                .transcription
+                .custom_endpoint
+                .base_url
+                .as_deref()
+                .unwrap_or("(not set)")
+        ),
+        "custom-model" => println!("{}", settings.transcription.custom_endpoint.model()),
+        "custom-auth" => println!("{}", settings.transcription.custom_endpoint.auth),
+        "custom-auth-header" => {
+            println!("{}", settings.transcription.custom_endpoint.auth_header())
+        }
+        "custom-timestamps" => println!("{}", settings.transcription.custom_endpoint.timestamps),
         "whisper-model-path" => {
             if let Some(path) = &settings.transcription.local_models.whisper_path {
                 println!("{}", path);
@@ -292,6 +591,7 @@ fn get_config(key: &str) -> Result<()> {
                 println!("(not set, using $LOCAL_PARAKEET_MODEL_PATH)");
             }
         }
+        "local-streaming" => println!("{}", settings.transcription.local_models.streaming),
         "post-processor" => println!("{}", settings.post_processing.processor),
         "post-processing-prompt" => {
             if let Some(prompt) = &settings.post_processing.prompt {
@@ -322,8 +622,21 @@ fn get_config(key: &str) -> Result<()> {
             }
         }
         "vad" => println!("{}", settings.ui.vad.enabled),
+        "vad-engine" => println!("{}", settings.ui.vad.engine),
         "vad-threshold" => println!("{:.2}", settings.ui.vad.threshold),
+        "vad-prefill" => println!("{}", format_vad_frames(settings.ui.vad.prefill_frames)),
+        "vad-onset" => println!("{}", format_vad_frames(settings.ui.vad.onset_frames)),
+        "vad-hangover" => println!("{}", format_vad_frames(settings.ui.vad.hangover_frames)),
+        "denoise" => println!("{}", settings.ui.denoise.enabled),
+        "denoise-strength" => println!("{:.2}", settings.ui.denoise.strength),
+        "auto-stop" => println!("{}", settings.ui.auto_stop.enabled),
+        "auto-stop-silence" => println!("{:.1}s", settings.ui.auto_stop.silence_secs),
+        "auto-stop-timeout" => println!("{}", format_auto_stop_timeout(&settings)),
+        "continuous" => println!("{}", settings.ui.continuous.enabled),
+        "continuous-file" => println!("{}", format_continuous_file(&settings)),
         "chunk-size" => println!("{}s", settings.ui.chunk_duration_secs),
+        "chunk-concurrency" => println!("{}", settings.ui.chunk_concurrency),
+        "upload-format" => println!("{}", format_upload_format(&settings)),
         "cli-mode" => println!("{}", settings.shortcuts.cli_mode),
         "cli-key" => println!("{}", settings.shortcuts.cli_key),
         "cli-push-to-talk" => println!("{}", settings.shortcuts.cli_push_to_talk),
@@ -354,6 +667,15 @@ fn show_all_settings() -> Result<()> {
         "language = {}",
         settings.transcription.language.as_deref().unwrap_or("auto")
     );
+    println!(
+        "vocabulary = {}",
+        format_vocabulary(&settings.transcription.vocabulary)
+    );
+    println!(
+        "fallback-providers = {}",
+        format_providers(&settings.transcription.fallback_providers)
+    );
+    println!("translate = {}", settings.transcription.translate);
 
     for provider in TranscriptionProvider::all() {
         let key_name = format!(
@@ -373,6 +695,30 @@ fn show_all_settings() -> Result<()> {
         println!("{} = {}", key_name, key_status);
     }
 
+    println!();
+    println!("[Models]");
+    for provider in TranscriptionProvider::all() {
+        if provider.default_model().is_some() {
+            println!(
+                "{}-model = {}",
+                provider.as_str(),
+                format_model(&settings, provider)
+            );
+        }
+    }
+
+    println!();
+    println!("[Custom Endpoint]");
+    let custom = &settings.transcription.custom_endpoint;
+    println!(
+        "custom-url = {}",
+        custom.base_url.as_deref().unwrap_or("(not set)")
+    );
+    println!("custom-model = {}", custom.model());
+    println!("custom-auth = {}", custom.auth);
+    println!("custom-auth-header = {}", custom.auth_header());
+    println!("custom-timestamps = {}", custom.timestamps);
+
     println!();
     println!("[Local Models]");
     if let Some(path) = &settings.transcription.local_models.whisper_path {
@@ -386,6 +732,10 @@ fn show_all_settings() -> Result<()> {
     } else {
         println!("parakeet-model-path = (not set, using $LOCAL_PARAKEET_MODEL_PATH)");
     }
+    println!(
+        "local-streaming = {}",
+        settings.transcription.local_models.streaming
+    );
 
     println!();
     println!("[Post-Processing]");
@@ -416,15 +766,46 @@ fn show_all_settings() -> Result<()> {
     } else {
         println!("microphone-device = System Default");
     }
+    println!("denoise = {}", settings.ui.denoise.enabled);
+    println!("denoise-strength = {:.2}", settings.ui.denoise.strength);
 
     println!();
     println!("[Voice Activity Detection]");
     println!("vad = {}", settings.ui.vad.enabled);
+    println!("vad-engine = {}", settings.ui.vad.engine);
     println!("vad-threshold = {:.2}", settings.ui.vad.threshold);
+    println!(
+        "vad-prefill = {}",
+        format_vad_frames(settings.ui.vad.prefill_frames)
+    );
+    println!(
+        "vad-onset = {}",
+        format_vad_frames(settings.ui.vad.onset_frames)
+    );
+    println!(
+        "vad-hangover = {}",
+        format_vad_frames(settings.ui.vad.hangover_frames)
+    );
+    println!("auto-stop = {}", settings.ui.auto_stop.enabled);
+    println!(
+        "auto-stop-silence = {:.1}s",
+        settings.ui.auto_stop.silence_secs
+    );
+    println!(
+        "auto-stop-timeout = {}",
+        format_auto_stop_timeout(&settings)
+    );
+
+    println!();
+    println!("[Continuous Dictation]");
+    println!("continuous = {}", settings.ui.continuous.enabled);
+    println!("continuous-file = {}", format_continuous_file(&settings));
 
     println!();
     println!("[Audio Chunking]");
     println!("chunk-size = {}s", settings.ui.chunk_duration_secs);
+    println!("chunk-concurrency = {}", settings.ui.chunk_concurrency);
+    println!("upload-format = {}", format_upload_format(&settings));
 
     println!();
     println!("[Shortcuts]");
@@ -451,13 +832,116 @@ fn show_usage() {
     eprintln!("  whis config provider openai");
     eprintln!("  whis config openai-api-key sk-...");
     eprintln!("  whis config language en");
+    eprintln!("  whis config vocabulary \"whis, Tauri, Kubernetes\"");
+    eprintln!("  whis config fallback-providers groq,local-whisper");
+    eprintln!("  whis config translate true");
+    eprintln!("  whis config deepgram-model nova-3");
+    eprintln!("  whis config custom-url http://localhost:8000/v1");
     eprintln!("  whis config post-processor ollama");
     eprintln!("  whis config vad true");
+    eprintln!("  whis config vad-prefill 25");
+    eprintln!("  whis config vad-engine energy");
+    eprintln!("  whis config denoise true");
+    eprintln!("  whis config auto-stop true");
+    eprintln!("  whis config continuous-file ~/notes/dictation.txt");
     eprintln!("  whis config chunk-size 30");
+    eprintln!("  whis config upload-format opus");
     eprintln!();
     eprintln!("Run 'whis config --list' to see all available keys and current values");
 }
 
+/// No-speech timeout in seconds, or "off"
+fn format_auto_stop_timeout(settings: &Settings) -> String {
+    match settings.ui.auto_stop.no_speech_timeout_secs {
+        Some(secs) => format!("{secs}s"),
+        None => "off".to_string(),
+    }
+}
+
+/// File continuous utterances are appended to, or "(not set)"
+fn format_continuous_file(settings: &Settings) -> String {
+    match &settings.ui.continuous.output_file {
+        Some(path) => path.display().to_string(),
+        None => "(not set)".to_string(),
+    }
+}
+
+/// Length of one VAD frame (512 samples at 16kHz)
+const VAD_FRAME_MS: usize = 32;
+
+/// Parse a VAD frame count and check it against `range`
+fn parse_vad_frames(
+    value: &str,
+    name: &str,
+    range: std::ops::RangeInclusive<usize>,
+) -> Result<usize> {
+    let frames = value.parse::<usize>().with_context(|| {
+        format!(
+            "Invalid {name}. Use a number of frames ({}ms each)",
+            VAD_FRAME_MS
+        )
+    })?;
+    if !range.contains(&frames) {
+        anyhow::bail!(
+            "Invalid VAD {name}: must be between {} and {} frames",
+            range.start(),
+            range.end()
+        );
+    }
+    Ok(frames)
+}
+
+/// VAD frame count with its duration, e.g. "15 (480ms)"
+fn format_vad_frames(frames: usize) -> String {
+    format!("{} ({}ms)", frames, frames * VAD_FRAME_MS)
+}
+
+/// Configured upload format, or "auto" with the format the active provider gets
+fn format_upload_format(settings: &Settings) -> String {
+    match settings.transcription.upload_format {
+        Some(format) => format.to_string(),
+        None => format!(
+            "auto ({})",
+            whis_core::audio::upload_format_for(&settings.transcription.provider, None)
+        ),
+    }
+}
+
+/// Provider for a `<provider>-model` key
+fn model_key_provider(key: &str) -> TranscriptionProvider {
+    key.trim_end_matches("-model")
+        .parse()
+        .expect("Key validation should prevent this")
+}
+
+/// Current model for a provider, marking the default
+fn format_model(settings: &Settings, provider: &TranscriptionProvider) -> String {
+    match settings.transcription.model_override(provider) {
+        Some(model) => model.to_string(),
+        None => format!("{} (default)", provider.default_model().unwrap_or_default()),
+    }
+}
+
+fn format_vocabulary(terms: &[String]) -> String {
+    if terms.is_empty() {
+        "(none)".to_string()
+    } else {
+        terms.join(", ")
+    }
+}
+
+fn format_providers(providers: &[TranscriptionProvider]) -> String {
+    if providers.is_empty() {
+        "(none)".to_string()
+    } else {
+        providers
+            .iter()
+            .map(|p| p.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
 fn expand_home_dir(path: &str) -> String {
     if let Some(rest) = path.strip_prefix("~/")
         && let Some(home) = dirs::home_dir()
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod config;
 pub mod model;
 pub mod preset;
@@ -8,3 +9,5 @@ pub mod start;
 pub mod status;
 pub mod stop;
 pub mod toggle;
+pub mod vad;
+pub mod watch;
@@ -75,6 +75,34 @@ fn show(name: &str) -> Result<()> {
         }
     }
 
+    if !preset.vocabulary.is_empty() {
+        println!();
+        println!("Vocabulary:");
+        println!("  {}", preset.vocabulary.join(", "));
+    }
+
+    if preset.translate {
+        println!();
+        println!("Translate: to English");
+    }
+
+    if let Some(vad) = &preset.vad {
+        println!();
+        println!("VAD:");
+        if let Some(threshold) = vad.threshold {
+            println!("  Threshold: {:.2}", threshold);
+        }
+        if let Some(frames) = vad.prefill_frames {
+            println!("  Prefill: {} frames", frames);
+        }
+        if let Some(frames) = vad.onset_frames {
+            println!("  Onset: {} frames", frames);
+        }
+        if let Some(frames) = vad.hangover_frames {
+            println!("  Hangover: {} frames", frames);
+        }
+    }
+
     // Show file location for user presets
     if source == PresetSource::User {
         println!();
@@ -0,0 +1,78 @@
+//! Live transcript display for realtime providers
+//!
+//! While recording, partial and final segments are drawn on the status line
+//! after the "Recording..." label. Only the tail that fits the terminal width is
+//! shown, so the line never wraps and can be redrawn in place.
+
+use crossterm::{
+    cursor::MoveToColumn,
+    queue,
+    style::Print,
+    terminal::{self, Clear, ClearType},
+};
+use std::io::{IsTerminal, Write};
+use tokio::sync::mpsc;
+use whis_core::TranscriptEvent;
+
+/// Fallback width when the terminal size can't be queried
+const DEFAULT_WIDTH: usize = 80;
+
+/// Whether live output should be drawn (interactive stdout, not quiet or verbose)
+pub fn enabled(quiet: bool) -> bool {
+    !quiet && !whis_core::verbose::is_verbose() && std::io::stdout().is_terminal()
+}
+
+/// Render transcript events until the sender is dropped
+pub async fn render(mut events_rx: mpsc::UnboundedReceiver<TranscriptEvent>, label: String) {
+    let mut committed = String::new();
+
+    while let Some(event) = events_rx.recv().await {
+        let line = match event {
+            TranscriptEvent::Final(text) => {
+                if !committed.is_empty() {
+                    committed.push(' ');
+                }
+                committed.push_str(text.trim());
+                committed.clone()
+            }
+            TranscriptEvent::Partial(text) if committed.is_empty() => text,
+            TranscriptEvent::Partial(text) => format!("{committed} {text}"),
+        };
+        draw(&label, line.trim());
+    }
+}
+
+/// Restore the plain status label once recording stops
+pub fn finish(label: &str) {
+    draw(label, "");
+}
+
+fn draw(label: &str, text: &str) {
+    let width = terminal::size()
+        .map(|(cols, _)| cols as usize)
+        .unwrap_or(DEFAULT_WIDTH);
+    // Leave room for the label, a separator and the cursor
+    let available = width.saturating_sub(label.chars().count() + 2);
+
+    let mut stdout = std::io::stdout();
+    let _ = queue!(
+        stdout,
+        MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+        Print(label)
+    );
+    if !text.is_empty() && available > 1 {
+        let _ = queue!(stdout, Print(" "), Print(tail(text, available)));
+    }
+    let _ = stdout.flush();
+}
+
+/// Last `max` characters of `text`, prefixed with an ellipsis when truncated
+fn tail(text: &str, max: usize) -> String {
+    let len = text.chars().count();
+    if len <= max {
+        return text.to_string();
+    }
+    let skip = len - (max - 1);
+    format!("…{}", text.chars().skip(skip).collect::<String>())
+}
@@ -0,0 +1,104 @@
+//! Input level meter shown while recording
+//!
+//! Draws a level bar after the "Recording..." label and warns once if the
+//! microphone stays silent, which usually means it's muted or the wrong
+//! device is selected.
+
+use crossterm::{
+    cursor::MoveToColumn,
+    queue,
+    style::Print,
+    terminal::{Clear, ClearType},
+};
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+use tokio::sync::watch;
+use whis_core::{AudioLevel, SILENT_INPUT_WARNING_SECS};
+
+/// Bar width in characters
+const BAR_WIDTH: usize = 20;
+/// Lowest level shown on the bar (dBFS)
+const FLOOR_DB: f32 = -60.0;
+/// Minimum time between redraws
+const REDRAW_INTERVAL: Duration = Duration::from_millis(80);
+
+/// Whether the level bar should be drawn (interactive stdout, not quiet or verbose)
+pub fn bar_enabled(quiet: bool) -> bool {
+    !quiet && !whis_core::verbose::is_verbose() && std::io::stdout().is_terminal()
+}
+
+/// Follow the input level until the recording stops
+///
+/// The bar is only drawn when `draw_bar` is set (interactive stdout and no
+/// live transcript on the status line); the silent-mic warning is always shown.
+pub async fn render(mut level_rx: watch::Receiver<AudioLevel>, label: String, draw_bar: bool) {
+    let mut warned = false;
+
+    while level_rx.changed().await.is_ok() {
+        let level = *level_rx.borrow_and_update();
+
+        if !warned && level.mic_appears_silent() {
+            warned = true;
+            warn_silent(&label);
+        }
+        if draw_bar {
+            draw(&label, &level);
+        }
+
+        tokio::time::sleep(REDRAW_INTERVAL).await;
+    }
+}
+
+/// Restore the plain status label once recording stops
+pub fn finish(label: &str) {
+    let mut stdout = std::io::stdout();
+    let _ = queue!(
+        stdout,
+        MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+        Print(label)
+    );
+    let _ = stdout.flush();
+}
+
+fn draw(label: &str, level: &AudioLevel) {
+    let filled = (((level.rms_db() - FLOOR_DB) / -FLOOR_DB).clamp(0.0, 1.0) * BAR_WIDTH as f32)
+        .round() as usize;
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled));
+
+    let mut stdout = std::io::stdout();
+    let _ = queue!(
+        stdout,
+        MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+        Print(label),
+        Print(" "),
+        Print(bar)
+    );
+    let _ = stdout.flush();
+}
+
+/// Print the warning on its own line, then restore the status label
+fn warn_silent(label: &str) {
+    let message = format!(
+        "Warning: no input from the microphone for {SILENT_INPUT_WARNING_SECS:.0}s. \
+         Is it muted, or is the wrong device selected? (whis config microphone-device)"
+    );
+
+    if !std::io::stdout().is_terminal() {
+        eprintln!("{message}");
+        return;
+    }
+
+    // The terminal may be in raw mode (waiting for Enter), so end lines with \r\n
+    let mut stdout = std::io::stdout();
+    let _ = queue!(
+        stdout,
+        MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+        Print(message),
+        Print("\r\n"),
+        Print(label)
+    );
+    let _ = stdout.flush();
+}
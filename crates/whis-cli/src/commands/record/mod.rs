@@ -22,7 +22,8 @@
 //!
 //! # Pipeline Phases
 //!
-//! 1. **Record Phase** (`modes/`): Capture audio from microphone with VAD
+//! 1. **Record Phase** (`modes/`): Capture audio from microphone with VAD,
+//!    or read a file / stdin (`-f -`, streamed into the chunker as it arrives)
 //!
 //! 2. **Transcribe Phase**: Progressive transcription
 //!    - Audio chunked into ~90s segments with overlap
@@ -45,14 +46,19 @@
 //! - VAD settings and hotkeys
 //! - Clipboard method
 
+#[cfg(feature = "realtime")]
+mod live;
+mod meter;
 mod modes;
-mod pipeline;
+pub(crate) mod pipeline;
 mod types;
 
 // Re-export public types for external use
 pub use types::RecordConfig;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::app;
 
@@ -67,17 +73,70 @@ pub fn run(config: RecordConfig) -> Result<()> {
     let transcription_config =
         app::load_transcription_config_with_language(config.language.clone())?;
 
-    // Branch: file transcription vs microphone recording
-    let transcription_result = if let Some(ref input_file) = config.input_file {
+    if config.diarize && !transcription_config.provider.supports_diarization() {
+        eprintln!(
+            "Warning: {} does not support speaker labels, ignoring --diarize",
+            transcription_config.provider.display_name()
+        );
+    }
+
+    // Global vocabulary plus the preset's terms
+    let settings = whis_core::Settings::load_cli();
+    let vocabulary = settings
+        .transcription
+        .vocabulary_for(config.preset.as_ref());
+
+    // --translate, the translate setting, or a translating preset
+    let translate =
+        config.translate || settings.transcription.translate_for(config.preset.as_ref());
+    let provider = &transcription_config.provider;
+    // Cloud realtime APIs never translate; local streaming does while decoding
+    let realtime_cloud = whis_core::is_realtime_provider(provider, false);
+    if translate && (!provider.supports_translation() || realtime_cloud) {
+        // Check the LLM fallback before recording so a dictation isn't lost
+        whis_core::resolve_post_processor_config(&config.preset, &settings).with_context(|| {
+            format!(
+                "{} can't translate natively, translation needs a post-processor",
+                provider.display_name()
+            )
+        })?;
+    }
+
+    // Branch: stdin vs file transcription vs microphone recording
+    let transcription_result = if let Some(ref input_file) = config.input_file
+        && modes::stdin::is_stdin(input_file)
+    {
+        // Stdin mode: transcribe piped audio as it arrives
+        runtime.block_on(transcribe_stdin(
+            config.stdin_format,
+            &transcription_config,
+            config.diarize,
+            &vocabulary,
+            translate,
+            quiet,
+        ))?
+    } else if let Some(ref input_file) = config.input_file {
         // File transcription mode
-        runtime.block_on(transcribe_file(input_file, &transcription_config, quiet))?
+        runtime.block_on(transcribe_file(
+            input_file,
+            &transcription_config,
+            config.diarize,
+            &vocabulary,
+            translate,
+            quiet,
+        ))?
     } else {
         // Microphone: Record and transcribe concurrently (streaming)
         let mic_config = modes::MicrophoneConfig {
             duration: config.duration,
             no_vad: config.no_vad,
+            auto_stop: config.auto_stop,
             provider: transcription_config.provider.clone(),
             will_post_process: config.post_process || config.preset.is_some(),
+            diarize: config.diarize,
+            vocabulary,
+            translate,
+            vad: settings.ui.vad.for_preset(config.preset.as_ref()),
         };
         runtime.block_on(progressive_record_and_transcribe(
             mic_config,
@@ -86,10 +145,18 @@ pub fn run(config: RecordConfig) -> Result<()> {
         ))?
     };
 
+    // Reported after the status line is complete (see below)
+    let detected_language = transcription_result
+        .detected_language
+        .clone()
+        .filter(|_| transcription_config.language.is_none())
+        .map(|language| (language, transcription_result.language_confidence));
+
     // Phase 3: Post-process and apply presets
     let processing_cfg = pipeline::ProcessingConfig {
         enabled: config.post_process,
         preset: config.preset,
+        translate,
     };
     let processed_result = runtime.block_on(pipeline::process(
         transcription_result,
@@ -102,6 +169,28 @@ pub fn run(config: RecordConfig) -> Result<()> {
         println!(" Done.");
     }
 
+    // Auto-detected language goes to stderr so --print output stays clean
+    if let Some((language, confidence)) = detected_language {
+        let confidence = confidence
+            .map(|c| format!(" ({:.0}%)", c * 100.0))
+            .unwrap_or_default();
+        eprintln!("Detected language: {language}{confidence}");
+    }
+
+    // Which provider transcribed each chunk (shows where fallbacks kicked in)
+    if whis_core::verbose::is_verbose() && !processed_result.chunks.is_empty() {
+        eprintln!("Chunks:");
+        for chunk in &processed_result.chunks {
+            eprintln!(
+                "  {:>3}  {:>7.1}s - {:>7.1}s  {}",
+                chunk.index + 1,
+                chunk.start,
+                chunk.end,
+                chunk.provider
+            );
+        }
+    }
+
     // Phase 4: Output (print, file, type to window, or clipboard)
     let output_mode = if config.print {
         pipeline::OutputMode::Print
@@ -129,23 +218,33 @@ async fn progressive_record_and_transcribe(
     quiet: bool,
 ) -> Result<types::TranscriptionResult> {
     use tokio::sync::mpsc;
-    #[cfg(feature = "local-transcription")]
-    use whis_core::progressive_transcribe_local;
     use whis_core::{
-        AudioRecorder, ChunkerConfig, ProgressiveChunker, Settings, TranscriptionProvider,
-        WarmupConfig, progressive_transcribe_cloud, warmup_configured,
+        AudioRecorder, ChunkerConfig, CloudTranscriptionOptions, Settings, WarmupConfig,
+        warmup_configured,
     };
 
     // Check if this is a realtime provider (for branching later)
-    let is_realtime = whis_core::is_realtime_provider(&transcription_config.provider);
+    let is_realtime = whis_core::is_realtime_provider(
+        &transcription_config.provider,
+        transcription_config.local_streaming,
+    );
 
     // Create recorder
     let mut recorder = AudioRecorder::new()?;
 
     // Configure VAD (disabled for realtime - they handle silence detection)
     let settings = Settings::load_cli();
-    let vad_enabled = settings.ui.vad.enabled && !mic_config.no_vad && !is_realtime;
-    recorder.set_vad(vad_enabled, settings.ui.vad.threshold);
+    let vad_enabled = mic_config.vad.enabled && !mic_config.no_vad && !is_realtime;
+    recorder.set_vad_config(whis_core::VadConfig {
+        enabled: vad_enabled,
+        ..mic_config.vad.config()
+    });
+    recorder.set_denoise(settings.ui.denoise.enabled, settings.ui.denoise.strength);
+
+    // Stop by itself after the speaker is done (--auto-stop or settings)
+    let mut auto_stop = settings.ui.auto_stop.config();
+    auto_stop.enabled |= mic_config.auto_stop;
+    recorder.set_auto_stop(auto_stop);
 
     // Preload models in background (same as batch mode)
     preload_models(&mic_config);
@@ -204,25 +303,69 @@ async fn progressive_record_and_transcribe(
         }
     });
 
+    // Status label shown while recording (live transcripts are drawn after it)
+    let recording_label = match mic_config.duration {
+        Some(dur) => format!("Recording for {} seconds...", dur.as_secs()),
+        None => "Recording...".to_string(),
+    };
+
+    // Level bar and silent-mic warning (the bar would fight live transcripts
+    // for the status line, so realtime providers only get the warning)
+    let meter_task = match recorder.level_receiver() {
+        Some(level_rx) if !quiet => {
+            let draw_bar = meter::bar_enabled(quiet) && !is_realtime;
+            Some((
+                tokio::spawn(meter::render(level_rx, recording_label.clone(), draw_bar)),
+                draw_bar,
+            ))
+        }
+        _ => None,
+    };
+
     // Branch based on provider type: realtime streaming vs chunked progressive
-    let (transcription_task, chunker_task): (
-        tokio::task::JoinHandle<anyhow::Result<String>>,
+    let (transcription_task, chunker_task, live_task): (
+        tokio::task::JoinHandle<anyhow::Result<whis_core::TranscriptionResult>>,
         Option<tokio::task::JoinHandle<anyhow::Result<()>>>,
+        Option<tokio::task::JoinHandle<()>>,
     ) = if is_realtime {
         // REALTIME PATH: Stream audio directly to WebSocket (no chunking)
         #[cfg(feature = "realtime")]
         {
-            let realtime_backend = whis_core::get_realtime_backend(&transcription_config.provider)?;
+            let realtime_backend = whis_core::get_realtime_backend(
+                &transcription_config.provider,
+                transcription_config.model.clone(),
+                &mic_config.vocabulary,
+                mic_config.translate,
+                transcription_config.local_streaming,
+            )?;
             let api_key = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
 
+            // Show partial/final segments on the status line while speaking
+            let (events_tx, live_task) = if live::enabled(quiet) {
+                let (events_tx, events_rx) = mpsc::unbounded_channel();
+                let live_task = tokio::spawn(live::render(events_rx, recording_label.clone()));
+                (Some(events_tx), Some(live_task))
+            } else {
+                (None, None)
+            };
+
             let task = tokio::spawn(async move {
                 realtime_backend
-                    .transcribe_stream(&api_key, audio_rx_unbounded, language)
+                    .transcribe_stream_with_events(
+                        &api_key,
+                        audio_rx_unbounded,
+                        language,
+                        events_tx,
+                    )
                     .await
+                    .map(|text| whis_core::TranscriptionResult {
+                        translated: realtime_backend.translates(),
+                        ..whis_core::TranscriptionResult::from_text(text)
+                    })
             });
 
-            (task, None) // No chunker task for realtime
+            (task, None, live_task) // No chunker task for realtime
         }
 
         #[cfg(not(feature = "realtime"))]
@@ -234,8 +377,6 @@ async fn progressive_record_and_transcribe(
         }
     } else {
         // NON-REALTIME PATH: Use chunking + progressive transcription
-        let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
-
         // Create chunker config from settings
         let target = settings.ui.chunk_duration_secs;
         let chunker_config = ChunkerConfig {
@@ -245,81 +386,118 @@ async fn progressive_record_and_transcribe(
             vad_aware: vad_enabled,
         };
 
-        // Spawn chunker task
-        let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
-        let chunker_task = tokio::spawn(async move {
-            chunker
-                .consume_stream(audio_rx_unbounded, None)
-                .await
-                .map_err(|e| anyhow::anyhow!(e))
-        });
-
-        // Spawn transcription task based on provider
-        let transcription_task = {
-            let provider = transcription_config.provider.clone();
-            let api_key = transcription_config.api_key.clone();
-            let language = transcription_config.language.clone();
-
-            tokio::spawn(async move {
-                #[cfg(feature = "local-transcription")]
-                if provider == TranscriptionProvider::LocalParakeet {
-                    // Local Parakeet progressive transcription
-                    let model_path = Settings::load_cli()
-                        .transcription
-                        .parakeet_model_path()
-                        .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
+        let options = CloudTranscriptionOptions {
+            language: transcription_config.language.clone(),
+            model: transcription_config.model.clone(),
+            custom_endpoint: transcription_config.custom_endpoint.clone(),
+            diarize: mic_config.diarize,
+            vocabulary: mic_config.vocabulary.clone(),
+            fallbacks: transcription_config.fallbacks.clone(),
+            concurrency: settings.ui.chunk_concurrency,
+            translate: mic_config.translate,
+            upload_format: settings.transcription.upload_format,
+        };
 
-                    return progressive_transcribe_local(&model_path, chunk_rx, None).await;
-                }
+        let (transcription_task, chunker_task) = spawn_progressive_transcription(
+            audio_rx_unbounded,
+            chunker_config,
+            transcription_config,
+            options,
+        );
 
-                // Cloud provider progressive transcription
-                progressive_transcribe_cloud(
-                    &provider,
-                    &api_key,
-                    language.as_deref(),
-                    chunk_rx,
-                    None,
-                )
-                .await
-            })
-        };
+        (transcription_task, Some(chunker_task), None)
+    };
 
-        (transcription_task, Some(chunker_task))
+    // Resolves when auto-stop detects the end of speech (never if disabled)
+    let auto_stop_rx = recorder.auto_stop_receiver();
+    let auto_stopped = async move {
+        if let Some(mut rx) = auto_stop_rx
+            && let Ok(reason) = rx.wait_for(Option::is_some).await
+        {
+            return *reason;
+        }
+        std::future::pending().await
     };
 
-    // Wait for recording to complete (user input or duration)
-    if let Some(dur) = mic_config.duration {
+    // Wait for recording to complete (user input, duration or auto-stop)
+    let auto_stop_reason = if let Some(dur) = mic_config.duration {
         // Timed recording
         if !quiet {
             if whis_core::verbose::is_verbose() {
-                println!("Recording for {} seconds...", dur.as_secs());
+                println!("{recording_label}");
             } else {
-                print!("Recording for {} seconds...", dur.as_secs());
+                print!("{recording_label}");
                 use std::io::Write;
                 std::io::stdout().flush()?;
             }
         }
-        tokio::time::sleep(dur).await;
+        tokio::select! {
+            _ = tokio::time::sleep(dur) => None,
+            reason = auto_stopped => reason,
+        }
     } else {
         // Interactive mode
         if !quiet {
-            println!("Press Enter to stop");
+            if auto_stop.enabled {
+                println!("Press Enter to stop (or just stop talking)");
+            } else {
+                println!("Press Enter to stop");
+            }
             if whis_core::verbose::is_verbose() {
-                println!("Recording...");
+                println!("{recording_label}");
             } else {
-                print!("Recording...");
+                print!("{recording_label}");
                 use std::io::Write;
                 std::io::stdout().flush()?;
             }
         }
 
-        // Wait for user to stop (blocking operation)
-        tokio::task::spawn_blocking(app::wait_for_stop).await??;
+        // Wait for user to stop (blocking operation, cancelled by auto-stop)
+        let cancel = Arc::new(AtomicBool::new(false));
+        let mut wait = tokio::task::spawn_blocking({
+            let cancel = cancel.clone();
+            move || app::wait_for_stop(&cancel)
+        });
+
+        tokio::select! {
+            result = &mut wait => {
+                result??;
+                None
+            }
+            reason = auto_stopped => {
+                // Let the key wait restore the terminal before continuing
+                cancel.store(true, Ordering::Relaxed);
+                wait.await??;
+                reason
+            }
+        }
+    };
+
+    if let Some(reason) = auto_stop_reason {
+        whis_core::verbose!("Recording stopped automatically: {:?}", reason);
     }
 
     // Stop recording (closes audio stream, signals chunker/realtime to finish)
     recorder.stop_recording()?;
 
+    if let Some((meter_task, draw_bar)) = meter_task {
+        meter_task.abort();
+        if draw_bar {
+            meter::finish(&recording_label);
+        }
+    }
+
+    // Keep drawing segments transcribed after the stream closed; the event
+    // channel (and so the live task) ends once the provider is done. Then clear
+    // the live transcript so the status line reads as before.
+    #[cfg(feature = "realtime")]
+    if let Some(live_task) = live_task {
+        let _ = live_task.await;
+        live::finish(&recording_label);
+    }
+    #[cfg(not(feature = "realtime"))]
+    drop(live_task);
+
     // Wait for chunker to finish (only for non-realtime path)
     if let Some(chunker_task) = chunker_task {
         chunker_task.await??;
@@ -330,9 +508,68 @@ async fn progressive_record_and_transcribe(
         app::print_status(" Transcribing...", Some(&transcription_config.provider));
     }
 
-    let text = transcription_task.await??;
+    let result = transcription_task.await??;
+
+    Ok(result.into())
+}
+
+/// Chunk an audio stream and transcribe chunks as they're produced
+///
+/// Shared by microphone recording and stdin input. Returns the transcription
+/// task and the chunker task; both finish once `audio_rx` is closed.
+fn spawn_progressive_transcription(
+    audio_rx: tokio::sync::mpsc::UnboundedReceiver<Vec<f32>>,
+    chunker_config: whis_core::ChunkerConfig,
+    transcription_config: &app::TranscriptionConfig,
+    options: whis_core::CloudTranscriptionOptions,
+) -> (
+    tokio::task::JoinHandle<Result<whis_core::TranscriptionResult>>,
+    tokio::task::JoinHandle<Result<()>>,
+) {
+    use whis_core::audio::upload_format_for;
+    #[cfg(feature = "local-transcription")]
+    use whis_core::progressive_transcribe_local;
+    use whis_core::{ProgressiveChunker, progressive_transcribe_cloud};
+
+    let (chunk_tx, chunk_rx) = tokio::sync::mpsc::unbounded_channel();
+    let provider = transcription_config.provider.clone();
+
+    // Spawn chunker task (cloud chunks are encoded while they're recorded)
+    let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
+    if !provider.is_local() {
+        chunker =
+            chunker.with_streaming_encoder(upload_format_for(&provider, options.upload_format));
+    }
+    let chunker_task = tokio::spawn(async move {
+        chunker
+            .consume_stream(audio_rx, None)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    });
+
+    // Spawn transcription task based on provider
+    let api_key = transcription_config.api_key.clone();
+    let transcription_task = tokio::spawn(async move {
+        #[cfg(feature = "local-transcription")]
+        if provider.is_local() {
+            // Local progressive transcription (api_key holds the model path)
+            return progressive_transcribe_local(
+                &provider,
+                &api_key,
+                options.language.as_deref(),
+                &options.vocabulary,
+                options.translate,
+                chunk_rx,
+                None,
+            )
+            .await;
+        }
+
+        // Cloud provider progressive transcription
+        progressive_transcribe_cloud(&provider, &api_key, options, chunk_rx, None).await
+    });
 
-    Ok(types::TranscriptionResult { text })
+    (transcription_task, chunker_task)
 }
 
 /// Preload models in background to reduce latency (extracted from MicrophoneMode)
@@ -366,13 +603,97 @@ fn preload_models(config: &modes::MicrophoneConfig) {
     }
 }
 
+/// Transcribe audio piped to stdin (`whis -f -`)
+///
+/// Audio is streamed into the progressive chunker while it's read, so long
+/// pipes (e.g. `parec`) are transcribed as they go rather than buffered whole.
+async fn transcribe_stdin(
+    format: crate::args::StdinFormat,
+    transcription_config: &app::TranscriptionConfig,
+    diarize: bool,
+    vocabulary: &[String],
+    translate: bool,
+    quiet: bool,
+) -> Result<types::TranscriptionResult> {
+    use whis_core::{ChunkerConfig, CloudTranscriptionOptions};
+
+    let (audio_rx, mut reader_task) = modes::stdin::spawn_reader(format, quiet)?;
+
+    if !quiet {
+        eprintln!("Transcribing stdin ({format})...");
+    }
+
+    // Fixed-duration chunks like file mode
+    let settings = whis_core::Settings::load_cli();
+    let target = settings.ui.chunk_duration_secs;
+    let chunker_config = ChunkerConfig {
+        target_duration_secs: target,
+        min_duration_secs: target,
+        max_duration_secs: target,
+        vad_aware: false,
+    };
+
+    let options = CloudTranscriptionOptions {
+        language: transcription_config.language.clone(),
+        model: transcription_config.model.clone(),
+        custom_endpoint: transcription_config.custom_endpoint.clone(),
+        diarize,
+        vocabulary: vocabulary.to_vec(),
+        fallbacks: transcription_config.fallbacks.clone(),
+        concurrency: settings.ui.chunk_concurrency,
+        translate,
+        upload_format: settings.transcription.upload_format,
+    };
+
+    let (mut transcription_task, chunker_task) =
+        spawn_progressive_transcription(audio_rx, chunker_config, transcription_config, options);
+
+    // The reader ends at EOF or Ctrl+C, but a provider failure shouldn't wait
+    // for the pipe to close: whichever task finishes first decides. A
+    // transcription error is also more useful than the chunker's "channel
+    // closed" that follows it.
+    let result = tokio::select! {
+        read = &mut reader_task => {
+            read??;
+            transcription_task.await??
+        }
+        transcribed = &mut transcription_task => {
+            let result = match transcribed {
+                Ok(Ok(result)) => result,
+                failed => {
+                    reader_task.abort();
+                    failed??
+                }
+            };
+            reader_task.await??;
+            result
+        }
+    };
+    chunker_task.await??;
+
+    if !quiet {
+        eprintln!("Done.");
+    }
+
+    Ok(result.into())
+}
+
 /// Transcribe an audio file
-async fn transcribe_file(
+///
+/// Also used by `whis batch` for each file of a batch.
+pub(crate) async fn transcribe_file(
     input_file: &std::path::Path,
     transcription_config: &app::TranscriptionConfig,
+    diarize: bool,
+    vocabulary: &[String],
+    translate: bool,
     quiet: bool,
 ) -> Result<types::TranscriptionResult> {
-    use whis_core::{TranscriptionProvider, http::get_http_client, provider::TranscriptionRequest};
+    use std::io::IsTerminal;
+    use whis_core::{
+        CloudTranscriptionOptions, TranscriptionCheckpoint, TranscriptionProvider,
+        transcribe_chunks_resumable,
+    };
 
     if !quiet {
         eprintln!(
@@ -385,7 +706,7 @@ async fn transcribe_file(
     let samples = modes::file::read_audio_file(input_file)?;
 
     // Handle local vs cloud providers differently
-    let text = match &transcription_config.provider {
+    let result = match &transcription_config.provider {
         #[cfg(feature = "local-transcription")]
         TranscriptionProvider::LocalParakeet => {
             let model_path = whis_core::Settings::load_cli()
@@ -397,45 +718,74 @@ async fn transcribe_file(
                 whis_core::provider::transcribe_raw_parakeet(&model_path, samples)
             })
             .await??
-            .text
         }
 
         #[cfg(feature = "local-transcription")]
         TranscriptionProvider::LocalWhisper => {
             let model_path = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
+            let vocabulary = vocabulary.to_vec();
             tokio::task::spawn_blocking(move || {
-                whis_core::provider::transcribe_raw(&model_path, &samples, language.as_deref())
+                whis_core::provider::transcribe_raw(
+                    &model_path,
+                    &samples,
+                    language.as_deref(),
+                    &vocabulary,
+                    translate,
+                )
             })
             .await??
-            .text
         }
 
         _ => {
-            // Cloud providers: encode to MP3 and send
-            let encoder = whis_core::audio::create_encoder();
-            let mp3_data =
-                encoder.encode_samples(&samples, whis_core::resample::WHISPER_SAMPLE_RATE)?;
+            // Cloud providers: split into chunks and transcribe several at once
+            let settings = whis_core::Settings::load_cli();
+            let chunks = whis_core::chunk_samples(&samples, settings.ui.chunk_duration_secs);
 
-            let client = get_http_client()?;
-            let provider =
-                whis_core::provider::registry().get_by_kind(&transcription_config.provider)?;
-
-            let request = TranscriptionRequest {
-                audio_data: mp3_data,
+            let options = CloudTranscriptionOptions {
                 language: transcription_config.language.clone(),
-                filename: format!(
-                    "{}.mp3",
-                    input_file.file_stem().unwrap_or_default().to_string_lossy()
-                ),
-                mime_type: "audio/mpeg".to_string(),
-                progress: None,
+                model: transcription_config.model.clone(),
+                custom_endpoint: transcription_config.custom_endpoint.clone(),
+                diarize,
+                vocabulary: vocabulary.to_vec(),
+                fallbacks: transcription_config.fallbacks.clone(),
+                concurrency: settings.ui.chunk_concurrency,
+                translate,
+                upload_format: settings.transcription.upload_format,
             };
 
-            provider
-                .transcribe_async(client, &transcription_config.api_key, request)
-                .await?
-                .text
+            // Finished chunks are checkpointed so a failed run resumes where it stopped
+            let checkpoint = match TranscriptionCheckpoint::open(
+                &samples,
+                &transcription_config.provider,
+                &options,
+            ) {
+                Ok(checkpoint) => Some(checkpoint),
+                Err(e) => {
+                    whis_core::verbose!("Transcription checkpoints disabled: {:#}", e);
+                    None
+                }
+            };
+            drop(samples);
+
+            let show_progress = !quiet
+                && chunks.len() > 1
+                && !whis_core::verbose::is_verbose()
+                && std::io::stderr().is_terminal();
+            let progress = show_progress.then(chunk_progress);
+            let result = transcribe_chunks_resumable(
+                &transcription_config.provider,
+                &transcription_config.api_key,
+                options,
+                chunks,
+                checkpoint,
+                progress,
+            )
+            .await;
+            if show_progress {
+                eprintln!();
+            }
+            result?
         }
     };
 
@@ -443,5 +793,60 @@ async fn transcribe_file(
         eprintln!("Done.");
     }
 
-    Ok(types::TranscriptionResult { text })
+    Ok(result.into())
+}
+
+/// Progress line for chunked file transcription ("12/40 chunks, ETA 3m20s")
+///
+/// The ETA only counts chunks finished in this run, so chunks resumed from a
+/// checkpoint don't make it look faster than it is.
+fn chunk_progress() -> Box<dyn Fn(usize, usize) + Send + Sync> {
+    use crossterm::{
+        cursor::MoveToColumn,
+        queue,
+        style::Print,
+        terminal::{Clear, ClearType},
+    };
+    use std::io::Write;
+
+    let started = std::time::Instant::now();
+    let resumed = std::sync::OnceLock::new();
+
+    Box::new(move |completed, total| {
+        // The first call reports what the checkpoint already had
+        let resumed = *resumed.get_or_init(|| {
+            if completed > 0 {
+                eprintln!("Resuming: {completed} of {total} chunks already transcribed");
+            }
+            completed
+        });
+
+        let done = completed - resumed;
+        let eta = if done == 0 || completed >= total {
+            String::new()
+        } else {
+            let remaining =
+                started.elapsed().as_secs_f64() / done as f64 * (total - completed) as f64;
+            format!(", ETA {}", format_eta(remaining))
+        };
+
+        let mut stderr = std::io::stderr();
+        let _ = queue!(
+            stderr,
+            MoveToColumn(0),
+            Clear(ClearType::CurrentLine),
+            Print(format!(" {completed}/{total} chunks{eta}"))
+        );
+        let _ = stderr.flush();
+    })
+}
+
+/// Format a duration estimate as "45s", "3m20s" or "1h05m"
+fn format_eta(secs: f64) -> String {
+    let secs = secs.round() as u64;
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+    }
 }
@@ -1,7 +1,7 @@
 //! Microphone recording configuration
 
 use std::time::Duration;
-use whis_core::TranscriptionProvider;
+use whis_core::{TranscriptionProvider, VadSettings};
 
 /// Microphone recording configuration
 #[derive(Debug, Clone)]
@@ -10,12 +10,22 @@ pub struct MicrophoneConfig {
     pub duration: Option<Duration>,
     /// Disable VAD
     pub no_vad: bool,
+    /// Stop when the speaker is done (in addition to the auto-stop setting)
+    pub auto_stop: bool,
     /// Provider (for preloading)
     pub provider: TranscriptionProvider,
     /// Whether post-processing will be used (for preloading)
     pub will_post_process: bool,
+    /// Request speaker labels from the provider
+    pub diarize: bool,
+    /// Terms to bias recognition towards
+    pub vocabulary: Vec<String>,
+    /// Translate to English (natively where supported)
+    pub translate: bool,
+    /// VAD settings with the preset's overrides applied
+    pub vad: VadSettings,
 }
 
 // Note: MicrophoneMode has been removed as microphone recording now exclusively
 // uses the progressive transcription path (see progressive_record_and_transcribe
-// in commands/record/mod.rs). The batch recording path only handles file/stdin.
+// in commands/record/mod.rs). File and stdin input are handled in their own modes.
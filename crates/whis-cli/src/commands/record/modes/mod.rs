@@ -1,8 +1,9 @@
 //! Recording mode strategies
 //!
-//! Supports microphone recording and file transcription.
+//! Supports microphone recording, file transcription and stdin input.
 
 pub mod file;
 pub mod microphone;
+pub mod stdin;
 
 pub use microphone::MicrophoneConfig;
@@ -0,0 +1,83 @@
+//! Stdin transcription mode
+//!
+//! Streams audio piped to `whis -f -` into the progressive chunker as it
+//! arrives, e.g.:
+//!
+//! ```text
+//! parec --format=s16le --rate=16000 --channels=1 | whis -f - --stdin-format s16le
+//! ffmpeg -i talk.mkv -f s16le -ar 16000 -ac 1 - | whis -f - --stdin-format s16le
+//! sox talk.wav -t raw -e float -b 32 -r 44100 -c 2 - | whis -f - --stdin-format f32le:44100:2
+//! ffmpeg -i talk.mkv -f wav - | whis -f -
+//! ```
+
+use anyhow::{Result, anyhow};
+use std::io::IsTerminal;
+use std::path::Path;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+use crate::args::StdinFormat;
+
+/// Whether `--file` names stdin ("-")
+pub fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Start reading stdin
+///
+/// Returns a receiver of 16kHz mono blocks and the reader task. The receiver
+/// closes at EOF or on Ctrl+C, so audio read so far is still transcribed.
+/// Reading runs on a plain thread: a read blocked on a stalled pipe can't be
+/// cancelled, and a detached thread doesn't hold up shutdown.
+pub fn spawn_reader(
+    format: StdinFormat,
+    quiet: bool,
+) -> Result<(mpsc::UnboundedReceiver<Vec<f32>>, JoinHandle<Result<()>>)> {
+    if std::io::stdin().is_terminal() {
+        anyhow::bail!(
+            "No audio piped to stdin\nExample: ffmpeg -i talk.mkv -f s16le -ar 16000 -ac 1 - | whis -f - --stdin-format s16le"
+        );
+    }
+
+    let (block_tx, mut block_rx) = mpsc::unbounded_channel();
+    let (done_tx, done_rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        let result = match format {
+            StdinFormat::Auto => whis_core::stream_audio(std::io::stdin(), &block_tx),
+            StdinFormat::Raw(pcm) => whis_core::stream_raw_pcm(std::io::stdin(), pcm, &block_tx),
+        };
+        let _ = done_tx.send(result);
+    });
+
+    let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+    let reader = tokio::spawn(async move {
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        loop {
+            tokio::select! {
+                block = block_rx.recv() => match block {
+                    Some(block) => {
+                        if audio_tx.send(block).is_err() {
+                            return Ok(());
+                        }
+                    }
+                    None => break,
+                },
+                _ = &mut ctrl_c => {
+                    // Dropping audio_tx ends the stream; queued chunks still finish
+                    if !quiet {
+                        eprintln!("\nInterrupted, transcribing the audio read so far...");
+                    }
+                    return Ok(());
+                }
+            }
+        }
+
+        done_rx
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("stdin reader stopped unexpectedly")))
+    });
+
+    Ok((audio_rx, reader))
+}
@@ -9,5 +9,5 @@
 pub mod output;
 pub mod process;
 
-pub use output::{OutputMode, output};
+pub use output::{OutputMode, format_text, output};
 pub use process::{ProcessingConfig, process};
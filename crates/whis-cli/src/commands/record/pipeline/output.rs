@@ -4,7 +4,7 @@ use anyhow::Result;
 use std::fs;
 use std::io::{self, IsTerminal};
 use std::path::PathBuf;
-use whis_core::{OutputMethod, Settings, autotype_text, copy_to_clipboard};
+use whis_core::{OutputMethod, Settings, TranscriptionSegment, autotype_text, copy_to_clipboard};
 
 use crate::args::OutputFormat;
 
@@ -20,7 +20,7 @@ pub enum OutputMode {
     File(PathBuf),
 }
 
-// Subtitle timing constants
+// Subtitle timing constants (heuristic, used when the provider returned no timings)
 const CHARS_PER_SECOND: f64 = 15.0;
 const SUBTITLE_GAP_SECS: f64 = 0.5;
 
@@ -29,6 +29,29 @@ struct TimedSegment<'a> {
     text: &'a str,
     start: f64,
     end: f64,
+    /// Speaker label ("Speaker 1") when the transcript was diarized
+    speaker: Option<String>,
+}
+
+/// Use provider timings when available, otherwise estimate them from the text
+fn timed_segments<'a>(
+    text: &'a str,
+    segments: &'a [TranscriptionSegment],
+) -> Vec<TimedSegment<'a>> {
+    if segments.is_empty() {
+        return split_into_timed_segments(text);
+    }
+
+    segments
+        .iter()
+        .map(|seg| TimedSegment {
+            text: seg.text.trim(),
+            start: seg.start,
+            end: seg.end,
+            speaker: seg.speaker_label(),
+        })
+        .filter(|seg| !seg.text.is_empty())
+        .collect()
 }
 
 /// Split text into timed segments for subtitle generation
@@ -48,6 +71,7 @@ fn split_into_timed_segments(text: &str) -> Vec<TimedSegment<'_>> {
             text: segment,
             start: time_offset,
             end: time_offset + duration,
+            speaker: None,
         });
         time_offset += duration + SUBTITLE_GAP_SECS;
     }
@@ -76,9 +100,10 @@ fn format_vtt_time(seconds: f64) -> String {
     format!("{h:02}:{m:02}:{s:02}.{ms:03}")
 }
 
-/// Format text as SRT subtitle
-fn format_srt(text: &str) -> String {
-    let segments = split_into_timed_segments(text);
+/// Format segments as SRT subtitle
+///
+/// Diarized cues are prefixed with the speaker ("Speaker 1: ...").
+fn format_srt(segments: &[TimedSegment]) -> String {
     if segments.is_empty() {
         return String::new();
     }
@@ -90,37 +115,67 @@ fn format_srt(text: &str) -> String {
             i + 1,
             format_srt_time(seg.start),
             format_srt_time(seg.end),
-            seg.text
+            match &seg.speaker {
+                Some(speaker) => format!("{speaker}: {}", seg.text),
+                None => seg.text.to_string(),
+            }
         ));
     }
     output.trim_end().to_string()
 }
 
-/// Format text as WebVTT subtitle
-fn format_vtt(text: &str) -> String {
-    let segments = split_into_timed_segments(text);
+/// Format segments as WebVTT subtitle
+///
+/// Diarized cues use WebVTT voice spans (`<v Speaker 1>...`).
+fn format_vtt(segments: &[TimedSegment]) -> String {
     if segments.is_empty() {
         return "WEBVTT\n".to_string();
     }
 
     let mut output = String::from("WEBVTT\n\n");
-    for seg in &segments {
+    for seg in segments {
         output.push_str(&format!(
             "{} --> {}\n{}\n\n",
             format_vtt_time(seg.start),
             format_vtt_time(seg.end),
-            seg.text
+            match &seg.speaker {
+                Some(speaker) => format!("<v {speaker}>{}", seg.text),
+                None => seg.text.to_string(),
+            }
         ));
     }
     output.trim_end().to_string()
 }
 
-/// Format text according to the specified output format
-pub fn format_text(text: &str, format: OutputFormat) -> String {
+/// Format the transcript as JSON (`{"text": ..., "segments": [...], "chunks": [...]}`)
+///
+/// Segments are the provider's timings as-is; the list is empty when there
+/// are none (no estimated timings, unlike the subtitle formats). Chunks name
+/// the provider that transcribed each chunk, so fallbacks are visible.
+/// `detected_language` and `language_confidence` are null when the provider
+/// doesn't report them.
+fn format_json(text: &str, result: &ProcessedResult) -> String {
+    let value = serde_json::json!({
+        "text": text,
+        "segments": result.segments,
+        "chunks": result.chunks,
+        "detected_language": result.detected_language,
+        "language_confidence": result.language_confidence,
+    });
+    serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+/// Format a result according to the specified output format
+///
+/// Subtitle formats use the result's segments for cue timings when present.
+pub fn format_text(result: &ProcessedResult, format: OutputFormat) -> String {
+    let text = result.text.trim();
+    let segments = &result.segments;
     match format {
         OutputFormat::Txt => text.to_string(),
-        OutputFormat::Srt => format_srt(text),
-        OutputFormat::Vtt => format_vtt(text),
+        OutputFormat::Srt => format_srt(&timed_segments(text, segments)),
+        OutputFormat::Vtt => format_vtt(&timed_segments(text, segments)),
+        OutputFormat::Json => format_json(text, result),
     }
 }
 
@@ -131,8 +186,7 @@ pub fn output(
     format: OutputFormat,
     quiet: bool,
 ) -> Result<()> {
-    let text = result.text.trim();
-    let formatted = format_text(text, format);
+    let formatted = format_text(&result, format);
 
     match mode {
         OutputMode::Print => {
@@ -183,3 +237,64 @@ pub fn output(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, end: f64, text: &str, speaker: Option<u32>) -> TranscriptionSegment {
+        TranscriptionSegment {
+            start,
+            end,
+            text: text.to_string(),
+            speaker,
+            confidence: None,
+        }
+    }
+
+    fn diarized() -> Vec<TranscriptionSegment> {
+        vec![
+            segment(0.0, 1.5, " Thanks for joining. ", Some(0)),
+            segment(1.6, 62.25, "Happy to be here.", Some(1)),
+        ]
+    }
+
+    #[test]
+    fn srt_prefixes_cues_with_the_speaker() {
+        let segments = diarized();
+        let srt = format_srt(&timed_segments("", &segments));
+
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,500\nSpeaker 1: Thanks for joining.\n\n\
+             2\n00:00:01,600 --> 00:01:02,250\nSpeaker 2: Happy to be here."
+        );
+    }
+
+    #[test]
+    fn vtt_uses_voice_spans_for_speakers() {
+        let segments = diarized();
+        let vtt = format_vtt(&timed_segments("", &segments));
+
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n\
+             00:00:00.000 --> 00:00:01.500\n<v Speaker 1>Thanks for joining.\n\n\
+             00:00:01.600 --> 00:01:02.250\n<v Speaker 2>Happy to be here."
+        );
+    }
+
+    #[test]
+    fn subtitles_without_speakers_have_no_labels() {
+        let segments = vec![segment(0.0, 2.0, "Hello world.", None)];
+
+        assert_eq!(
+            format_srt(&timed_segments("", &segments)),
+            "1\n00:00:00,000 --> 00:00:02,000\nHello world."
+        );
+        assert_eq!(
+            format_vtt(&timed_segments("", &segments)),
+            "WEBVTT\n\n00:00:00.000 --> 00:00:02.000\nHello world."
+        );
+    }
+}
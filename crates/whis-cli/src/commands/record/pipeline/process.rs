@@ -1,7 +1,10 @@
 //! Post-processing pipeline phase
 
 use anyhow::Result;
-use whis_core::{PostProcessor, Preset, Settings, post_process, resolve_post_processor_config};
+use whis_core::{
+    PostProcessor, Preset, Settings, fill_language_placeholder, post_process,
+    resolve_post_processor_config, translate_transcript,
+};
 
 use super::super::types::{ProcessedResult, TranscriptionResult};
 use crate::app;
@@ -10,6 +13,8 @@ use crate::app;
 pub struct ProcessingConfig {
     pub enabled: bool,
     pub preset: Option<Preset>,
+    /// Translate to English unless the provider already did
+    pub translate: bool,
 }
 
 /// Execute post-processing phase
@@ -19,12 +24,27 @@ pub async fn process(
     quiet: bool,
 ) -> Result<ProcessedResult> {
     let mut text = transcription.text;
+    let mut segments = transcription.segments;
+
+    // Providers without native translation: translate with the post-processor
+    if config.translate && !transcription.translated {
+        let settings = Settings::load_cli();
+        if !quiet {
+            app::print_status(" Translating...", None);
+        }
+
+        text = translate_transcript(&text, &config.preset, &settings).await?;
+
+        // Translated text no longer lines up with the provider timings
+        segments.clear();
+    }
 
     // If post-processing is enabled OR a preset is provided, apply LLM processing
     if config.enabled || config.preset.is_some() {
         let settings = Settings::load_cli();
         let (processor, api_key, model, prompt) =
             resolve_post_processor_config(&config.preset, &settings)?;
+        let prompt = fill_language_placeholder(&prompt, transcription.detected_language.as_deref());
 
         // Re-warm Ollama model (in case it unloaded during long recording > keep_alive timeout)
         if processor == PostProcessor::Ollama && model.is_some() {
@@ -38,7 +58,16 @@ pub async fn process(
         }
 
         text = post_process(&text, &processor, &api_key, &prompt, model.as_deref()).await?;
+
+        // Rewritten text no longer lines up with the provider timings
+        segments.clear();
     }
 
-    Ok(ProcessedResult { text })
+    Ok(ProcessedResult {
+        text,
+        segments,
+        chunks: transcription.chunks,
+        detected_language: transcription.detected_language,
+        language_confidence: transcription.language_confidence,
+    })
 }
@@ -13,12 +13,12 @@
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
-//! │  Progressive    │  → TranscriptionResult { text }
+//! │  Progressive    │  → TranscriptionResult { text, segments }
 //! │  Transcription  │
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
-//! │  Process Phase  │  → ProcessedResult { text }
+//! │  Process Phase  │  → ProcessedResult { text, segments }
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
@@ -29,21 +29,23 @@
 //! # Key Types
 //!
 //! - `RecordConfig`: User-provided configuration (flags, presets, output mode)
-//! - `TranscriptionResult`: Raw transcript text from provider
+//! - `TranscriptionResult`: Raw transcript text (timings, speaker labels) from provider
 //! - `ProcessedResult`: Final processed text after LLM cleanup/preset transform
 
 use anyhow::Result;
 use std::path::PathBuf;
 use std::time::Duration;
-use whis_core::Preset;
+use whis_core::{ChunkSource, Preset, TranscriptionSegment};
 
-use crate::args::{InputOptions, OutputFormat, OutputOptions, ProcessingOptions};
+use crate::args::{InputOptions, OutputFormat, OutputOptions, ProcessingOptions, StdinFormat};
 
 /// Configuration for the record command
 #[derive(Debug, Clone)]
 pub struct RecordConfig {
-    /// Input file path (None = record from microphone)
+    /// Input file path (None = record from microphone, "-" = stdin)
     pub input_file: Option<PathBuf>,
+    /// Format of audio piped to stdin
+    pub stdin_format: StdinFormat,
     /// Whether to enable post-processing
     pub post_process: bool,
     /// Preset to apply to output
@@ -52,14 +54,20 @@ pub struct RecordConfig {
     pub print: bool,
     /// Output file path (None = clipboard)
     pub output_path: Option<PathBuf>,
-    /// Output format (txt, srt, vtt)
+    /// Output format (txt, srt, vtt, json)
     pub format: OutputFormat,
     /// Recording duration (None = until silence/manual stop)
     pub duration: Option<Duration>,
     /// Disable Voice Activity Detection
     pub no_vad: bool,
+    /// Stop recording after trailing silence (`--auto-stop`)
+    pub auto_stop: bool,
     /// Language override (None = use configured language)
     pub language: Option<String>,
+    /// Request speaker labels from the provider
+    pub diarize: bool,
+    /// Translate the transcript to English (`--translate`)
+    pub translate: bool,
 }
 
 impl RecordConfig {
@@ -90,6 +98,7 @@ impl RecordConfig {
 
         Ok(Self {
             input_file: input.file.clone(),
+            stdin_format: input.stdin_format,
             post_process: processing.post_process,
             preset,
             print: output.print,
@@ -97,7 +106,10 @@ impl RecordConfig {
             format,
             duration: processing.duration,
             no_vad: processing.no_vad,
+            auto_stop: processing.auto_stop,
             language: processing.language.clone(),
+            diarize: processing.diarize,
+            translate: processing.translate,
         })
     }
 
@@ -112,6 +124,30 @@ impl RecordConfig {
 pub struct TranscriptionResult {
     /// The transcribed text
     pub text: String,
+    /// Timed segments from the provider (empty if no timings are available)
+    pub segments: Vec<TranscriptionSegment>,
+    /// Provider of each chunk (empty unless transcribed in chunks)
+    pub chunks: Vec<ChunkSource>,
+    /// Text is already English (translated by the provider)
+    pub translated: bool,
+    /// Spoken language reported by the provider (ISO 639-1)
+    pub detected_language: Option<String>,
+    /// Provider confidence in `detected_language`
+    pub language_confidence: Option<f64>,
+}
+
+impl From<whis_core::TranscriptionResult> for TranscriptionResult {
+    fn from(result: whis_core::TranscriptionResult) -> Self {
+        Self {
+            segments: result.timed_segments(),
+            chunks: result.chunks.clone(),
+            translated: result.translated,
+            detected_language: result.detected_language.clone(),
+            language_confidence: result.language_confidence,
+            // Diarized transcripts are rendered as "Speaker N: ..." turns
+            text: result.speaker_text().unwrap_or(result.text),
+        }
+    }
 }
 
 /// Result of post-processing phase
@@ -119,4 +155,12 @@ pub struct TranscriptionResult {
 pub struct ProcessedResult {
     /// The processed text
     pub text: String,
+    /// Timed segments (empty if unavailable or the text was rewritten)
+    pub segments: Vec<TranscriptionSegment>,
+    /// Provider of each chunk (empty unless transcribed in chunks)
+    pub chunks: Vec<ChunkSource>,
+    /// Spoken language reported by the provider (ISO 639-1)
+    pub detected_language: Option<String>,
+    /// Provider confidence in `detected_language`
+    pub language_confidence: Option<f64>,
 }
@@ -4,6 +4,7 @@
 //! - OpenAI (standard + realtime streaming)
 //! - Deepgram (standard + realtime streaming)
 //! - Mistral, Groq, ElevenLabs
+//! - Custom OpenAI-compatible endpoint (base URL, model, optional key)
 //!
 //! # Flow
 //!
@@ -13,6 +14,7 @@
 //! 4. Save to settings
 
 use anyhow::{Result, anyhow};
+use whis_core::settings::{CustomAuthStyle, CustomEndpointConfig};
 use whis_core::{Settings, TranscriptionProvider};
 
 use super::interactive;
@@ -32,6 +34,60 @@ fn select_streaming_method(
     Ok(if choice == 1 { realtime } else { base })
 }
 
+/// Prompt for a custom OpenAI-compatible endpoint (URL, model, optional key)
+fn setup_custom_endpoint(settings: &mut Settings) -> Result<()> {
+    let current = settings.transcription.custom_endpoint.clone();
+
+    let base_url = loop {
+        let url = interactive::input(
+            "Base URL (e.g., http://localhost:8000/v1)",
+            current.base_url.as_deref(),
+        )?;
+        let url = url.trim().to_string();
+        if url.starts_with("http://") || url.starts_with("https://") {
+            break url;
+        }
+        interactive::error("URL must start with http:// or https://");
+    };
+
+    let model = interactive::input("Model", Some(current.model()))?
+        .trim()
+        .to_string();
+
+    let has_key = current.auth != CustomAuthStyle::None
+        && settings
+            .transcription
+            .api_key_for(&TranscriptionProvider::Custom)
+            .is_some();
+    let needs_key = interactive::select(
+        "Does the endpoint require an API key?",
+        &["No", "Yes"],
+        Some(usize::from(has_key)),
+    )? == 1;
+
+    let auth = if needs_key {
+        let api_key = interactive::password("Custom endpoint API key")?;
+        settings
+            .transcription
+            .set_api_key(&TranscriptionProvider::Custom, api_key.trim().to_string());
+        match current.auth {
+            CustomAuthStyle::None => CustomAuthStyle::Bearer,
+            style => style,
+        }
+    } else {
+        CustomAuthStyle::None
+    };
+
+    settings.transcription.custom_endpoint = CustomEndpointConfig {
+        base_url: Some(base_url),
+        model: Some(model),
+        auth,
+        ..current
+    };
+
+    Ok(())
+}
+
 /// Prompt for and validate an API key
 pub fn prompt_and_validate_key(provider: &TranscriptionProvider) -> Result<String> {
     // Validation loop with secure password input
@@ -140,6 +196,14 @@ pub fn setup_transcription_cloud() -> Result<()> {
         _ => provider,
     };
 
+    // Custom endpoints need a URL rather than a provider-issued key
+    if provider == TranscriptionProvider::Custom {
+        setup_custom_endpoint(&mut settings)?;
+        settings.transcription.provider = provider;
+        settings.save_cli()?;
+        return Ok(());
+    }
+
     // Check if API key already exists for this provider
     if let Some(existing_key) = settings.transcription.api_key_for(&provider) {
         let is_configured = settings.transcription.has_configured_api_key(&provider);
@@ -22,6 +22,7 @@ pub fn provider_description(provider: &TranscriptionProvider) -> &'static str {
         TranscriptionProvider::OpenAIRealtime | TranscriptionProvider::DeepgramRealtime => {
             "Streaming"
         }
+        TranscriptionProvider::Custom => "OpenAI-compatible",
         _ => "",
     }
 }
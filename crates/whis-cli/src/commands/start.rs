@@ -5,7 +5,7 @@ use whis_core::autotyping::OutputMethod;
 use whis_core::settings::CliShortcutMode;
 use whis_core::{Preset, resolve_post_processor_config};
 
-pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
+pub fn run(autotype: bool, preset_name: Option<String>, continuous: bool) -> Result<()> {
     // Check if service is already running
     if ipc::is_service_running() {
         eprintln!("Error: whis service is already running.");
@@ -23,6 +23,7 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
         None
     };
     let config = app::load_transcription_config()?;
+    let continuous = continuous || settings.ui.continuous.enabled;
 
     // Load preset if specified
     let preset = preset_name
@@ -52,7 +53,12 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
                 .unwrap_or(&settings.ui.output_method);
             match hotkey::setup(shortcut) {
                 Ok((hotkey_rx, _guard)) => {
-                    if push_to_talk {
+                    if continuous {
+                        println!(
+                            "Listening. Press {} to toggle continuous dictation. Output: {}. Ctrl+C to stop.",
+                            shortcut, output_method
+                        );
+                    } else if push_to_talk {
                         println!(
                             "Listening. Hold {} to record (push-to-talk). Output: {}. Ctrl+C to stop.",
                             shortcut, output_method
@@ -65,8 +71,12 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
                     }
 
                     runtime.block_on(async {
-                        let service =
-                            service::Service::new(config, preset, output_method_override)?;
+                        let service = service::Service::new(
+                            config,
+                            preset,
+                            output_method_override,
+                            continuous,
+                        )?;
                         tokio::select! {
                             result = service.run(Some(hotkey_rx), push_to_talk) => result,
                             _ = tokio::signal::ctrl_c() => {
@@ -94,13 +104,19 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
             let output_method = output_method_override
                 .as_ref()
                 .unwrap_or(&settings.ui.output_method);
+            let action = if continuous {
+                "toggle continuous dictation"
+            } else {
+                "record"
+            };
             println!(
-                "Listening. Press your configured shortcut to record. Output: {}. Ctrl+C to stop.",
-                output_method
+                "Listening. Press your configured shortcut to {}. Output: {}. Ctrl+C to stop.",
+                action, output_method
             );
 
             runtime.block_on(async {
-                let service = service::Service::new(config, preset, output_method_override)?;
+                let service =
+                    service::Service::new(config, preset, output_method_override, continuous)?;
                 tokio::select! {
                     result = service.run(None, false) => result,
                     _ = tokio::signal::ctrl_c() => {
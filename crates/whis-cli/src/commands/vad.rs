@@ -0,0 +1,159 @@
+//! Voice activity detection tools
+//!
+//! `whis vad test` records from the microphone (or replays a file) and prints
+//! the speech probability of every 32ms frame, so the threshold, prefill,
+//! onset and hangover can be tuned for a specific microphone. `--as <preset>`
+//! starts from a preset's VAD overrides instead of the global settings.
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use whis_core::audio::{Denoiser, VadProcessor};
+use whis_core::resample::WHISPER_SAMPLE_RATE;
+use whis_core::{AudioRecorder, Preset, Settings, VadConfig};
+
+use crate::args::{VadAction, VadTestOptions};
+
+/// VAD frame length in seconds (512 samples at 16kHz)
+const FRAME_SECS: f32 = 0.032;
+
+/// Width of the probability bar
+const BAR_WIDTH: usize = 30;
+
+/// Run the vad command
+pub fn run(action: VadAction) -> Result<()> {
+    match action {
+        VadAction::Test(options) => test(options),
+    }
+}
+
+/// Print per-frame speech probabilities and what the VAD would keep
+fn test(options: VadTestOptions) -> Result<()> {
+    let settings = Settings::load_cli();
+    let preset = options
+        .preset
+        .as_deref()
+        .map(|name| Preset::load(name).map(|(p, _source)| p))
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    // Settings (or --as preset), with command-line overrides for trying out values
+    let mut config = VadConfig {
+        enabled: true,
+        ..settings.ui.vad.for_preset(preset.as_ref()).config()
+    };
+    config.engine = options.engine.unwrap_or(config.engine);
+    if let Some(threshold) = options.threshold {
+        if !(0.0..=1.0).contains(&threshold) {
+            anyhow::bail!("Invalid VAD threshold: must be between 0.0 and 1.0");
+        }
+        config.threshold = threshold;
+    }
+    config.prefill_frames = options.prefill.unwrap_or(config.prefill_frames);
+    config.onset_frames = options.onset.unwrap_or(config.onset_frames).max(1);
+    config.hangover_frames = options.hangover.unwrap_or(config.hangover_frames);
+
+    let mut samples = match &options.file {
+        Some(path) => whis_core::decode_audio_file(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        None => record(&settings, options.duration)?,
+    };
+
+    // Same order as recording: noise suppression runs before VAD
+    if settings.ui.denoise.enabled {
+        let mut denoiser = Denoiser::new(settings.ui.denoise.strength)?;
+        let mut denoised = denoiser.process(&samples);
+        denoised.extend(denoiser.flush());
+        samples = denoised;
+    }
+
+    let mut vad = VadProcessor::from_config(&config)?;
+    let frames = vad.analyze(&samples);
+
+    println!(
+        "engine {}, threshold {:.2}, prefill {}, onset {}, hangover {} (frames of 32ms)",
+        vad.engine(),
+        config.threshold,
+        config.prefill_frames,
+        config.onset_frames,
+        config.hangover_frames
+    );
+    println!();
+    println!("    TIME  PROB  {:<BAR_WIDTH$}  STATE", "");
+
+    // Threshold marker position in the bar
+    let marker = ((config.threshold * BAR_WIDTH as f32).round() as usize).min(BAR_WIDTH - 1);
+    let mut voice_frames = 0;
+    let mut segments = 0;
+    let mut was_speaking = false;
+
+    for (index, frame) in frames.iter().enumerate() {
+        let filled = (frame.probability.clamp(0.0, 1.0) * BAR_WIDTH as f32).round() as usize;
+        let bar: String = (0..BAR_WIDTH)
+            .map(|i| match (i < filled, i == marker) {
+                (true, _) => '#',
+                (false, true) => '|',
+                (false, false) => ' ',
+            })
+            .collect();
+
+        let is_voice = frame.probability >= config.threshold;
+        let state = match (frame.state.is_speaking, is_voice) {
+            (true, true) => "speech",
+            (true, false) => "hangover",
+            // Above the threshold but not confirmed yet
+            (false, true) => "onset",
+            (false, false) => "",
+        };
+
+        println!(
+            "{:>7.2}s  {:.2}  {}  {}",
+            index as f32 * FRAME_SECS,
+            frame.probability,
+            bar,
+            state
+        );
+
+        if is_voice {
+            voice_frames += 1;
+        }
+        if frame.state.is_speaking && !was_speaking {
+            segments += 1;
+        }
+        was_speaking = frame.state.is_speaking;
+    }
+
+    // What a recording with these values would keep (same engine as above)
+    let mut filter = VadProcessor::from_config(&VadConfig {
+        engine: vad.engine(),
+        ..config
+    })?;
+    let mut kept = filter.process(&samples);
+    kept.extend(filter.flush());
+
+    let total_secs = samples.len() as f32 / WHISPER_SAMPLE_RATE as f32;
+    let kept_secs = kept.len() as f32 / WHISPER_SAMPLE_RATE as f32;
+    println!();
+    println!(
+        "{} frames, {} above threshold, {} speech segment{}",
+        frames.len(),
+        voice_frames,
+        segments,
+        if segments == 1 { "" } else { "s" }
+    );
+    println!("Kept {:.1}s of {:.1}s", kept_secs, total_secs);
+
+    Ok(())
+}
+
+/// Record from the configured microphone for `duration` (VAD off)
+fn record(settings: &Settings, duration: std::time::Duration) -> Result<Vec<f32>> {
+    let mut recorder = AudioRecorder::new()?;
+    recorder.start_recording_with_device(settings.ui.microphone_device.as_deref())?;
+
+    print!("Recording for {}s, speak normally...", duration.as_secs());
+    std::io::stdout().flush()?;
+    std::thread::sleep(duration);
+    println!();
+
+    Ok(recorder.stop_recording()?.finalize_raw())
+}
@@ -0,0 +1,514 @@
+//! Watch Command - transcribe audio files as they appear in a folder
+//!
+//! `whis watch <dir>` watches a directory (inotify on Linux) and transcribes
+//! each new audio file once it has stopped growing, so half-synced or
+//! half-copied files aren't picked up. Transcripts are post-processed like
+//! recordings (preset / post-processing settings) and written next to the
+//! audio file, or into `--out` if given. They keep the audio extension
+//! (`memo.m4a.txt`), so `memo.m4a` and `memo.mp3` don't share a transcript.
+//! A couple of files are transcribed at once (one with local models).
+//!
+//! A file counts as done when its transcript exists and is newer than the
+//! audio, so files that arrived while the watcher wasn't running are
+//! transcribed on the next start.
+//! Failed files are retried with exponential backoff.
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use whis_core::{Preset, Settings, resolve_post_processor_config};
+
+use crate::app;
+use crate::args::{OutputFormat, WatchOptions};
+use crate::commands::record::{self, pipeline};
+
+/// How long a file's size and mtime must stay unchanged before it's read
+const STABLE_FOR: Duration = Duration::from_secs(3);
+/// How often pending files are checked
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Delay before the first retry (doubles per attempt)
+const RETRY_BASE: Duration = Duration::from_secs(30);
+/// Longest delay between retries
+const RETRY_MAX: Duration = Duration::from_secs(30 * 60);
+/// Attempts per file before giving up (until the file changes again)
+const MAX_ATTEMPTS: u32 = 6;
+/// Files transcribed at the same time with cloud providers
+const MAX_RUNNING: usize = 2;
+
+/// A file waiting to be transcribed
+struct Pending {
+    /// Size and mtime at the last check
+    snapshot: (u64, Option<SystemTime>),
+    /// When `snapshot` last changed
+    changed_at: Instant,
+    /// Failed attempts so far
+    attempts: u32,
+    /// Earliest time for the next attempt
+    retry_at: Instant,
+    /// Being transcribed right now
+    running: bool,
+}
+
+impl Pending {
+    fn new(snapshot: (u64, Option<SystemTime>)) -> Self {
+        let now = Instant::now();
+        Self {
+            snapshot,
+            changed_at: now,
+            attempts: 0,
+            retry_at: now,
+            running: false,
+        }
+    }
+}
+
+/// Transcription settings shared by all files
+struct WatchContext {
+    transcription: app::TranscriptionConfig,
+    processing: pipeline::ProcessingConfig,
+    vocabulary: Vec<String>,
+    format: OutputFormat,
+    out_dir: Option<PathBuf>,
+}
+
+impl WatchContext {
+    /// Whether `path` has an up-to-date transcript
+    fn is_done(&self, path: &Path) -> bool {
+        is_transcribed(path, &self.output_path(path))
+    }
+
+    /// Transcript path: `<out>/<name>.<ext>` or next to the audio file
+    fn output_path(&self, path: &Path) -> PathBuf {
+        output_path(path, self.format, self.out_dir.as_deref())
+    }
+}
+
+pub fn run(options: WatchOptions) -> Result<()> {
+    let dir = options.dir.clone();
+    if !dir.is_dir() {
+        anyhow::bail!("Not a directory: {}", dir.display());
+    }
+    if let Some(out) = &options.out {
+        fs::create_dir_all(out).with_context(|| format!("Failed to create {}", out.display()))?;
+    }
+
+    let settings = Settings::load_cli();
+    let transcription = app::load_transcription_config_with_language(options.language.clone())?;
+
+    // --as, or the preset selected in settings
+    let preset_name = options
+        .preset
+        .clone()
+        .or_else(|| settings.ui.active_preset.clone());
+    let preset = preset_name
+        .map(|name| Preset::load(&name).map(|(p, _source)| p))
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    // Validate post-processing up front rather than failing every file
+    let post_process = options.post_process || settings.post_processing.enabled;
+    if post_process || preset.is_some() {
+        resolve_post_processor_config(&preset, &settings)?;
+    }
+
+    // Local models transcribe one file at a time
+    let max_running = if transcription.provider.is_local() {
+        1
+    } else {
+        MAX_RUNNING
+    };
+
+    let context = Arc::new(WatchContext {
+        vocabulary: settings.transcription.vocabulary_for(preset.as_ref()),
+        processing: pipeline::ProcessingConfig {
+            enabled: post_process,
+            translate: settings.transcription.translate_for(preset.as_ref()),
+            preset,
+        },
+        transcription,
+        format: options.format,
+        out_dir: options.out.clone(),
+    });
+
+    // notify calls back on its own thread; forward paths into the async loop
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel::<PathBuf>();
+    let mut watcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
+            Ok(event) => {
+                if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                    for path in event.paths {
+                        let _ = event_tx.send(path);
+                    }
+                }
+            }
+            Err(err) => whis_core::verbose!("Watch error: {}", err),
+        })
+        .context("Failed to create file watcher")?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .with_context(|| format!("Failed to watch {}", dir.display()))?;
+
+    let mut pending: HashMap<PathBuf, Pending> = HashMap::new();
+
+    // Pick up files that arrived while nothing was watching
+    if !options.new_only {
+        for entry in fs::read_dir(&dir)?.flatten() {
+            let path = entry.path();
+            if is_candidate(&path) && !context.is_done(&path) {
+                track(&mut pending, path);
+            }
+        }
+    }
+
+    println!(
+        "Watching {} ({} queued). Output: {}. Ctrl+C to stop.",
+        dir.display(),
+        pending.len(),
+        context
+            .out_dir
+            .as_ref()
+            .map(|out| out.display().to_string())
+            .unwrap_or_else(|| "next to each file".to_string())
+    );
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let watch_loop = async {
+            let mut poll = tokio::time::interval(POLL_INTERVAL);
+            let mut running = JoinSet::new();
+            // File of each running task, to release a task that panicked
+            let mut running_paths: HashMap<tokio::task::Id, PathBuf> = HashMap::new();
+            loop {
+                tokio::select! {
+                    path = event_rx.recv() => {
+                        // Only closes if the watcher goes away
+                        let Some(path) = path else { break };
+                        if is_candidate(&path) && !context.is_done(&path) {
+                            track(&mut pending, path);
+                        }
+                    }
+                    _ = poll.tick() => {
+                        let free = max_running.saturating_sub(running.len());
+                        for path in ready_files(&mut pending).into_iter().take(free) {
+                            if let Some(entry) = pending.get_mut(&path) {
+                                entry.running = true;
+                            }
+                            let context = context.clone();
+                            let task_path = path.clone();
+                            let task = running.spawn(async move {
+                                let result = transcribe(&task_path, &context).await;
+                                (task_path, result)
+                            });
+                            running_paths.insert(task.id(), path);
+                        }
+                    }
+                    Some(joined) = running.join_next_with_id(), if !running.is_empty() => {
+                        // A panicking task counts as a failed attempt
+                        let (path, result) = match joined {
+                            Ok((id, output)) => {
+                                running_paths.remove(&id);
+                                output
+                            }
+                            Err(err) => {
+                                let path = running_paths.remove(&err.id()).unwrap_or_default();
+                                (path, Err(anyhow::anyhow!("Transcription task failed: {err}")))
+                            }
+                        };
+                        finish_file(&path, result, &context, &mut pending);
+                    }
+                }
+            }
+        };
+
+        tokio::select! {
+            _ = watch_loop => Ok(()),
+            _ = tokio::signal::ctrl_c() => {
+                println!("\nShutting down...");
+                Ok(())
+            }
+        }
+    })
+}
+
+/// Audio file in the watched folder (skips hidden and partial sync files)
+fn is_candidate(path: &Path) -> bool {
+    let hidden = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_none_or(|name| name.starts_with('.'));
+
+    !hidden
+        && path.is_file()
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                whis_core::audio::SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str())
+            })
+}
+
+/// Whether `output` exists and is newer than the audio file
+fn is_transcribed(path: &Path, output: &Path) -> bool {
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    match (modified(output), modified(path)) {
+        (Some(transcript), Some(audio)) => transcript >= audio,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Start (or restart) waiting for `path` to become stable
+fn track(pending: &mut HashMap<PathBuf, Pending>, path: PathBuf) {
+    let Some(snapshot) = snapshot(&path) else {
+        return;
+    };
+    match pending.get_mut(&path) {
+        // Checked again when the running attempt finishes
+        Some(entry) if entry.running => {}
+        // A changed file gets a fresh set of attempts
+        Some(entry) if entry.snapshot != snapshot => *entry = Pending::new(snapshot),
+        Some(_) => {}
+        None => {
+            whis_core::verbose!("Queued {}", path.display());
+            pending.insert(path, Pending::new(snapshot));
+        }
+    }
+}
+
+fn snapshot(path: &Path) -> Option<(u64, Option<SystemTime>)> {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.len(), meta.modified().ok()))
+}
+
+/// Files unchanged for `STABLE_FOR` that are due for an attempt
+fn ready_files(pending: &mut HashMap<PathBuf, Pending>) -> Vec<PathBuf> {
+    let now = Instant::now();
+    pending.retain(|path, entry| entry.running || path.exists());
+
+    let mut ready: Vec<PathBuf> = pending
+        .iter_mut()
+        .filter(|(_, entry)| !entry.running)
+        .filter_map(|(path, entry)| {
+            let current = snapshot(path)?;
+            if current != entry.snapshot {
+                *entry = Pending::new(current);
+                return None;
+            }
+            let stable = current.0 > 0 && now.duration_since(entry.changed_at) >= STABLE_FOR;
+            let due = entry.attempts < MAX_ATTEMPTS && now >= entry.retry_at;
+            (stable && due).then(|| path.clone())
+        })
+        .collect();
+    ready.sort();
+    ready
+}
+
+/// Report a finished attempt, scheduling a retry on failure
+fn finish_file(
+    path: &Path,
+    result: Result<()>,
+    context: &WatchContext,
+    pending: &mut HashMap<PathBuf, Pending>,
+) {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let Some(entry) = pending.get_mut(path) else {
+        return;
+    };
+    entry.running = false;
+
+    // Changed while it was transcribed: start over with the new contents
+    let current = snapshot(path);
+    if current.is_some_and(|current| current != entry.snapshot) {
+        if let Err(err) = &result {
+            whis_core::verbose!("{} failed while it changed: {:#}", name, err);
+        }
+        track(pending, path.to_path_buf());
+        return;
+    }
+
+    match result {
+        Ok(()) => {
+            println!("{} -> {}", name, context.output_path(path).display());
+            pending.remove(path);
+        }
+        Err(err) => {
+            entry.attempts += 1;
+            if entry.attempts >= MAX_ATTEMPTS {
+                eprintln!(
+                    "{name} failed {} times, giving up until it changes: {err:#}",
+                    entry.attempts
+                );
+            } else {
+                let delay = retry_delay(entry.attempts);
+                eprintln!(
+                    "{name} failed (attempt {}/{}), retrying in {}s: {err:#}",
+                    entry.attempts,
+                    MAX_ATTEMPTS,
+                    delay.as_secs()
+                );
+                entry.retry_at = Instant::now() + delay;
+            }
+        }
+    }
+}
+
+async fn transcribe(path: &Path, context: &WatchContext) -> Result<()> {
+    let transcription = record::transcribe_file(
+        path,
+        &context.transcription,
+        false,
+        &context.vocabulary,
+        context.processing.translate,
+        true,
+    )
+    .await?;
+    let processed = pipeline::process(transcription, &context.processing, true).await?;
+
+    let output = context.output_path(path);
+    let formatted = pipeline::format_text(&processed, context.format);
+    fs::write(&output, formatted).with_context(|| format!("Failed to write {}", output.display()))
+}
+
+/// Transcript path: the audio file name plus the format's extension, in
+/// `out_dir` or next to the audio file
+fn output_path(path: &Path, format: OutputFormat, out_dir: Option<&Path>) -> PathBuf {
+    let file_name = format!(
+        "{}.{}",
+        path.file_name().unwrap_or_default().to_string_lossy(),
+        format.extension()
+    );
+    match out_dir {
+        Some(out) => out.join(file_name),
+        None => path.with_file_name(file_name),
+    }
+}
+
+/// Exponential backoff: 30s, 1m, 2m, ... capped at 30 minutes
+fn retry_delay(attempts: u32) -> Duration {
+    RETRY_BASE
+        .saturating_mul(1 << attempts.saturating_sub(1).min(16))
+        .min(RETRY_MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("whis-watch-{}-{name}", std::process::id()))
+    }
+
+    /// Pending entry for `path` whose snapshot was taken `age` ago
+    fn pending_since(path: &Path, age: Duration) -> Pending {
+        let mut entry = Pending::new(snapshot(path).unwrap());
+        entry.changed_at = Instant::now() - age;
+        entry.retry_at = entry.changed_at;
+        entry
+    }
+
+    #[test]
+    fn ready_files_waits_until_the_file_is_stable() {
+        let path = temp_path("stable.wav");
+        fs::write(&path, b"audio").unwrap();
+        let mut pending = HashMap::new();
+
+        pending.insert(path.clone(), pending_since(&path, Duration::ZERO));
+        assert!(ready_files(&mut pending).is_empty());
+
+        pending.insert(path.clone(), pending_since(&path, STABLE_FOR));
+        assert_eq!(ready_files(&mut pending), [path.clone()]);
+
+        // Grown since the snapshot: waits again
+        pending.insert(path.clone(), pending_since(&path, STABLE_FOR));
+        fs::write(&path, b"more audio").unwrap();
+        assert!(ready_files(&mut pending).is_empty());
+        assert!(pending[&path].changed_at.elapsed() < STABLE_FOR);
+
+        // Already running or out of attempts
+        let mut entry = pending_since(&path, STABLE_FOR);
+        entry.running = true;
+        pending.insert(path.clone(), entry);
+        assert!(ready_files(&mut pending).is_empty());
+        let mut entry = pending_since(&path, STABLE_FOR);
+        entry.attempts = MAX_ATTEMPTS;
+        pending.insert(path.clone(), entry);
+        assert!(ready_files(&mut pending).is_empty());
+
+        fs::remove_file(&path).unwrap();
+        assert!(ready_files(&mut pending).is_empty());
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn empty_files_are_not_ready() {
+        let path = temp_path("empty.wav");
+        fs::write(&path, b"").unwrap();
+        let mut pending = HashMap::new();
+        pending.insert(path.clone(), pending_since(&path, STABLE_FOR));
+
+        let ready = ready_files(&mut pending);
+        fs::remove_file(&path).ok();
+        assert!(ready.is_empty());
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        assert_eq!(retry_delay(1), Duration::from_secs(30));
+        assert_eq!(retry_delay(2), Duration::from_secs(60));
+        assert_eq!(retry_delay(3), Duration::from_secs(120));
+        assert_eq!(retry_delay(7), RETRY_MAX);
+        assert_eq!(retry_delay(u32::MAX), RETRY_MAX);
+    }
+
+    #[test]
+    fn transcribed_means_transcript_newer_than_audio() {
+        let audio = temp_path("memo.m4a");
+        let transcript = temp_path("memo.m4a.txt");
+        fs::write(&audio, b"audio").unwrap();
+        fs::remove_file(&transcript).ok();
+        assert!(!is_transcribed(&audio, &transcript));
+
+        fs::write(&transcript, b"text").unwrap();
+        let old = SystemTime::now() - Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(&audio)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        assert!(is_transcribed(&audio, &transcript));
+
+        // Audio replaced after the transcript was written
+        fs::File::options()
+            .write(true)
+            .open(&transcript)
+            .unwrap()
+            .set_modified(old - Duration::from_secs(60))
+            .unwrap();
+        assert!(!is_transcribed(&audio, &transcript));
+
+        fs::remove_file(&audio).ok();
+        fs::remove_file(&transcript).ok();
+    }
+
+    #[test]
+    fn transcripts_keep_the_audio_extension() {
+        let m4a = output_path(Path::new("/sync/memo.m4a"), OutputFormat::Txt, None);
+        let mp3 = output_path(Path::new("/sync/memo.mp3"), OutputFormat::Txt, None);
+        assert_eq!(m4a, Path::new("/sync/memo.m4a.txt"));
+        assert_eq!(mp3, Path::new("/sync/memo.mp3.txt"));
+
+        let out = output_path(
+            Path::new("/sync/memo.m4a"),
+            OutputFormat::Srt,
+            Some(Path::new("/out")),
+        );
+        assert_eq!(out, Path::new("/out/memo.m4a.srt"));
+    }
+}
@@ -103,7 +103,7 @@ pub fn display_error(err: &WhisError) {
             eprintln!("Error: Failed to load audio file");
             eprintln!("{}", msg);
             eprintln!();
-            eprintln!("Hint: Supported formats: WAV, MP3, MP4, M4A, FLAC, OGG");
+            eprintln!("Hint: Supported formats: WAV, MP3, FLAC, OGG, M4A, MP4, MKV, WebM");
         }
 
         // Configuration errors
@@ -26,11 +26,17 @@ fn run() -> Result<()> {
     whis_core::set_verbose(cli.verbose);
 
     match cli.command {
-        Some(args::Commands::Start { autotype, preset }) => commands::start::run(autotype, preset),
+        Some(args::Commands::Start {
+            autotype,
+            preset,
+            continuous,
+        }) => commands::start::run(autotype, preset, continuous),
         Some(args::Commands::Stop) => commands::stop::run(),
-        Some(args::Commands::Restart { autotype, preset }) => {
-            commands::restart::run(autotype, preset)
-        }
+        Some(args::Commands::Restart {
+            autotype,
+            preset,
+            continuous,
+        }) => commands::restart::run(autotype, preset, continuous),
         Some(args::Commands::Status) => commands::status::run(),
         Some(args::Commands::Toggle) => commands::toggle::run(),
         Some(args::Commands::Config {
@@ -42,6 +48,9 @@ fn run() -> Result<()> {
         Some(args::Commands::Preset { action }) => commands::preset::run(action),
         Some(args::Commands::Setup) => commands::setup::run(),
         Some(args::Commands::Model { action }) => commands::model::run(action),
+        Some(args::Commands::Vad { action }) => commands::vad::run(action),
+        Some(args::Commands::Batch(options)) => commands::batch::run(options),
+        Some(args::Commands::Watch(options)) => commands::watch::run(options),
         None => {
             // Microphone recording or file transcription
             let config =
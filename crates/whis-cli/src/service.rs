@@ -25,26 +25,45 @@
 //!                        (auto return)
 //! ```
 //!
+//! # Continuous Mode (continuous = true or `whis start --continuous`)
+//!
+//! ```text
+//! ┌─────────┐  press    ┌───────────┐  press    ┌──────────────┐
+//! │  Idle   │ ────────► │ Listening │ ────────► │  Finishing   │
+//! └─────────┘           └───────────┘           └──────────────┘
+//!      ▲                  │  pause → utterance          │
+//!      │                  ▼  transcribed and output     │
+//!      └───────────────────────────────────────────────┘
+//!                   (last utterance delivered)
+//! ```
+//!
 //! # Architecture
 //!
 //! - Event-driven loop using `tokio::select!` (no polling, zero CPU when idle)
+//! - Optional auto-stop: recording ends by itself after trailing silence
+//! - Continuous mode: VAD splits the audio into utterances, each one is
+//!   transcribed and output as soon as it's ready
 //! - Progressive transcription: audio chunks sent during recording
 //! - Post-processing and clipboard copy on completion
 
 use anyhow::{Context, Result};
 use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::sync::watch;
 
 use crate::app::TranscriptionConfig;
 use crate::hotkey::HotkeyEvent;
 use crate::ipc::{IpcMessage, IpcResponse, IpcServer};
+use whis_core::settings::CustomEndpointConfig;
 use whis_core::{
-    AudioRecorder, OutputMethod, PostProcessor, Preset, Settings, TranscriptionProvider,
-    autotype_text, copy_to_clipboard, post_process, resolve_post_processor_config,
+    AudioRecorder, AutoStopReason, FallbackProvider, OutputMethod, PostProcessor, Preset, Settings,
+    TranscriptionProvider, TranscriptionResult, autotype_text, copy_to_clipboard,
+    fill_language_placeholder, post_process, resolve_post_processor_config, translate_transcript,
 };
 
 // Type aliases to reduce complexity warnings
 type TaskHandle<T> = Arc<Mutex<Option<tokio::task::JoinHandle<T>>>>;
+type UtteranceReceiver = UnboundedReceiver<Result<TranscriptionResult>>;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ServiceState {
@@ -56,16 +75,25 @@ enum ServiceState {
 pub struct Service {
     state: Arc<Mutex<ServiceState>>,
     recorder: Arc<Mutex<Option<AudioRecorder>>>,
+    /// Auto-stop signal of the current recording (None if disabled)
+    auto_stop_rx: Arc<Mutex<Option<watch::Receiver<Option<AutoStopReason>>>>>,
     // Store handles for background tasks (progressive transcription)
     chunker_handle: TaskHandle<Result<(), String>>,
-    transcription_handle: TaskHandle<Result<String>>,
+    transcription_handle: TaskHandle<Result<TranscriptionResult>>,
     provider: TranscriptionProvider,
     api_key: String,
     language: Option<String>,
+    model: Option<String>,
+    fallbacks: Vec<FallbackProvider>,
+    custom_endpoint: Option<CustomEndpointConfig>,
     recording_counter: Arc<Mutex<u32>>,
     preset: Option<Preset>,
     /// CLI override for output method (e.g., --autotype flag)
     output_method_override: Option<OutputMethod>,
+    /// Toggle continuous dictation instead of single recordings
+    continuous: bool,
+    /// Utterance results of a new continuous session, picked up by the run loop
+    utterance_rx: Arc<Mutex<Option<UtteranceReceiver>>>,
 }
 
 impl Service {
@@ -73,18 +101,25 @@ impl Service {
         config: TranscriptionConfig,
         preset: Option<Preset>,
         output_method_override: Option<OutputMethod>,
+        continuous: bool,
     ) -> Result<Self> {
         Ok(Self {
             state: Arc::new(Mutex::new(ServiceState::Idle)),
             recorder: Arc::new(Mutex::new(None)),
+            auto_stop_rx: Arc::new(Mutex::new(None)),
             chunker_handle: Arc::new(Mutex::new(None)),
             transcription_handle: Arc::new(Mutex::new(None)),
             provider: config.provider,
             api_key: config.api_key,
             language: config.language,
+            model: config.model,
+            fallbacks: config.fallbacks,
+            custom_endpoint: config.custom_endpoint,
             recording_counter: Arc::new(Mutex::new(0)),
             preset,
             output_method_override,
+            continuous,
+            utterance_rx: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -109,7 +144,18 @@ impl Service {
             self.provider.set_keep_loaded(keep_loaded);
         }
 
+        // Utterances of the current continuous session, and how many were output
+        let mut utterances: Option<UtteranceReceiver> = None;
+        let mut utterance_count = 0;
+
         loop {
+            // Picked up again each iteration, so a new recording is watched
+            let auto_stop_rx = self.auto_stop_rx.lock().unwrap().clone();
+            if utterances.is_none() {
+                utterances = self.utterance_rx.lock().unwrap().take();
+                utterance_count = 0;
+            }
+
             tokio::select! {
                 // Wait for IPC connection
                 Some(mut conn) = ipc_server.accept() => {
@@ -132,7 +178,7 @@ impl Service {
                         None => std::future::pending().await,
                     }
                 } => {
-                    if push_to_talk {
+                    if push_to_talk && !self.continuous {
                         // Push-to-talk mode: press starts, release stops
                         match event {
                             HotkeyEvent::Pressed => {
@@ -149,6 +195,51 @@ impl Service {
                         }
                     }
                 }
+
+                // Wait for the speaker to finish (if auto-stop is enabled)
+                reason = async {
+                    if let Some(mut rx) = auto_stop_rx
+                        && let Ok(reason) = rx.wait_for(Option::is_some).await
+                    {
+                        return *reason;
+                    }
+                    std::future::pending().await
+                } => {
+                    let count = *self.recording_counter.lock().unwrap();
+                    if reason == Some(AutoStopReason::NoSpeech) {
+                        println!("#{count} No speech detected");
+                    }
+                    self.handle_stop().await;
+                }
+
+                // Output continuous dictation as each utterance is transcribed
+                result = async {
+                    match &mut utterances {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    let count = *self.recording_counter.lock().unwrap();
+                    match result {
+                        Some(result) => {
+                            utterance_count += 1;
+                            self.deliver_utterance(&format!("#{count}.{utterance_count}"), result)
+                                .await;
+                        }
+                        None => {
+                            // Stopped and every utterance delivered (or the
+                            // chunker failed; stop the microphone then too)
+                            utterances = None;
+                            if let Some(mut recorder) = self.recorder.lock().unwrap().take() {
+                                let _ = recorder.stop_recording();
+                            }
+                            self.auto_stop_rx.lock().unwrap().take();
+                            *self.state.lock().unwrap() = ServiceState::Idle;
+                            println!("#{count} Stopped listening.");
+                            println!();
+                        }
+                    }
+                }
             }
         }
     }
@@ -191,7 +282,11 @@ impl Service {
                 };
                 match self.start_recording().await {
                     Ok(_) => {
-                        println!("#{count} Recording...");
+                        if self.continuous {
+                            println!("#{count} Listening (continuous)...");
+                        } else {
+                            println!("#{count} Recording...");
+                        }
                         IpcResponse::Recording
                     }
                     Err(e) => {
@@ -200,6 +295,10 @@ impl Service {
                     }
                 }
             }
+            ServiceState::Recording if self.continuous => {
+                self.stop_continuous();
+                IpcResponse::Transcribing
+            }
             ServiceState::Recording => {
                 // Stop recording and transcribe
                 *self.state.lock().unwrap() = ServiceState::Transcribing;
@@ -279,18 +378,57 @@ impl Service {
         }
     }
 
+    /// Stop listening in continuous mode
+    ///
+    /// Utterances still being transcribed are output by the run loop, which
+    /// returns to idle once the last one is delivered.
+    fn stop_continuous(&self) {
+        let count = *self.recording_counter.lock().unwrap();
+        *self.state.lock().unwrap() = ServiceState::Transcribing;
+
+        if let Some(mut recorder) = self.recorder.lock().unwrap().take() {
+            // Fails when nothing was said, which is fine here
+            if let Err(e) = recorder.stop_recording() {
+                whis_core::verbose!("{e}");
+            }
+        }
+        println!("#{count} Finishing...");
+    }
+
+    /// Output one continuous dictation utterance
+    async fn deliver_utterance(&self, label: &str, result: Result<TranscriptionResult>) {
+        let result = match result {
+            Ok(result) if result.text.trim().is_empty() => return,
+            Ok(result) => result,
+            Err(e) => {
+                println!("{label} error: {e:#}");
+                return;
+            }
+        };
+        if let Err(e) = self.process_and_output(label, result, true).await {
+            println!("{label} error: {e}");
+        }
+    }
+
     /// Start recording audio with progressive transcription
+    ///
+    /// In continuous mode, VAD is always on and the audio is split into
+    /// utterances that are transcribed one by one (see `run`).
     async fn start_recording(&self) -> Result<()> {
-        use tokio::sync::mpsc;
         use whis_core::{ChunkerConfig, ProgressiveChunker};
 
         let mut recorder = AudioRecorder::new()?;
 
-        // Configure VAD from settings
+        // Configure VAD from settings and preset (continuous mode needs it to find pauses)
         let settings = Settings::load_cli();
-        #[cfg(feature = "vad")]
-        {
-            recorder.set_vad(settings.ui.vad.enabled, settings.ui.vad.threshold);
+        let vad = settings.ui.vad.for_preset(self.preset.as_ref()).config();
+        recorder.set_vad_config(whis_core::VadConfig {
+            enabled: vad.enabled || self.continuous,
+            ..vad
+        });
+        recorder.set_denoise(settings.ui.denoise.enabled, settings.ui.denoise.strength);
+        if !self.continuous {
+            recorder.set_auto_stop(settings.ui.auto_stop.config());
         }
 
         // Start streaming recording with configured device
@@ -316,18 +454,34 @@ impl Service {
         // Create chunker config from settings
         let vad_enabled = settings.ui.vad.enabled;
         let target = settings.ui.chunk_duration_secs;
-        let chunker_config = ChunkerConfig {
-            target_duration_secs: target,
-            min_duration_secs: target * 2 / 3,
-            max_duration_secs: target * 4 / 3,
-            vad_aware: vad_enabled,
+        let chunker_config = if self.continuous {
+            ChunkerConfig::utterances()
+        } else {
+            ChunkerConfig {
+                target_duration_secs: target,
+                min_duration_secs: target * 2 / 3,
+                max_duration_secs: target * 4 / 3,
+                vad_aware: vad_enabled,
+            }
         };
 
-        // Spawn chunker task
+        // Spawn chunker task (cloud chunks are encoded while they're recorded)
         let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
+        if !self.provider.is_local() {
+            chunker = chunker.with_streaming_encoder(whis_core::audio::upload_format_for(
+                &self.provider,
+                settings.transcription.upload_format,
+            ));
+        }
+        let vad_state_rx = if self.continuous {
+            chunker = chunker.with_utterances();
+            recorder.vad_state_receiver()
+        } else {
+            None
+        };
         let chunker_handle = tokio::spawn(async move {
             chunker
-                .consume_stream(audio_rx_unbounded, None)
+                .consume_stream(audio_rx_unbounded, vad_state_rx)
                 .await
                 .map_err(|e| e.to_string())
         });
@@ -335,30 +489,70 @@ impl Service {
         // Spawn transcription task based on provider
         let provider = self.provider.clone();
         let api_key = self.api_key.clone();
-        let language = self.language.clone();
-
-        let transcription_handle = tokio::spawn(async move {
-            #[cfg(feature = "local-transcription")]
-            if provider == TranscriptionProvider::LocalParakeet {
-                // Local Parakeet progressive transcription
-                let model_path = Settings::load_cli()
-                    .transcription
-                    .parakeet_model_path()
-                    .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
-
-                return whis_core::progressive_transcribe_local(&model_path, chunk_rx, None).await;
-            }
+        let options = whis_core::CloudTranscriptionOptions {
+            language: self.language.clone(),
+            model: self.model.clone(),
+            custom_endpoint: self.custom_endpoint.clone(),
+            diarize: false,
+            vocabulary: settings.transcription.vocabulary_for(self.preset.as_ref()),
+            fallbacks: self.fallbacks.clone(),
+            concurrency: settings.ui.chunk_concurrency,
+            translate: settings.transcription.translate_for(self.preset.as_ref()),
+            upload_format: settings.transcription.upload_format,
+        };
 
-            // Cloud provider progressive transcription
-            whis_core::progressive_transcribe_cloud(
-                &provider,
-                &api_key,
-                language.as_deref(),
-                chunk_rx,
-                None,
-            )
-            .await
-        });
+        if self.continuous {
+            // Each utterance goes to the run loop on its own
+            let (utterance_tx, utterance_rx) = mpsc::unbounded_channel();
+            *self.utterance_rx.lock().unwrap() = Some(utterance_rx);
+            let transcription_tx = utterance_tx.clone();
+            tokio::spawn(async move {
+                let result = whis_core::transcribe_utterances(
+                    &provider,
+                    &api_key,
+                    options,
+                    chunk_rx,
+                    transcription_tx.clone(),
+                )
+                .await;
+                if let Err(e) = result {
+                    let _ = transcription_tx.send(Err(e));
+                }
+            });
+
+            // Nobody awaits the chunker until the session ends, so report its
+            // failure as an utterance error (ends the session)
+            tokio::spawn(async move {
+                if let Ok(Err(e)) = chunker_handle.await {
+                    let _ = utterance_tx.send(Err(anyhow::anyhow!("Chunker task failed: {e}")));
+                }
+            });
+        } else {
+            let transcription_handle = tokio::spawn(async move {
+                #[cfg(feature = "local-transcription")]
+                if provider.is_local() {
+                    // Local progressive transcription (api_key holds the model path)
+                    return whis_core::progressive_transcribe_local(
+                        &provider,
+                        &api_key,
+                        options.language.as_deref(),
+                        &options.vocabulary,
+                        options.translate,
+                        chunk_rx,
+                        None,
+                    )
+                    .await;
+                }
+
+                // Cloud provider progressive transcription
+                whis_core::progressive_transcribe_cloud(
+                    &provider, &api_key, options, chunk_rx, None,
+                )
+                .await
+            });
+            *self.transcription_handle.lock().unwrap() = Some(transcription_handle);
+            *self.chunker_handle.lock().unwrap() = Some(chunker_handle);
+        }
 
         // Preload models in background (same as before)
         #[cfg(feature = "local-transcription")]
@@ -379,9 +573,8 @@ impl Service {
         }
 
         // Store recorder and task handles
+        *self.auto_stop_rx.lock().unwrap() = recorder.auto_stop_receiver();
         *self.recorder.lock().unwrap() = Some(recorder);
-        *self.chunker_handle.lock().unwrap() = Some(chunker_handle);
-        *self.transcription_handle.lock().unwrap() = Some(transcription_handle);
         *self.state.lock().unwrap() = ServiceState::Recording;
 
         Ok(())
@@ -397,6 +590,9 @@ impl Service {
             .take()
             .context("No active recording")?;
 
+        // The signal has fired (or is moot now); stop watching it
+        self.auto_stop_rx.lock().unwrap().take();
+
         // Stop recording (closes audio stream, signals chunker to finish)
         recorder.stop_recording()?;
 
@@ -422,12 +618,42 @@ impl Service {
             .map_err(|e| anyhow::anyhow!("Chunker task failed: {}", e))?;
 
         // Wait for transcription to finish
-        let transcription = transcription_handle
+        let result = transcription_handle
             .await
             .context("Failed to join transcription task")??;
+        if let Some(language) = &result.detected_language {
+            println!("#{count} Detected language: {language}");
+        }
 
-        // Apply post-processing if enabled or preset is provided
+        self.process_and_output(&format!("#{count}"), result, false)
+            .await
+    }
+
+    /// Translate and post-process a transcription as configured, then output it
+    ///
+    /// `label` prefixes progress messages (`#3`, or `#3.2` for the second
+    /// utterance of a continuous session). Continuous utterances are typed
+    /// with a trailing space, so consecutive ones don't run together, and
+    /// appended to the continuous output file if one is set.
+    async fn process_and_output(
+        &self,
+        label: &str,
+        result: TranscriptionResult,
+        continuous: bool,
+    ) -> Result<()> {
+        let mut transcription = result.text;
+
+        // Translate with the post-processor if the provider couldn't do it natively
         let settings = Settings::load_cli();
+        if settings.transcription.translate_for(self.preset.as_ref()) && !result.translated {
+            println!("{label} Translating...");
+            match translate_transcript(&transcription, &self.preset, &settings).await {
+                Ok(translated) => transcription = translated,
+                Err(e) => eprintln!("{label} Translation failed: {e:#}"),
+            }
+        }
+
+        // Apply post-processing if enabled or preset is provided
         let final_text = if settings.post_processing.enabled || self.preset.is_some() {
             match resolve_post_processor_config(&self.preset, &settings) {
                 Ok((processor, api_key, model, prompt)) => {
@@ -437,8 +663,10 @@ impl Service {
                         tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
                     }
 
-                    println!("#{count} Post-processing...");
+                    println!("{label} Post-processing...");
 
+                    let prompt =
+                        fill_language_placeholder(&prompt, result.detected_language.as_deref());
                     match post_process(
                         &transcription,
                         &processor,
@@ -449,24 +677,24 @@ impl Service {
                     .await
                     {
                         Ok(processed) => {
-                            println!("#{count} Done.");
+                            println!("{label} Done.");
                             processed
                         }
                         Err(e) => {
-                            eprintln!("#{count} Post-processing failed: {e}");
-                            println!("#{count} Done.");
+                            eprintln!("{label} Post-processing failed: {e}");
+                            println!("{label} Done.");
                             transcription
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("#{count} Post-processing config error: {e}");
-                    println!("#{count} Done.");
+                    eprintln!("{label} Post-processing config error: {e}");
+                    println!("{label} Done.");
                     transcription
                 }
             }
         } else {
-            println!("#{count} Done.");
+            println!("{label} Done.");
             transcription
         };
 
@@ -479,20 +707,29 @@ impl Service {
             .unwrap_or(settings.ui.output_method.clone());
         let autotype_backend = settings.ui.autotype_backend.clone();
         let autotype_delay_ms = settings.ui.autotype_delay_ms;
+        let continuous_settings = settings.ui.continuous.clone();
 
         tokio::task::spawn_blocking(move || {
+            let typed = if continuous {
+                format!("{} ", final_text.trim())
+            } else {
+                final_text.clone()
+            };
             match output_method {
                 OutputMethod::Clipboard => {
                     copy_to_clipboard(&final_text, clipboard_method)?;
                 }
                 OutputMethod::Autotype => {
-                    autotype_text(&final_text, autotype_backend, autotype_delay_ms)?;
+                    autotype_text(&typed, autotype_backend, autotype_delay_ms)?;
                 }
                 OutputMethod::Both => {
                     copy_to_clipboard(&final_text, clipboard_method)?;
-                    autotype_text(&final_text, autotype_backend, autotype_delay_ms)?;
+                    autotype_text(&typed, autotype_backend, autotype_delay_ms)?;
                 }
             }
+            if continuous {
+                continuous_settings.append(&final_text)?;
+            }
             Ok::<(), anyhow::Error>(())
         })
         .await
@@ -7,6 +7,10 @@
 //! - Fixed duration chunking (90s default)
 //! - VAD-aware chunking (chunks at silence near target duration)
 //! - 2-second overlap between chunks for better accuracy
+//! - Utterance mode: one chunk per pause, no overlap, for continuous dictation
+//!   (see `ProgressiveChunker::with_utterances`)
+//! - Optional streaming encoding, so a chunk's upload bytes are ready the
+//!   moment it is cut (see `ProgressiveChunker::with_streaming_encoder`)
 //!
 //! ## Architecture
 //! ```text
@@ -22,10 +26,12 @@
 //! ```
 
 use std::collections::VecDeque;
-use tokio::sync::mpsc;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
 
 use crate::resample::WHISPER_SAMPLE_RATE;
 
+use super::encoder::{StreamingEncoder, UploadFormat, start_stream_encoder};
 use super::vad::VadState;
 
 /// Overlap duration in seconds (used for all providers)
@@ -34,6 +40,10 @@ const OVERLAP_SECS: usize = 2;
 /// Overlap in samples at 16kHz
 const OVERLAP_SAMPLES: usize = OVERLAP_SECS * WHISPER_SAMPLE_RATE as usize;
 
+/// How long silence must last before an utterance is cut, so trailing
+/// samples still in flight end up in the right chunk
+const UTTERANCE_SETTLE: Duration = Duration::from_millis(150);
+
 /// Audio chunk with metadata
 #[derive(Debug, Clone)]
 pub struct AudioChunk {
@@ -43,6 +53,22 @@ pub struct AudioChunk {
     pub samples: Vec<f32>,
     /// Whether this chunk has leading overlap from previous chunk
     pub has_leading_overlap: bool,
+    /// Position of the first sample within the whole recording (including overlap)
+    pub start_sample: usize,
+    /// `samples` already compressed for upload (None = encode when transcribing)
+    pub encoded: Option<(UploadFormat, Vec<u8>)>,
+}
+
+impl AudioChunk {
+    /// Start time of this chunk within the whole recording, in seconds
+    pub fn start_secs(&self) -> f64 {
+        self.start_sample as f64 / WHISPER_SAMPLE_RATE as f64
+    }
+
+    /// Duration of this chunk in seconds
+    pub fn duration_secs(&self) -> f64 {
+        self.samples.len() as f64 / WHISPER_SAMPLE_RATE as f64
+    }
 }
 
 /// Configuration for progressive chunking
@@ -58,6 +84,21 @@ pub struct ChunkerConfig {
     pub vad_aware: bool,
 }
 
+impl ChunkerConfig {
+    /// Short utterance-level chunks for continuous dictation
+    ///
+    /// Cuts at every pause, and forces a cut after 30 seconds of
+    /// uninterrupted speech.
+    pub fn utterances() -> Self {
+        Self {
+            target_duration_secs: 30,
+            min_duration_secs: 0,
+            max_duration_secs: 30,
+            vad_aware: true,
+        }
+    }
+}
+
 impl Default for ChunkerConfig {
     fn default() -> Self {
         Self {
@@ -77,6 +118,12 @@ struct ChunkBuffer {
     overlap_buffer: VecDeque<f32>,
     /// Current chunk index
     chunk_index: usize,
+    /// Position of the current chunk's first sample within the whole recording
+    chunk_start: usize,
+    /// Compresses the current chunk as samples arrive (if enabled)
+    encoder: Option<ChunkEncoder>,
+    /// Whether each chunk starts with the end of the previous one
+    overlap: bool,
 }
 
 impl ChunkBuffer {
@@ -85,6 +132,9 @@ impl ChunkBuffer {
             current_chunk: Vec::new(),
             overlap_buffer: VecDeque::with_capacity(OVERLAP_SAMPLES + 1024),
             chunk_index: 0,
+            chunk_start: 0,
+            encoder: None,
+            overlap: true,
         }
     }
 
@@ -92,6 +142,9 @@ impl ChunkBuffer {
     fn add_samples(&mut self, samples: &[f32]) {
         // Add to current chunk
         self.current_chunk.extend(samples);
+        if let Some(encoder) = &mut self.encoder {
+            encoder.push(samples);
+        }
 
         // Add to overlap buffer and keep only last 2 seconds
         self.overlap_buffer.extend(samples);
@@ -110,11 +163,22 @@ impl ChunkBuffer {
         let chunk = AudioChunk {
             index: self.chunk_index,
             samples: std::mem::take(&mut self.current_chunk),
-            has_leading_overlap: self.chunk_index > 0,
+            has_leading_overlap: self.overlap && self.chunk_index > 0,
+            start_sample: self.chunk_start,
+            encoded: self.encoder.as_mut().and_then(ChunkEncoder::finish),
         };
 
         // Prepend overlap to next chunk (for continuity)
-        self.current_chunk.extend(self.overlap_buffer.iter());
+        let carried = if self.overlap {
+            self.current_chunk.extend(self.overlap_buffer.iter());
+            self.overlap_buffer.len()
+        } else {
+            0
+        };
+        if let Some(encoder) = &mut self.encoder {
+            encoder.restart(&self.current_chunk);
+        }
+        self.chunk_start += chunk.samples.len() - carried;
 
         self.chunk_index += 1;
         chunk
@@ -129,11 +193,65 @@ impl ChunkBuffer {
         Some(AudioChunk {
             index: self.chunk_index,
             samples: std::mem::take(&mut self.current_chunk),
-            has_leading_overlap: self.chunk_index > 0,
+            has_leading_overlap: self.overlap && self.chunk_index > 0,
+            start_sample: self.chunk_start,
+            encoded: self.encoder.as_mut().and_then(ChunkEncoder::finish),
         })
     }
 }
 
+/// Streaming encoder for the chunk being accumulated
+///
+/// Encoder errors aren't fatal: the chunk is then encoded from its samples
+/// when it's transcribed, as without streaming encoding.
+struct ChunkEncoder {
+    format: UploadFormat,
+    stream: Option<Box<dyn StreamingEncoder>>,
+}
+
+impl ChunkEncoder {
+    fn new(format: UploadFormat) -> Self {
+        let mut encoder = Self {
+            format,
+            stream: None,
+        };
+        encoder.restart(&[]);
+        encoder
+    }
+
+    /// Start a new stream, primed with `samples` (the next chunk's overlap)
+    fn restart(&mut self, samples: &[f32]) {
+        self.stream = match start_stream_encoder(self.format, WHISPER_SAMPLE_RATE) {
+            Ok(stream) => Some(stream),
+            Err(e) => {
+                crate::verbose!("Streaming {} encoder unavailable: {:#}", self.format, e);
+                None
+            }
+        };
+        self.push(samples);
+    }
+
+    fn push(&mut self, samples: &[f32]) {
+        if let Some(stream) = &mut self.stream
+            && let Err(e) = stream.push(samples)
+        {
+            crate::verbose!("Streaming {} encoding failed: {:#}", self.format, e);
+            self.stream = None;
+        }
+    }
+
+    /// Finish the current stream; the next `restart` begins a new one
+    fn finish(&mut self) -> Option<(UploadFormat, Vec<u8>)> {
+        match self.stream.take()?.finish() {
+            Ok(data) => Some((self.format, data)),
+            Err(e) => {
+                crate::verbose!("Streaming {} encoding failed: {:#}", self.format, e);
+                None
+            }
+        }
+    }
+}
+
 /// Progressive audio chunker
 ///
 /// Consumes streaming audio and produces chunks based on:
@@ -143,6 +261,8 @@ pub struct ProgressiveChunker {
     config: ChunkerConfig,
     buffer: ChunkBuffer,
     chunk_tx: mpsc::UnboundedSender<AudioChunk>,
+    /// Cut at every pause as soon as it happens (see `with_utterances`)
+    utterances: bool,
 }
 
 impl ProgressiveChunker {
@@ -152,9 +272,33 @@ impl ProgressiveChunker {
             config,
             buffer: ChunkBuffer::new(),
             chunk_tx,
+            utterances: false,
         }
     }
 
+    /// Compress chunks while they're recorded, so their upload bytes are
+    /// ready the moment a chunk is cut instead of being encoded afterwards.
+    ///
+    /// Use the format the provider will receive (`upload_format_for`); chunks
+    /// sent to a provider expecting another format are re-encoded.
+    pub fn with_streaming_encoder(mut self, format: UploadFormat) -> Self {
+        self.buffer.encoder = Some(ChunkEncoder::new(format));
+        self
+    }
+
+    /// Produce one chunk per utterance instead of long overlapping chunks.
+    ///
+    /// A chunk is cut as soon as the VAD reports silence (once
+    /// `min_duration_secs` of audio is buffered) rather than when the next
+    /// samples arrive, and chunks don't overlap, so each one can be
+    /// transcribed and delivered on its own. Use with
+    /// `ChunkerConfig::utterances` and a VAD state receiver.
+    pub fn with_utterances(mut self) -> Self {
+        self.utterances = true;
+        self.buffer.overlap = false;
+        self
+    }
+
     /// Check if we should create a chunk
     ///
     /// Decision logic:
@@ -184,16 +328,30 @@ impl ProgressiveChunker {
         duration >= self.config.target_duration_secs
     }
 
+    /// Send the buffered audio as a chunk
+    fn send_chunk(&mut self) -> Result<(), String> {
+        let chunk = self.buffer.create_chunk();
+        crate::verbose!(
+            "Created chunk {} ({:.1}s)",
+            chunk.index,
+            chunk.samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
+        );
+        self.chunk_tx.send(chunk).map_err(|e| e.to_string())
+    }
+
     /// Consume audio stream and produce chunks
     ///
     /// Reads from audio_rx, accumulates samples, and sends chunks
-    /// to chunk_tx when boundaries are detected.
+    /// to chunk_tx when boundaries are detected. `vad_state_rx` follows the
+    /// recorder's VAD (`AudioRecorder::vad_state_receiver`).
     pub async fn consume_stream(
         &mut self,
         mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
-        mut vad_state_rx: Option<mpsc::UnboundedReceiver<VadState>>,
+        mut vad_state_rx: Option<watch::Receiver<VadState>>,
     ) -> Result<(), String> {
         let mut current_vad_state: Option<VadState> = None;
+        // Utterance mode: when the pending pause becomes a cut
+        let mut cut_at: Option<tokio::time::Instant> = None;
 
         loop {
             tokio::select! {
@@ -201,26 +359,43 @@ impl ProgressiveChunker {
                 Some(samples) = audio_rx.recv() => {
                     self.buffer.add_samples(&samples);
 
-                    // Check if we should chunk
-                    if self.should_chunk(current_vad_state) {
-                        let chunk = self.buffer.create_chunk();
-                        crate::verbose!(
-                            "Created chunk {} ({:.1}s)",
-                            chunk.index,
-                            chunk.samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
-                        );
-                        self.chunk_tx.send(chunk).map_err(|e| e.to_string())?;
+                    // Utterances are cut on pauses below, only overlong ones here
+                    let due = if self.utterances {
+                        self.buffer.duration_secs() >= self.config.max_duration_secs
+                    } else {
+                        self.should_chunk(current_vad_state)
+                    };
+                    if due {
+                        cut_at = None;
+                        self.send_chunk()?;
                     }
                 }
 
                 // Receive VAD state updates (if enabled)
                 Some(state) = async {
                     match &mut vad_state_rx {
-                        Some(rx) => rx.recv().await,
+                        Some(rx) => match rx.changed().await {
+                            Ok(()) => Some(*rx.borrow_and_update()),
+                            Err(_) => None,
+                        },
                         None => None,
                     }
                 } => {
                     current_vad_state = Some(state);
+                    if self.utterances {
+                        let pause = state.is_silence()
+                            && !self.buffer.current_chunk.is_empty()
+                            && self.buffer.duration_secs() >= self.config.min_duration_secs;
+                        cut_at = pause.then(|| tokio::time::Instant::now() + UTTERANCE_SETTLE);
+                    }
+                }
+
+                // Utterance mode: the pause held, cut the utterance
+                _ = tokio::time::sleep_until(cut_at.unwrap_or_else(tokio::time::Instant::now)),
+                    if cut_at.is_some() =>
+                {
+                    cut_at = None;
+                    self.send_chunk()?;
                 }
 
                 // Audio stream closed - send final chunk
@@ -241,3 +416,126 @@ impl ProgressiveChunker {
         Ok(())
     }
 }
+
+/// Split audio that is already fully available (e.g. a file) into chunks.
+///
+/// Uses the same fixed-duration boundaries and overlap as streaming chunking
+/// without VAD, so results merge the same way.
+pub fn chunk_samples(samples: &[f32], target_duration_secs: u64) -> Vec<AudioChunk> {
+    let mut buffer = ChunkBuffer::new();
+    let mut chunks = Vec::new();
+
+    // Feed one second at a time, like a recording would
+    for piece in samples.chunks(WHISPER_SAMPLE_RATE as usize) {
+        buffer.add_samples(piece);
+        if buffer.duration_secs() >= target_duration_secs {
+            chunks.push(buffer.create_chunk());
+        }
+    }
+    chunks.extend(buffer.create_final_chunk());
+
+    crate::verbose!(
+        "Split {:.1}s of audio into {} chunks",
+        samples.len() as f32 / WHISPER_SAMPLE_RATE as f32,
+        chunks.len()
+    );
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEAKING: VadState = VadState {
+        is_speaking: true,
+        in_hangover: false,
+    };
+    const SILENT: VadState = VadState {
+        is_speaking: false,
+        in_hangover: false,
+    };
+
+    /// Utterance chunker running on its own task
+    fn spawn_utterance_chunker() -> (
+        mpsc::UnboundedSender<Vec<f32>>,
+        watch::Sender<VadState>,
+        mpsc::UnboundedReceiver<AudioChunk>,
+        tokio::task::JoinHandle<Result<(), String>>,
+    ) {
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        let (vad_tx, vad_rx) = watch::channel(SILENT);
+        let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            ProgressiveChunker::new(ChunkerConfig::utterances(), chunk_tx)
+                .with_utterances()
+                .consume_stream(audio_rx, Some(vad_rx))
+                .await
+        });
+        (audio_tx, vad_tx, chunk_rx, task)
+    }
+
+    /// Send `secs` of audio and give the chunker time to buffer it
+    async fn speak(audio_tx: &mpsc::UnboundedSender<Vec<f32>>, secs: f32) {
+        audio_tx
+            .send(vec![0.1; (secs * WHISPER_SAMPLE_RATE as f32) as usize])
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    /// Wait past `UTTERANCE_SETTLE`
+    async fn settle() {
+        tokio::time::sleep(UTTERANCE_SETTLE * 2).await;
+    }
+
+    #[tokio::test]
+    async fn utterances_are_cut_at_pauses_without_overlap() {
+        let (audio_tx, vad_tx, mut chunk_rx, task) = spawn_utterance_chunker();
+
+        vad_tx.send(SPEAKING).unwrap();
+        speak(&audio_tx, 1.0).await;
+        vad_tx.send(SILENT).unwrap();
+        settle().await;
+        let first = chunk_rx.try_recv().unwrap();
+        assert_eq!((first.index, first.start_sample), (0, 0));
+        assert_eq!(first.samples.len(), 16_000);
+
+        vad_tx.send(SPEAKING).unwrap();
+        speak(&audio_tx, 0.5).await;
+        vad_tx.send(SILENT).unwrap();
+        settle().await;
+        let second = chunk_rx.try_recv().unwrap();
+        assert_eq!((second.index, second.start_sample), (1, 16_000));
+        assert_eq!(second.samples.len(), 8_000);
+        assert!(!second.has_leading_overlap);
+
+        // A pause that ends before settling is not a cut
+        vad_tx.send(SPEAKING).unwrap();
+        speak(&audio_tx, 0.5).await;
+        vad_tx.send(SILENT).unwrap();
+        tokio::time::sleep(UTTERANCE_SETTLE / 3).await;
+        vad_tx.send(SPEAKING).unwrap();
+        speak(&audio_tx, 0.5).await;
+        settle().await;
+        assert!(chunk_rx.try_recv().is_err());
+
+        // The rest is sent when the stream ends
+        drop(audio_tx);
+        drop(vad_tx);
+        task.await.unwrap().unwrap();
+        let last = chunk_rx.try_recv().unwrap();
+        assert_eq!((last.index, last.start_sample), (2, 24_000));
+        assert_eq!(last.samples.len(), 16_000);
+        assert!(chunk_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn overlong_utterances_are_cut_at_the_maximum() {
+        let (audio_tx, vad_tx, mut chunk_rx, _task) = spawn_utterance_chunker();
+
+        vad_tx.send(SPEAKING).unwrap();
+        speak(&audio_tx, 30.0).await;
+
+        let chunk = chunk_rx.try_recv().unwrap();
+        assert_eq!(chunk.samples.len(), 30 * 16_000);
+    }
+}
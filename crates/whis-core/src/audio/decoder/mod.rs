@@ -0,0 +1,351 @@
+//! Audio file decoding via symphonia (pure Rust, no FFmpeg dependency)
+//!
+//! Decodes the first audio track of a file to 16kHz mono f32 samples ready for
+//! transcription. Supported containers and codecs:
+//! - WAV (PCM / ADPCM)
+//! - MP3
+//! - FLAC
+//! - OGG (Vorbis; Opus with `opus-decoder`)
+//! - MP4 / M4A (AAC, ALAC)
+//! - MKV / WebM (audio track only; video tracks are skipped)
+//!
+//! `stream_audio` decodes the same formats from a non-seekable reader such as
+//! stdin, except containers that keep their index at the end (most MP4/M4A).
+//!
+//! symphonia has no Opus codec, so Opus tracks (OGG, WebM) are decoded with
+//! libopus when the `opus-decoder` feature is enabled. Without it they fail
+//! with an error suggesting a conversion.
+
+#[cfg(feature = "opus-decoder")]
+mod opus;
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{
+    CODEC_TYPE_NULL, CODEC_TYPE_OPUS, CodecParameters, Decoder, DecoderOptions,
+};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::{MediaSourceStream, ReadOnlySource};
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use tokio::sync::mpsc;
+
+use crate::resample::{FrameResampler, resample_to_16k};
+
+/// File extensions `decode_audio_file` is expected to handle (for file pickers)
+///
+/// OGG and WebM files usually hold Opus, so they're only listed when it can
+/// be decoded.
+pub const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "wav",
+    "mp3",
+    "flac",
+    "m4a",
+    "mp4",
+    "m4v",
+    "mov",
+    "aac",
+    "mkv",
+    "mka",
+    #[cfg(feature = "opus-decoder")]
+    "ogg",
+    #[cfg(feature = "opus-decoder")]
+    "oga",
+    #[cfg(feature = "opus-decoder")]
+    "opus",
+    #[cfg(feature = "opus-decoder")]
+    "webm",
+];
+
+/// Decoder for the selected track
+enum TrackDecoder {
+    Symphonia {
+        decoder: Box<dyn Decoder>,
+        buffer: Option<SampleBuffer<f32>>,
+    },
+    #[cfg(feature = "opus-decoder")]
+    Opus(opus::OpusPacketDecoder),
+}
+
+/// Decode an audio or video file and return 16kHz mono f32 samples
+///
+/// The container is probed from its contents; the extension is only a hint,
+/// so mislabeled files still decode.
+pub fn decode_audio_file(path: &Path) -> Result<Vec<f32>> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let label = path.display().to_string();
+    let mut samples: Vec<f32> = Vec::new();
+    let mut spec = (0, 1);
+    decode_packets(stream, &hint, &label, |block, rate, channels| {
+        samples.extend_from_slice(block);
+        spec = (rate, channels);
+        true
+    })?;
+
+    let (sample_rate, channels) = spec;
+    if samples.is_empty() || sample_rate == 0 {
+        anyhow::bail!("No audio decoded from {label}");
+    }
+
+    crate::verbose!(
+        "Decoded {}: {:.1}s at {}Hz, {} channel(s)",
+        label,
+        samples.len() as f64 / f64::from(sample_rate) / f64::from(channels.max(1)),
+        sample_rate,
+        channels
+    );
+
+    resample_to_16k(&samples, sample_rate, channels)
+}
+
+/// Decode a self-describing audio stream (e.g. WAV, MP3 or FLAC on stdin)
+///
+/// Unlike `decode_audio_file`, the source doesn't need to be seekable and
+/// decoded audio is sent to `audio_tx` as 16kHz mono blocks while reading, so
+/// transcription can start before the stream ends. Containers that need
+/// seeking to find their index (most MP4/M4A files) can't be streamed.
+///
+/// Blocks until the stream ends or the receiver is dropped.
+pub fn stream_audio<R>(reader: R, audio_tx: &mpsc::UnboundedSender<Vec<f32>>) -> Result<()>
+where
+    R: Read + Send + Sync + 'static,
+{
+    let stream = MediaSourceStream::new(Box::new(ReadOnlySource::new(reader)), Default::default());
+
+    let mut resampler: Option<FrameResampler> = None;
+    let mut error = None;
+    decode_packets(stream, &Hint::new(), "stream", |block, rate, channels| {
+        let resampler = match &mut resampler {
+            Some(resampler) => resampler,
+            None => match FrameResampler::new(rate, channels) {
+                Ok(created) => resampler.insert(created),
+                Err(err) => {
+                    error = Some(err);
+                    return false;
+                }
+            },
+        };
+        let samples = resampler.process(block);
+        samples.is_empty() || audio_tx.send(samples).is_ok()
+    })?;
+
+    if let Some(err) = error {
+        return Err(err);
+    }
+    if let Some(mut resampler) = resampler {
+        let _ = audio_tx.send(resampler.flush());
+    }
+    Ok(())
+}
+
+/// Probe `stream`, decode its first audio track and pass each block of
+/// interleaved samples to `on_block` with the block's rate and channel count.
+///
+/// Stops early when `on_block` returns false.
+fn decode_packets(
+    stream: MediaSourceStream,
+    hint: &Hint,
+    label: &str,
+    mut on_block: impl FnMut(&[f32], u32, u16) -> bool,
+) -> Result<()> {
+    let probed = symphonia::default::get_probe()
+        .format(
+            hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .with_context(|| format!("Unsupported or corrupt media: {label}"))?;
+    let mut format = probed.format;
+
+    // Video containers list video/subtitle tracks too; take the first audio one
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL && t.codec_params.sample_rate.is_some())
+        .ok_or_else(|| anyhow::anyhow!("No audio track found in {label}"))?;
+    let track_id = track.id;
+
+    let mut decoder = if track.codec_params.codec == CODEC_TYPE_OPUS {
+        opus_decoder(&track.codec_params, label)?
+    } else {
+        TrackDecoder::Symphonia {
+            decoder: symphonia::default::get_codecs()
+                .make(&track.codec_params, &DecoderOptions::default())
+                .context("Unsupported audio codec")?,
+            buffer: None,
+        }
+    };
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            // End of stream is reported as an unexpected EOF
+            Err(SymphoniaError::IoError(err))
+                if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(err) => return Err(err).context("Failed to read audio packet"),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let keep_going = match &mut decoder {
+            TrackDecoder::Symphonia { decoder, buffer } => {
+                let decoded = match decoder.decode(&packet) {
+                    Ok(decoded) => decoded,
+                    // A corrupt frame shouldn't fail the whole file
+                    Err(SymphoniaError::DecodeError(err)) => {
+                        crate::verbose!("Skipping undecodable audio packet: {}", err);
+                        continue;
+                    }
+                    Err(err) => return Err(err).context("Failed to decode audio"),
+                };
+
+                let spec = *decoded.spec();
+
+                // Packets never exceed the codec's max frame size, so one buffer fits all
+                let capacity = decoded.capacity() as u64;
+                let buf = buffer.get_or_insert_with(|| SampleBuffer::new(capacity, spec));
+                buf.copy_interleaved_ref(decoded);
+
+                on_block(buf.samples(), spec.rate, spec.channels.count() as u16)
+            }
+            #[cfg(feature = "opus-decoder")]
+            TrackDecoder::Opus(decoder) => {
+                let channels = decoder.channels();
+                match decoder.decode(packet.buf()) {
+                    Some(samples) if !samples.is_empty() => {
+                        on_block(samples, opus::SAMPLE_RATE, channels)
+                    }
+                    _ => continue,
+                }
+            }
+        };
+
+        if !keep_going {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Decoder for an Opus track (decoded with libopus, see the `opus` module)
+#[cfg(feature = "opus-decoder")]
+fn opus_decoder(params: &CodecParameters, _label: &str) -> Result<TrackDecoder> {
+    Ok(TrackDecoder::Opus(opus::OpusPacketDecoder::new(params)?))
+}
+
+/// Opus tracks can't be decoded without libopus
+#[cfg(not(feature = "opus-decoder"))]
+fn opus_decoder(_params: &CodecParameters, label: &str) -> Result<TrackDecoder> {
+    anyhow::bail!(
+        "Opus audio needs the 'opus-decoder' feature: {label}\nConvert it first, e.g. ffmpeg -i input.opus output.flac"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// One second of a 440Hz tone as 16-bit interleaved samples
+    fn tone(sample_rate: u32, channels: u16) -> Vec<i16> {
+        (0..sample_rate)
+            .flat_map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                let sample = ((2.0 * std::f32::consts::PI * 440.0 * t).sin() * 8000.0) as i16;
+                std::iter::repeat_n(sample, channels as usize)
+            })
+            .collect()
+    }
+
+    /// 16-bit PCM WAV file bytes
+    fn wav(samples: &[i16], sample_rate: u32, channels: u16) -> Vec<u8> {
+        let data_len = (samples.len() * 2) as u32;
+        let block_align = channels * 2;
+        let mut bytes = Vec::with_capacity(44 + data_len as usize);
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&(sample_rate * u32::from(block_align)).to_le_bytes());
+        bytes.extend_from_slice(&block_align.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("whis-decoder-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn assert_about_one_second(samples: &[f32], tolerance: usize) {
+        let expected = 16_000;
+        assert!(
+            samples.len().abs_diff(expected) <= tolerance,
+            "decoded {} samples, expected about {expected}",
+            samples.len()
+        );
+    }
+
+    #[test]
+    fn decodes_stereo_wav_file_to_16k_mono() {
+        let path = temp_file("stereo.wav", &wav(&tone(48_000, 2), 48_000, 2));
+        let samples = decode_audio_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        let samples = samples.unwrap();
+        assert_about_one_second(&samples, 160);
+        assert!(samples.iter().any(|s| s.abs() > 0.1));
+    }
+
+    #[test]
+    fn streams_wav_from_non_seekable_reader() {
+        let bytes = wav(&tone(16_000, 1), 16_000, 1);
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel();
+        stream_audio(std::io::Cursor::new(bytes), &audio_tx).unwrap();
+        drop(audio_tx);
+
+        let mut samples = Vec::new();
+        while let Ok(block) = audio_rx.try_recv() {
+            samples.extend(block);
+        }
+        assert_about_one_second(&samples, 160);
+    }
+
+    #[test]
+    fn rejects_files_that_are_not_media() {
+        let path = temp_file("notes.mp3", b"not audio at all, just some text");
+        let result = decode_audio_file(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(result.is_err());
+    }
+}
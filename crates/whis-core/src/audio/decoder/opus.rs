@@ -0,0 +1,79 @@
+//! Opus packet decoding via libopus (the opus crate).
+//!
+//! symphonia demuxes Opus tracks from OGG and MKV/WebM but has no Opus codec,
+//! so their packets are decoded here. Output is interleaved f32 at 48kHz, the
+//! rate Opus always decodes to, with the encoder's pre-skip trimmed.
+
+use anyhow::{Context, Result};
+use opus::{Channels, Decoder};
+use symphonia::core::codecs::CodecParameters;
+
+/// Opus always decodes at 48kHz
+pub(super) const SAMPLE_RATE: u32 = 48_000;
+
+/// Longest Opus packet: 120ms per channel at 48kHz
+const MAX_PACKET_FRAMES: usize = 5_760;
+
+/// Decoder for the packets of one Opus track (mono or stereo)
+pub(super) struct OpusPacketDecoder {
+    decoder: Decoder,
+    channels: u16,
+    /// Samples per channel still to drop from the start (encoder delay)
+    pre_skip: usize,
+    buffer: Vec<f32>,
+}
+
+impl OpusPacketDecoder {
+    pub fn new(params: &CodecParameters) -> Result<Self> {
+        let head = params
+            .extra_data
+            .as_deref()
+            .filter(|head| head.len() >= 12 && head.starts_with(b"OpusHead"));
+
+        let channels = params
+            .channels
+            .map(|channels| channels.count() as u16)
+            .or_else(|| head.map(|head| u16::from(head[9])))
+            .unwrap_or(1);
+        let opus_channels = match channels {
+            1 => Channels::Mono,
+            2 => Channels::Stereo,
+            other => anyhow::bail!("Opus with {other} channels is not supported"),
+        };
+
+        // OGG reports the pre-skip as the codec delay; WebM only has the OpusHead
+        let pre_skip = params
+            .delay
+            .or_else(|| head.map(|head| u32::from(u16::from_le_bytes([head[10], head[11]]))))
+            .unwrap_or(0) as usize;
+
+        Ok(Self {
+            decoder: Decoder::new(SAMPLE_RATE, opus_channels)
+                .context("Failed to create Opus decoder")?,
+            channels,
+            pre_skip,
+            buffer: vec![0.0; MAX_PACKET_FRAMES * channels as usize],
+        })
+    }
+
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    /// Decode one packet to interleaved samples (None for corrupt packets)
+    pub fn decode(&mut self, packet: &[u8]) -> Option<&[f32]> {
+        let frames = match self.decoder.decode_float(packet, &mut self.buffer, false) {
+            Ok(frames) => frames,
+            Err(err) => {
+                crate::verbose!("Skipping undecodable Opus packet: {}", err);
+                return None;
+            }
+        };
+
+        let skip = self.pre_skip.min(frames);
+        self.pre_skip -= skip;
+
+        let channels = self.channels as usize;
+        Some(&self.buffer[skip * channels..frames * channels])
+    }
+}
@@ -0,0 +1,121 @@
+//! RNNoise noise suppression via nnnoiseless
+//!
+//! RNNoise works on 48kHz frames of 480 samples, so the 16kHz recording
+//! stream is upsampled, denoised and downsampled again. Strength blends the
+//! denoised signal with the original to keep speech natural at low settings.
+
+use anyhow::{Context, Result};
+use nnnoiseless::DenoiseState;
+
+use crate::resample::{FrameResampler, WHISPER_SAMPLE_RATE};
+
+/// Sample rate RNNoise operates at
+const DENOISE_SAMPLE_RATE: u32 = 48000;
+
+/// Samples per RNNoise frame (10ms at 48kHz)
+const FRAME_SIZE: usize = DenoiseState::FRAME_SIZE;
+
+/// RNNoise expects samples in 16-bit PCM range
+const PCM_SCALE: f32 = i16::MAX as f32;
+
+/// Noise suppressor for 16kHz mono audio
+pub struct Denoiser {
+    state: Box<DenoiseState<'static>>,
+    /// Mix of denoised vs original signal (0.0-1.0)
+    strength: f32,
+    /// 16kHz -> 48kHz
+    upsampler: FrameResampler,
+    /// 48kHz -> 16kHz
+    downsampler: FrameResampler,
+    /// 48kHz samples waiting for a full frame
+    pending: Vec<f32>,
+    /// Previous input frame (RNNoise output lags its input by one frame)
+    previous: Vec<f32>,
+    /// Whether the first output frame (warm-up, no matching input) was dropped
+    primed: bool,
+    frame_in: Vec<f32>,
+    frame_out: Vec<f32>,
+}
+
+impl Denoiser {
+    /// Create a new denoiser
+    ///
+    /// # Arguments
+    /// * `strength` - How much of the denoised signal to use (0.0-1.0)
+    pub fn new(strength: f32) -> Result<Self> {
+        let upsampler = FrameResampler::with_rates(WHISPER_SAMPLE_RATE, DENOISE_SAMPLE_RATE, 1)
+            .context("Failed to create denoise upsampler")?;
+        let downsampler = FrameResampler::with_rates(DENOISE_SAMPLE_RATE, WHISPER_SAMPLE_RATE, 1)
+            .context("Failed to create denoise downsampler")?;
+
+        Ok(Self {
+            state: DenoiseState::new(),
+            strength: strength.clamp(0.0, 1.0),
+            upsampler,
+            downsampler,
+            pending: Vec::with_capacity(FRAME_SIZE * 2),
+            previous: vec![0.0; FRAME_SIZE],
+            primed: false,
+            frame_in: vec![0.0; FRAME_SIZE],
+            frame_out: vec![0.0; FRAME_SIZE],
+        })
+    }
+
+    /// Denoise 16kHz mono samples
+    ///
+    /// Output is delayed by the resamplers and one RNNoise frame, so it may
+    /// be shorter than the input until `flush` is called.
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        let upsampled = self.upsampler.process(samples);
+        let denoised = self.denoise(&upsampled);
+        self.downsampler.process(&denoised)
+    }
+
+    /// Flush remaining buffered samples at the end of recording.
+    pub fn flush(&mut self) -> Vec<f32> {
+        let upsampled = self.upsampler.flush();
+        let mut denoised = self.denoise(&upsampled);
+
+        // Pad the partial frame, then push one silent frame through so the
+        // lagged output of the last real frame comes out
+        if !self.pending.is_empty() {
+            let padding = FRAME_SIZE - self.pending.len();
+            denoised.extend(self.denoise(&vec![0.0; padding]));
+        }
+        denoised.extend(self.denoise(&[0.0; FRAME_SIZE]));
+
+        let mut output = self.downsampler.process(&denoised);
+        output.extend(self.downsampler.flush());
+        output
+    }
+
+    /// Run complete 48kHz frames through RNNoise
+    fn denoise(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.pending.extend_from_slice(samples);
+
+        let frames = self.pending.len() / FRAME_SIZE;
+        let mut output = Vec::with_capacity(frames * FRAME_SIZE);
+
+        for frame in self.pending.chunks_exact(FRAME_SIZE) {
+            for (scaled, sample) in self.frame_in.iter_mut().zip(frame) {
+                *scaled = sample * PCM_SCALE;
+            }
+            self.state
+                .process_frame(&mut self.frame_out, &self.frame_in);
+
+            if self.primed {
+                output.extend(self.frame_out.iter().zip(&self.previous).map(
+                    |(denoised, original)| {
+                        self.strength * denoised / PCM_SCALE + (1.0 - self.strength) * original
+                    },
+                ));
+            } else {
+                self.primed = true;
+            }
+            self.previous.copy_from_slice(frame);
+        }
+
+        self.pending.drain(..frames * FRAME_SIZE);
+        output
+    }
+}
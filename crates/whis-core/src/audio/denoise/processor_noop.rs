@@ -0,0 +1,29 @@
+//! No-op denoiser implementation (used when denoise feature is disabled)
+//!
+//! Provides the same API as the real denoiser, but passes audio through.
+
+use anyhow::Result;
+
+/// No-op noise suppressor
+///
+/// This implementation is used when the "denoise" feature is disabled.
+#[derive(Debug, Clone)]
+pub struct Denoiser;
+
+impl Denoiser {
+    /// Create a new no-op denoiser
+    pub fn new(_strength: f32) -> Result<Self> {
+        crate::verbose!("Noise suppression not available in this build (enable 'denoise')");
+        Ok(Self)
+    }
+
+    /// Process audio samples (passthrough)
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        samples.to_vec()
+    }
+
+    /// Flush remaining buffered samples (no-op - returns empty vec)
+    pub fn flush(&mut self) -> Vec<f32> {
+        Vec::new()
+    }
+}
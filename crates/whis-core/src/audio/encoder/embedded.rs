@@ -7,11 +7,15 @@
 use anyhow::{Context, Result};
 use mp3lame_encoder::{Builder, FlushNoGap, InterleavedPcm, MonoPcm};
 
-use super::AudioEncoder;
+use super::{AudioEncoder, StreamingEncoder};
+
+/// Bytes LAME may emit when flushing (see lame.h)
+const FLUSH_BUFFER_SIZE: usize = 7200;
 
 /// MP3 encoder using embedded LAME library.
 ///
 /// Uses mp3lame-encoder crate for high-quality MP3 encoding without external dependencies.
+#[derive(Clone)]
 pub struct EmbeddedEncoder {
     channels: u16,
 }
@@ -106,6 +110,46 @@ impl EmbeddedEncoder {
     }
 }
 
+/// Incremental MP3 encoding with one LAME instance per stream
+struct Mp3Stream {
+    encoder: mp3lame_encoder::Encoder,
+    mp3_data: Vec<u8>,
+}
+
+impl StreamingEncoder for Mp3Stream {
+    fn push(&mut self, samples: &[f32]) -> Result<()> {
+        let i16_samples = EmbeddedEncoder::new().samples_to_i16(samples);
+        self.mp3_data
+            .reserve(mp3lame_encoder::max_required_buffer_size(i16_samples.len()));
+
+        let encoded_size = self
+            .encoder
+            .encode(MonoPcm(&i16_samples), self.mp3_data.spare_capacity_mut())
+            .map_err(|e| anyhow::anyhow!("Failed to encode MP3: {:?}", e))?;
+
+        // SAFETY: encode returns the number of bytes it initialized in the
+        // spare capacity, directly after the current length.
+        unsafe {
+            self.mp3_data.set_len(self.mp3_data.len() + encoded_size);
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<Vec<u8>> {
+        self.mp3_data.reserve(FLUSH_BUFFER_SIZE);
+        let flush_size = self
+            .encoder
+            .flush::<FlushNoGap>(self.mp3_data.spare_capacity_mut())
+            .map_err(|e| anyhow::anyhow!("Failed to flush MP3 encoder: {:?}", e))?;
+
+        // SAFETY: flush returns the number of additional bytes written.
+        unsafe {
+            self.mp3_data.set_len(self.mp3_data.len() + flush_size);
+        }
+        Ok(self.mp3_data)
+    }
+}
+
 impl Default for EmbeddedEncoder {
     fn default() -> Self {
         Self::new()
@@ -113,6 +157,13 @@ impl Default for EmbeddedEncoder {
 }
 
 impl AudioEncoder for EmbeddedEncoder {
+    fn start_stream(&self, sample_rate: u32) -> Result<Box<dyn StreamingEncoder>> {
+        Ok(Box::new(Mp3Stream {
+            encoder: self.build_encoder(sample_rate)?,
+            mp3_data: Vec::new(),
+        }))
+    }
+
     fn encode_samples(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<u8>> {
         // Convert f32 samples to i16
         let i16_samples = self.samples_to_i16(samples);
@@ -1,21 +1,196 @@
-//! Audio encoding module providing MP3 encoding via embedded LAME encoder.
+//! Audio encoding for uploads to cloud providers.
+//!
+//! - MP3 via embedded LAME (`embedded-encoder`, default)
+//! - Opus in OGG via libopus (`opus-encoder`), ~24 kbps speech for slow links
+//! - FLAC via flacenc (`flac-encoder`), lossless
+//!
+//! The format for a chunk is picked by [`upload_format_for`]: the configured
+//! `upload_format` if the provider accepts it, otherwise the provider's most
+//! preferred format that is compiled in.
+//!
+//! Encoders can also run incrementally ([`AudioEncoder::start_stream`]) so a
+//! recording's chunks are compressed while they're recorded rather than after
+//! they're cut. Encoders that only work on whole inputs (FLAC) keep the default,
+//! which buffers the samples and encodes them on `finish`.
 
 #[cfg(feature = "embedded-encoder")]
 mod embedded;
+#[cfg(feature = "flac-encoder")]
+mod flac;
+#[cfg(feature = "opus-encoder")]
+mod opus;
+
+use std::fmt;
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::config::TranscriptionProvider;
 
 /// Trait for encoding raw audio samples to compressed formats.
-pub trait AudioEncoder: Send + Sync {
-    /// Encode raw f32 PCM samples to MP3.
+pub trait AudioEncoder: Send + Sync + CloneEncoder {
+    /// Encode raw f32 PCM samples to the encoder's format.
     ///
     /// # Parameters
     /// - `samples`: Raw audio samples (f32 PCM, expected to be 16kHz mono)
     /// - `sample_rate`: Sample rate of the input audio
     ///
     /// # Returns
-    /// Encoded MP3 data as bytes
+    /// Encoded file data as bytes
     fn encode_samples(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<u8>>;
+
+    /// Start encoding audio that arrives incrementally.
+    ///
+    /// Push samples as they're recorded, then `finish` to get the same file
+    /// `encode_samples` would produce for the concatenated input.
+    ///
+    /// The default buffers everything and calls `encode_samples` on `finish`,
+    /// so no work is saved; override it for encoders that can run in blocks.
+    fn start_stream(&self, sample_rate: u32) -> Result<Box<dyn StreamingEncoder>> {
+        Ok(Box::new(BufferedStream {
+            encoder: self.clone_encoder(),
+            samples: Vec::new(),
+            sample_rate,
+        }))
+    }
+}
+
+/// Owned copy of an encoder, for streams that outlive the `&self` they came from
+///
+/// Implemented for every `Clone` encoder.
+pub trait CloneEncoder {
+    fn clone_encoder(&self) -> Box<dyn AudioEncoder>;
+}
+
+impl<T: AudioEncoder + Clone + 'static> CloneEncoder for T {
+    fn clone_encoder(&self) -> Box<dyn AudioEncoder> {
+        Box::new(self.clone())
+    }
+}
+
+/// An encoding in progress (see [`AudioEncoder::start_stream`])
+pub trait StreamingEncoder: Send {
+    /// Encode the next samples (f32 PCM, same rate as the stream)
+    fn push(&mut self, samples: &[f32]) -> Result<()>;
+
+    /// Flush buffered audio and return the complete file
+    fn finish(self: Box<Self>) -> Result<Vec<u8>>;
+}
+
+/// Default stream: collects samples and encodes them in one go on `finish`
+struct BufferedStream {
+    encoder: Box<dyn AudioEncoder>,
+    samples: Vec<f32>,
+    sample_rate: u32,
+}
+
+impl StreamingEncoder for BufferedStream {
+    fn push(&mut self, samples: &[f32]) -> Result<()> {
+        self.samples.extend_from_slice(samples);
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<u8>> {
+        self.encoder.encode_samples(&self.samples, self.sample_rate)
+    }
+}
+
+/// Compressed format audio is uploaded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadFormat {
+    Mp3,
+    Opus,
+    Flac,
+}
+
+impl UploadFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UploadFormat::Mp3 => "mp3",
+            UploadFormat::Opus => "opus",
+            UploadFormat::Flac => "flac",
+        }
+    }
+
+    /// File extension used in the upload filename
+    pub fn extension(&self) -> &'static str {
+        match self {
+            UploadFormat::Mp3 => "mp3",
+            // Opus is wrapped in OGG; providers detect the format from ".ogg"
+            UploadFormat::Opus => "ogg",
+            UploadFormat::Flac => "flac",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            UploadFormat::Mp3 => "audio/mpeg",
+            UploadFormat::Opus => "audio/ogg",
+            UploadFormat::Flac => "audio/flac",
+        }
+    }
+
+    /// Whether an encoder for this format is compiled in
+    pub fn is_available(&self) -> bool {
+        match self {
+            UploadFormat::Mp3 => cfg!(feature = "embedded-encoder"),
+            UploadFormat::Opus => cfg!(feature = "opus-encoder"),
+            UploadFormat::Flac => cfg!(feature = "flac-encoder"),
+        }
+    }
+}
+
+impl fmt::Display for UploadFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for UploadFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mp3" => Ok(UploadFormat::Mp3),
+            "opus" | "ogg" => Ok(UploadFormat::Opus),
+            "flac" => Ok(UploadFormat::Flac),
+            _ => Err(format!(
+                "Unknown upload format: {}. Available: mp3, opus, flac",
+                s
+            )),
+        }
+    }
+}
+
+/// Format to upload to `provider` in
+///
+/// Uses `preferred` (the `upload_format` setting) when it's compiled in and
+/// the provider accepts it, otherwise the first compiled-in entry of
+/// `provider.upload_formats()`. Falls back to MP3.
+pub fn upload_format_for(
+    provider: &TranscriptionProvider,
+    preferred: Option<UploadFormat>,
+) -> UploadFormat {
+    let accepted = provider.upload_formats();
+    let usable = |format: &UploadFormat| format.is_available() && accepted.contains(format);
+
+    if let Some(format) = preferred {
+        if usable(&format) {
+            return format;
+        }
+        crate::verbose!(
+            "{} upload not available for {}, choosing automatically",
+            format,
+            provider
+        );
+    }
+
+    accepted
+        .iter()
+        .copied()
+        .find(|format| format.is_available())
+        .unwrap_or(UploadFormat::Mp3)
 }
 
 /// Create the audio encoder using embedded LAME library.
@@ -33,3 +208,118 @@ pub fn create_encoder() -> Box<dyn AudioEncoder> {
         panic!("No audio encoder available. Enable the 'embedded-encoder' feature.");
     }
 }
+
+/// Start a streaming encoder for an upload format.
+pub fn start_stream_encoder(
+    format: UploadFormat,
+    sample_rate: u32,
+) -> Result<Box<dyn StreamingEncoder>> {
+    create_encoder_for(format)?.start_stream(sample_rate)
+}
+
+/// Create the encoder for an upload format.
+///
+/// Fails if the format's cargo feature isn't enabled.
+pub fn create_encoder_for(format: UploadFormat) -> Result<Box<dyn AudioEncoder>> {
+    match format {
+        #[cfg(feature = "embedded-encoder")]
+        UploadFormat::Mp3 => Ok(Box::new(embedded::EmbeddedEncoder::new())),
+        #[cfg(feature = "opus-encoder")]
+        UploadFormat::Opus => Ok(Box::new(opus::OpusEncoder::new())),
+        #[cfg(feature = "flac-encoder")]
+        UploadFormat::Flac => Ok(Box::new(flac::FlacEncoder::new())),
+        #[allow(unreachable_patterns)]
+        other => anyhow::bail!(
+            "No {} encoder available in this build (enable the '{}' feature)",
+            other,
+            match other {
+                UploadFormat::Mp3 => "embedded-encoder",
+                UploadFormat::Opus => "opus-encoder",
+                UploadFormat::Flac => "flac-encoder",
+            }
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encoder without a streaming implementation: "encodes" to the sample count
+    #[derive(Clone)]
+    struct CountingEncoder;
+
+    impl AudioEncoder for CountingEncoder {
+        fn encode_samples(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<u8>> {
+            Ok(format!("{}@{}", samples.len(), sample_rate).into_bytes())
+        }
+    }
+
+    #[test]
+    fn default_stream_encodes_all_pushed_samples_on_finish() {
+        let mut stream = CountingEncoder.start_stream(16_000).unwrap();
+        stream.push(&[0.0; 100]).unwrap();
+        stream.push(&[]).unwrap();
+        stream.push(&[0.5; 60]).unwrap();
+
+        assert_eq!(stream.finish().unwrap(), b"160@16000");
+    }
+
+    #[test]
+    fn upload_format_prefers_setting_when_provider_accepts_it() {
+        let local = TranscriptionProvider::LocalWhisper;
+        assert_eq!(
+            upload_format_for(&local, Some(UploadFormat::Flac)),
+            UploadFormat::Mp3
+        );
+        assert_eq!(upload_format_for(&local, None), UploadFormat::Mp3);
+
+        let openai = TranscriptionProvider::OpenAI;
+        for format in [UploadFormat::Mp3, UploadFormat::Opus, UploadFormat::Flac] {
+            if format.is_available() {
+                assert_eq!(upload_format_for(&openai, Some(format)), format);
+            }
+        }
+    }
+
+    /// Encode one second of a 440Hz tone at 16kHz and decode it back
+    #[cfg(feature = "file-decoding")]
+    fn round_trip(format: UploadFormat) -> Vec<f32> {
+        let samples: Vec<f32> = (0..16_000)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16_000.0).sin() * 0.25)
+            .collect();
+        let encoded = create_encoder_for(format)
+            .unwrap()
+            .encode_samples(&samples, 16_000)
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "whis-encoder-{}.{}",
+            std::process::id(),
+            format.extension()
+        ));
+        std::fs::write(&path, encoded).unwrap();
+        let decoded = crate::audio::decode_audio_file(&path);
+        std::fs::remove_file(&path).ok();
+        decoded.unwrap()
+    }
+
+    #[cfg(all(feature = "opus-encoder", feature = "opus-decoder"))]
+    #[test]
+    fn opus_upload_decodes_back_to_the_input() {
+        let decoded = round_trip(UploadFormat::Opus);
+
+        // Pre-skip is trimmed; the last frame may carry up to 20ms of padding
+        assert!(decoded.len().abs_diff(16_000) <= 480, "{}", decoded.len());
+        assert!(decoded.iter().any(|s| s.abs() > 0.1));
+    }
+
+    #[cfg(all(feature = "flac-encoder", feature = "file-decoding"))]
+    #[test]
+    fn flac_upload_decodes_back_to_the_input() {
+        let decoded = round_trip(UploadFormat::Flac);
+
+        assert_eq!(decoded.len(), 16_000);
+        assert!(decoded.iter().any(|s| s.abs() > 0.1));
+    }
+}
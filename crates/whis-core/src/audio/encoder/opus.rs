@@ -0,0 +1,186 @@
+//! Opus-in-OGG encoder implementation.
+//!
+//! Uses libopus (via the opus crate) in VoIP mode and wraps the packets in an
+//! OGG container (RFC 7845), which every provider accepting ".ogg" decodes.
+//! At 24 kbps a 90s chunk is ~270 KB versus ~1.4 MB of 128 kbps MP3.
+
+use anyhow::{Context, Result};
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+use opus::{Application, Bitrate, Channels, Encoder};
+
+use super::{AudioEncoder, StreamingEncoder};
+
+/// Target bitrate for speech (Opus is transparent for speech from ~16 kbps)
+const BITRATE: i32 = 24_000;
+/// Frame length in milliseconds
+const FRAME_MS: u32 = 20;
+/// OGG Opus granule positions are always counted at 48kHz
+const GRANULE_RATE: u64 = 48_000;
+/// Serial number of the single logical stream
+const STREAM_SERIAL: u32 = 0x7768_6973; // "whis"
+
+/// Opus encoder producing OGG Opus files (mono)
+#[derive(Clone, Default)]
+pub struct OpusEncoder;
+
+impl OpusEncoder {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// OpusHead identification header (RFC 7845 §5.1)
+fn opus_head(sample_rate: u32, pre_skip: u16) -> Vec<u8> {
+    let mut head = Vec::with_capacity(19);
+    head.extend_from_slice(b"OpusHead");
+    head.push(1); // version
+    head.push(1); // channels
+    head.extend_from_slice(&pre_skip.to_le_bytes());
+    head.extend_from_slice(&sample_rate.to_le_bytes());
+    head.extend_from_slice(&0i16.to_le_bytes()); // output gain
+    head.push(0); // channel mapping family (mono/stereo)
+    head
+}
+
+/// OpusTags comment header (RFC 7845 §5.2)
+fn opus_tags() -> Vec<u8> {
+    let vendor = concat!("whis ", env!("CARGO_PKG_VERSION"));
+    let mut tags = Vec::new();
+    tags.extend_from_slice(b"OpusTags");
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor.as_bytes());
+    tags.extend_from_slice(&0u32.to_le_bytes()); // no user comments
+    tags
+}
+
+/// Incremental OGG Opus encoding
+///
+/// The newest packet is held back until the next one arrives, so the last
+/// packet can carry the end-of-stream flag and the exact final granule.
+struct OpusStream {
+    encoder: Encoder,
+    writer: PacketWriter<'static, Vec<u8>>,
+    /// Samples waiting for a full frame
+    pending: Vec<f32>,
+    frame_size: usize,
+    granule_scale: u64,
+    pre_skip: u64,
+    /// Samples pushed so far (excluding padding)
+    total_samples: u64,
+    /// Frames encoded so far
+    frames: u64,
+    held: Option<Vec<u8>>,
+    packet: Vec<u8>,
+}
+
+impl OpusStream {
+    fn new(sample_rate: u32) -> Result<Self> {
+        let mut encoder = Encoder::new(sample_rate, Channels::Mono, Application::Voip)
+            .context("Failed to create Opus encoder")?;
+        encoder
+            .set_bitrate(Bitrate::Bits(BITRATE))
+            .context("Failed to set Opus bitrate")?;
+
+        // Granules advance in 48kHz units regardless of the input rate
+        let granule_scale = GRANULE_RATE / u64::from(sample_rate);
+        let lookahead = encoder
+            .get_lookahead()
+            .context("Failed to query Opus lookahead")?;
+        let pre_skip = lookahead as u64 * granule_scale;
+
+        let mut writer = PacketWriter::new(Vec::new());
+        writer
+            .write_packet(
+                opus_head(sample_rate, pre_skip as u16),
+                STREAM_SERIAL,
+                PacketWriteEndInfo::EndPage,
+                0,
+            )
+            .context("Failed to write OpusHead")?;
+        writer
+            .write_packet(opus_tags(), STREAM_SERIAL, PacketWriteEndInfo::EndPage, 0)
+            .context("Failed to write OpusTags")?;
+
+        Ok(Self {
+            encoder,
+            writer,
+            pending: Vec::new(),
+            frame_size: (sample_rate * FRAME_MS / 1000) as usize,
+            granule_scale,
+            pre_skip,
+            total_samples: 0,
+            frames: 0,
+            held: None,
+            packet: vec![0u8; 4000],
+        })
+    }
+
+    /// Encode one frame and write the previously held packet
+    fn encode_frame(&mut self, frame: &[f32]) -> Result<()> {
+        let len = self
+            .encoder
+            .encode_float(frame, &mut self.packet)
+            .context("Failed to encode Opus frame")?;
+
+        if let Some(previous) = self.held.take() {
+            let granule = self.pre_skip + self.frames * self.frame_size as u64 * self.granule_scale;
+            self.writer
+                .write_packet(
+                    previous,
+                    STREAM_SERIAL,
+                    PacketWriteEndInfo::NormalPacket,
+                    granule,
+                )
+                .context("Failed to write Opus packet")?;
+        }
+
+        self.frames += 1;
+        self.held = Some(self.packet[..len].to_vec());
+        Ok(())
+    }
+}
+
+impl StreamingEncoder for OpusStream {
+    fn push(&mut self, samples: &[f32]) -> Result<()> {
+        self.total_samples += samples.len() as u64;
+        self.pending.extend_from_slice(samples);
+
+        let full = self.pending.len() - self.pending.len() % self.frame_size;
+        let pending = std::mem::take(&mut self.pending);
+        for frame in pending[..full].chunks_exact(self.frame_size) {
+            self.encode_frame(frame)?;
+        }
+        self.pending = pending[full..].to_vec();
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<Vec<u8>> {
+        // The last frame is zero-padded; the final granule trims the padding
+        if !self.pending.is_empty() || self.held.is_none() {
+            let mut frame = std::mem::take(&mut self.pending);
+            frame.resize(self.frame_size, 0.0);
+            self.encode_frame(&frame)?;
+        }
+
+        let granule = self.pre_skip + self.total_samples * self.granule_scale;
+        if let Some(last) = self.held.take() {
+            self.writer
+                .write_packet(last, STREAM_SERIAL, PacketWriteEndInfo::EndStream, granule)
+                .context("Failed to write Opus packet")?;
+        }
+
+        Ok(self.writer.into_inner())
+    }
+}
+
+impl AudioEncoder for OpusEncoder {
+    fn start_stream(&self, sample_rate: u32) -> Result<Box<dyn StreamingEncoder>> {
+        Ok(Box::new(OpusStream::new(sample_rate)?))
+    }
+
+    fn encode_samples(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<u8>> {
+        let mut stream = self.start_stream(sample_rate)?;
+        stream.push(samples)?;
+        stream.finish()
+    }
+}
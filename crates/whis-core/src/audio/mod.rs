@@ -2,8 +2,12 @@
 //!
 //! This module provides cross-platform audio recording with the following features:
 //! - Real-time resampling to 16kHz mono
-//! - Voice Activity Detection (optional, via `vad` feature)
-//! - MP3 encoding via embedded encoder
+//! - Live input level metering (RMS/peak, silent microphone detection)
+//! - Noise suppression (optional, via `denoise` feature)
+//! - Voice Activity Detection (Silero via `vad` feature, built-in energy fallback)
+//! - MP3 encoding via embedded encoder (Opus/FLAC via `opus-encoder`/`flac-encoder`)
+//! - Audio/video file decoding (optional, via `file-decoding` feature)
+//! - Streaming input from raw PCM or encoded pipes (e.g. stdin)
 //!
 //! # Architecture
 //!
@@ -11,6 +15,7 @@
 //! AudioRecorder
 //!   ├── Stream (cpal) - Platform-specific audio capture
 //!   ├── Resampler     - Real-time 16kHz conversion
+//!   ├── Denoiser (opt)- RNNoise noise suppression
 //!   ├── VAD (optional)- Voice activity detection
 //!   └── Encoder       - MP3 encoding
 //! ```
@@ -34,9 +39,13 @@
 //! - **Linux**: ALSA stderr suppression via safe FFI wrapper
 
 pub mod chunker;
+#[cfg(feature = "file-decoding")]
+mod decoder;
+mod denoise;
 mod devices;
 mod encoder;
 pub mod error;
+mod pcm;
 mod recorder;
 mod types;
 mod vad;
@@ -46,12 +55,28 @@ mod vad;
 mod pulse;
 
 // Re-export public types
-pub use chunker::{AudioChunk as ProgressiveChunk, ChunkerConfig, ProgressiveChunker};
+pub use chunker::{
+    AudioChunk as ProgressiveChunk, ChunkerConfig, ProgressiveChunker, chunk_samples,
+};
+#[cfg(feature = "file-decoding")]
+pub use decoder::{SUPPORTED_EXTENSIONS, decode_audio_file, stream_audio};
 pub use devices::list_audio_devices;
-pub use encoder::{AudioEncoder, create_encoder};
+pub use encoder::{
+    AudioEncoder, StreamingEncoder, UploadFormat, create_encoder, create_encoder_for,
+    start_stream_encoder, upload_format_for,
+};
 pub use error::AudioError;
-pub use recorder::{AudioRecorder, AudioStreamSender, RecorderConfig, RecordingData};
+pub use pcm::{PcmEncoding, RawPcmFormat, stream_raw_pcm};
+pub use recorder::{
+    AudioLevel, AudioRecorder, AudioStreamSender, RecorderConfig, RecordingData,
+    SILENT_INPUT_WARNING_SECS,
+};
 pub use types::AudioDeviceInfo;
 
-// Re-export VAD types (always available - no-op when feature disabled)
-pub use vad::{VadConfig, VadProcessor, VadState};
+// Re-export denoise types (always available - no-op when feature disabled)
+pub use denoise::{DenoiseConfig, Denoiser};
+
+// Re-export VAD types (always available - energy engine when feature disabled)
+pub use vad::{
+    AutoStopConfig, AutoStopReason, VadConfig, VadEngine, VadFrame, VadProcessor, VadState,
+};
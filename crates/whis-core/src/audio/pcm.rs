@@ -0,0 +1,267 @@
+//! Raw PCM stream input
+//!
+//! Reads headerless little-endian PCM (as produced by `parec`, `ffmpeg -f s16le`
+//! or `sox -t raw`) from any reader and forwards 16kHz mono blocks as they
+//! arrive, so transcription can start before the stream ends.
+
+use std::fmt;
+use std::io::{ErrorKind, Read};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use tokio::sync::mpsc;
+
+use crate::resample::{FrameResampler, WHISPER_SAMPLE_RATE};
+
+/// Sample encoding of a raw PCM stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PcmEncoding {
+    /// Signed 16-bit little-endian
+    S16Le,
+    /// 32-bit float little-endian
+    F32Le,
+}
+
+impl PcmEncoding {
+    fn bytes_per_sample(self) -> usize {
+        match self {
+            PcmEncoding::S16Le => 2,
+            PcmEncoding::F32Le => 4,
+        }
+    }
+
+    fn decode(self, bytes: &[u8]) -> f32 {
+        match self {
+            PcmEncoding::S16Le => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32768.0,
+            PcmEncoding::F32Le => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        }
+    }
+}
+
+/// Layout of a raw PCM stream
+///
+/// Parsed from `ENCODING[:RATE[:CHANNELS]]`, e.g. "s16le", "f32le:48000" or
+/// "s16le:44100:2". Rate defaults to 16000 and channels to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawPcmFormat {
+    pub encoding: PcmEncoding,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+impl FromStr for RawPcmFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split(':');
+
+        let encoding = match parts.next().unwrap_or_default().to_lowercase().as_str() {
+            "s16le" => PcmEncoding::S16Le,
+            "f32le" => PcmEncoding::F32Le,
+            other => {
+                return Err(format!(
+                    "unknown PCM encoding '{other}' (expected s16le or f32le)"
+                ));
+            }
+        };
+
+        let sample_rate = match parts.next() {
+            Some(rate) => rate
+                .parse()
+                .ok()
+                .filter(|rate| *rate > 0)
+                .ok_or_else(|| format!("invalid sample rate: {rate}"))?,
+            None => WHISPER_SAMPLE_RATE,
+        };
+
+        let channels = match parts.next() {
+            Some(channels) => channels
+                .parse()
+                .ok()
+                .filter(|channels| (1..=8).contains(channels))
+                .ok_or_else(|| format!("invalid channel count: {channels} (expected 1-8)"))?,
+            None => 1,
+        };
+
+        if parts.next().is_some() {
+            return Err(format!(
+                "invalid PCM format '{s}' (expected ENCODING[:RATE[:CHANNELS]])"
+            ));
+        }
+
+        Ok(Self {
+            encoding,
+            sample_rate,
+            channels,
+        })
+    }
+}
+
+impl fmt::Display for RawPcmFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoding = match self.encoding {
+            PcmEncoding::S16Le => "s16le",
+            PcmEncoding::F32Le => "f32le",
+        };
+        write!(f, "{}:{}:{}", encoding, self.sample_rate, self.channels)
+    }
+}
+
+/// Read raw PCM from `reader` and send 16kHz mono blocks to `audio_tx`
+///
+/// Blocks (~100ms each) are sent as soon as they're read. Returns when the
+/// reader hits EOF or the receiver is dropped; a trailing partial frame is
+/// discarded.
+pub fn stream_raw_pcm(
+    mut reader: impl Read,
+    format: RawPcmFormat,
+    audio_tx: &mpsc::UnboundedSender<Vec<f32>>,
+) -> Result<()> {
+    let mut resampler = FrameResampler::new(format.sample_rate, format.channels)?;
+
+    let sample_bytes = format.encoding.bytes_per_sample();
+    let frame_bytes = sample_bytes * format.channels as usize;
+    let block_frames = (format.sample_rate as usize / 10).max(1);
+    let mut buf = vec![0u8; block_frames * frame_bytes];
+    let mut filled = 0;
+    let mut total_frames = 0usize;
+
+    loop {
+        let read = match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err).context("Failed to read PCM stream"),
+        };
+        filled += read;
+
+        // Pipes deliver small reads; wait for a full block
+        if filled < buf.len() {
+            continue;
+        }
+        filled = 0;
+        total_frames += block_frames;
+
+        let samples: Vec<f32> = buf
+            .chunks_exact(sample_bytes)
+            .map(|bytes| format.encoding.decode(bytes))
+            .collect();
+        let block = resampler.process(&samples);
+        if !block.is_empty() && audio_tx.send(block).is_err() {
+            return Ok(());
+        }
+    }
+
+    // Convert what's left of the last block (minus a split frame)
+    let complete = filled - filled % frame_bytes;
+    let samples: Vec<f32> = buf[..complete]
+        .chunks_exact(sample_bytes)
+        .map(|bytes| format.encoding.decode(bytes))
+        .collect();
+    total_frames += complete / frame_bytes;
+
+    let mut tail = resampler.process(&samples);
+    tail.extend(resampler.flush());
+    if !tail.is_empty() {
+        let _ = audio_tx.send(tail);
+    }
+
+    crate::verbose!(
+        "Read {:.1}s of {} PCM",
+        total_frames as f64 / f64::from(format.sample_rate),
+        format
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reader handing out a few bytes per call, like a pipe
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(7).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn format_defaults_to_16khz_mono() {
+        assert_eq!(
+            "s16le".parse::<RawPcmFormat>(),
+            Ok(RawPcmFormat {
+                encoding: PcmEncoding::S16Le,
+                sample_rate: 16_000,
+                channels: 1,
+            })
+        );
+        assert_eq!(
+            " F32LE:48000 ".parse::<RawPcmFormat>(),
+            Ok(RawPcmFormat {
+                encoding: PcmEncoding::F32Le,
+                sample_rate: 48_000,
+                channels: 1,
+            })
+        );
+        assert_eq!(
+            "s16le:44100:2".parse::<RawPcmFormat>().unwrap().to_string(),
+            "s16le:44100:2"
+        );
+    }
+
+    #[test]
+    fn format_rejects_bad_values() {
+        assert!("u8".parse::<RawPcmFormat>().is_err());
+        assert!("s16le:0".parse::<RawPcmFormat>().is_err());
+        assert!("s16le:fast".parse::<RawPcmFormat>().is_err());
+        assert!("s16le:16000:0".parse::<RawPcmFormat>().is_err());
+        assert!("s16le:16000:9".parse::<RawPcmFormat>().is_err());
+        assert!("s16le:16000:1:extra".parse::<RawPcmFormat>().is_err());
+    }
+
+    #[test]
+    fn stream_drops_a_split_trailing_frame() {
+        // 1.5 blocks of 16kHz mono s16le plus one stray byte
+        let frames = 2_400;
+        let mut bytes: Vec<u8> = (0..frames)
+            .flat_map(|i| ((i % 100) as i16 * 100).to_le_bytes())
+            .collect();
+        bytes.push(0x7f);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let format = "s16le".parse().unwrap();
+        stream_raw_pcm(Trickle(&bytes), format, &tx).unwrap();
+        drop(tx);
+
+        let mut blocks = Vec::new();
+        while let Ok(block) = rx.try_recv() {
+            blocks.push(block);
+        }
+        let lengths: Vec<usize> = blocks.iter().map(Vec::len).collect();
+        assert_eq!(lengths, vec![1_600, 800]);
+
+        let samples: Vec<f32> = blocks.concat();
+        assert_eq!(samples[1], 100.0 / 32768.0);
+        assert_eq!(samples[2_399], 9_900.0 / 32768.0);
+    }
+
+    #[test]
+    fn stream_decodes_f32_samples() {
+        let bytes: Vec<u8> = [0.5f32, -0.25, 1.0]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let format = "f32le".parse().unwrap();
+        stream_raw_pcm(bytes.as_slice(), format, &tx).unwrap();
+
+        assert_eq!(rx.try_recv().unwrap(), vec![0.5, -0.25, 1.0]);
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 //! Audio recorder configuration.
 
+use super::super::denoise::DenoiseConfig;
 use super::super::vad::VadConfig;
 
 /// Configuration for the audio recorder.
@@ -10,6 +11,9 @@ pub struct RecorderConfig {
 
     /// Voice Activity Detection configuration (no-op when vad feature disabled)
     pub vad: VadConfig,
+
+    /// Noise suppression configuration (no-op when denoise feature disabled)
+    pub denoise: DenoiseConfig,
 }
 
 impl RecorderConfig {
@@ -30,6 +34,12 @@ impl RecorderConfig {
         self
     }
 
+    /// Set noise suppression configuration.
+    pub fn with_denoise(mut self, denoise: DenoiseConfig) -> Self {
+        self.denoise = denoise;
+        self
+    }
+
     /// Disable VAD.
     pub fn without_vad(mut self) -> Self {
         self.vad = VadConfig::disabled();
@@ -0,0 +1,125 @@
+//! Live input level metering.
+//!
+//! The level is measured on the raw device samples in the cpal callback
+//! (before resampling, denoising and VAD), so a muted or wrong microphone
+//! shows up immediately rather than as an empty transcript.
+
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Peak below this counts as silent input (-60 dBFS)
+const SILENT_PEAK: f32 = 0.001;
+
+/// Continuous silent input before the microphone is reported as silent
+pub const SILENT_INPUT_WARNING_SECS: f32 = 3.0;
+
+/// Input level of the most recent audio callback
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct AudioLevel {
+    /// Root mean square amplitude (0.0-1.0)
+    pub rms: f32,
+    /// Peak absolute amplitude (0.0-1.0)
+    pub peak: f32,
+    /// Seconds the input has been continuously near zero
+    pub silent_secs: f32,
+}
+
+impl AudioLevel {
+    /// RMS level in dBFS (-inf..0), floored at -100
+    pub fn rms_db(&self) -> f32 {
+        (20.0 * self.rms.max(1e-5).log10()).max(-100.0)
+    }
+
+    /// Whether the input has been near zero long enough to suspect a muted
+    /// or wrong microphone
+    pub fn mic_appears_silent(&self) -> bool {
+        self.silent_secs >= SILENT_INPUT_WARNING_SECS
+    }
+}
+
+/// Measures callback buffers and publishes the result
+pub(super) struct LevelMeter {
+    tx: Arc<watch::Sender<AudioLevel>>,
+    /// Interleaved samples per second (rate * channels)
+    samples_per_sec: f32,
+    /// Interleaved samples since the input was last above `SILENT_PEAK`
+    silent_samples: u64,
+}
+
+impl LevelMeter {
+    pub fn new(tx: Arc<watch::Sender<AudioLevel>>, sample_rate: u32, channels: u16) -> Self {
+        Self {
+            tx,
+            samples_per_sec: (sample_rate * channels as u32) as f32,
+            silent_samples: 0,
+        }
+    }
+
+    /// Measure one callback buffer (raw f32 samples, any channel count)
+    pub fn measure(&mut self, samples: &[f32]) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let (sum_squares, peak) = samples.iter().fold((0.0f32, 0.0f32), |(sum, peak), s| {
+            (sum + s * s, peak.max(s.abs()))
+        });
+        let rms = (sum_squares / samples.len() as f32).sqrt();
+
+        if peak < SILENT_PEAK {
+            self.silent_samples += samples.len() as u64;
+        } else {
+            self.silent_samples = 0;
+        }
+
+        // send_replace never blocks and works without receivers
+        self.tx.send_replace(AudioLevel {
+            rms,
+            peak,
+            silent_secs: self.silent_samples as f32 / self.samples_per_sec,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silent_secs_counts_continuous_silence_across_channels() {
+        let (tx, rx) = watch::channel(AudioLevel::default());
+        // 16kHz stereo: 32000 interleaved samples per second
+        let mut meter = LevelMeter::new(Arc::new(tx), 16_000, 2);
+
+        meter.measure(&[0.0; 32_000]);
+        assert_eq!(rx.borrow().silent_secs, 1.0);
+        meter.measure(&[0.0005; 64_000]);
+        assert_eq!(rx.borrow().silent_secs, 3.0);
+        assert!(rx.borrow().mic_appears_silent());
+
+        // Any sample above the threshold resets it
+        let mut speech = vec![0.0; 3_200];
+        speech[100] = 0.5;
+        meter.measure(&speech);
+        let level = *rx.borrow();
+        assert_eq!(level.silent_secs, 0.0);
+        assert_eq!(level.peak, 0.5);
+        assert!(!level.mic_appears_silent());
+
+        // Empty buffers change nothing
+        meter.measure(&[]);
+        assert_eq!(*rx.borrow(), level);
+    }
+
+    #[test]
+    fn rms_db_is_floored() {
+        let level = |rms| AudioLevel {
+            rms,
+            ..Default::default()
+        };
+        assert_eq!(level(1.0).rms_db(), 0.0);
+        assert!((level(0.1).rms_db() + 20.0).abs() < 1e-4);
+        assert_eq!(level(0.0).rms_db(), -100.0);
+    }
+}
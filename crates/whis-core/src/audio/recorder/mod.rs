@@ -1,20 +1,25 @@
-//! Audio recording with real-time resampling and optional VAD.
+//! Audio recording with real-time resampling, optional noise suppression and VAD.
 
 mod config;
+mod level;
 mod processor;
 mod stream;
 
 pub use config::RecorderConfig;
+pub use level::{AudioLevel, SILENT_INPUT_WARNING_SECS};
 pub use stream::{get_stream_error_count, reset_stream_error_count};
 
 use anyhow::{Context, Result};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
 
+use super::denoise::{DenoiseConfig, Denoiser};
 use super::devices;
-use super::vad::{VadConfig, VadProcessor};
+use super::vad::{AutoStopConfig, AutoStopReason, VadConfig, VadProcessor, VadState};
 use crate::resample::{FrameResampler, WHISPER_SAMPLE_RATE};
 
+use level::LevelMeter;
 use processor::SampleProcessor;
 
 /// Sender type for streaming audio samples during recording
@@ -35,14 +40,24 @@ pub struct AudioRecorder {
     /// Real-time resampler (converts device rate to 16kHz mono)
     /// Created when recording starts (needs device sample rate)
     resampler: Option<Arc<Mutex<FrameResampler>>>,
-    /// Sample processor (combines resampler and VAD)
+    /// Sample processor (combines resampler, denoiser and VAD)
     processor: Option<Arc<Mutex<SampleProcessor>>>,
     /// Voice Activity Detection processor (optional, filters silence)
     vad: Option<Arc<Mutex<VadProcessor>>>,
     /// VAD configuration for next recording
     vad_config: VadConfig,
+    /// Noise suppression configuration for next recording
+    denoise_config: DenoiseConfig,
+    /// Auto-stop configuration for next recording
+    auto_stop_config: AutoStopConfig,
+    /// VAD state publisher (created per recording when VAD runs)
+    vad_state_tx: Option<Arc<watch::Sender<VadState>>>,
+    /// Auto-stop signal (created per recording when auto-stop is enabled)
+    auto_stop_tx: Option<Arc<watch::Sender<Option<AutoStopReason>>>>,
     /// Optional sender for streaming samples during recording
     stream_tx: Option<Arc<AudioStreamSender>>,
+    /// Input level publisher (created per recording, dropped on stop)
+    level_tx: Option<Arc<watch::Sender<AudioLevel>>>,
 }
 
 // SAFETY: AudioRecorder is always used behind a Mutex in AppState, ensuring
@@ -70,19 +85,72 @@ impl AudioRecorder {
             processor: None,
             vad: None,
             vad_config: VadConfig::default(),
+            denoise_config: DenoiseConfig::default(),
+            auto_stop_config: AutoStopConfig::default(),
+            vad_state_tx: None,
+            auto_stop_tx: None,
             stream_tx: None,
+            level_tx: None,
         })
     }
 
     /// Configure Voice Activity Detection for the next recording.
     /// VAD filters out silence to reduce audio size and improve transcription.
+    ///
+    /// Keeps the prefill, onset and hangover from `set_vad_config`.
     pub fn set_vad(&mut self, enabled: bool, threshold: f32) {
+        self.vad_config.enabled = enabled;
+        self.vad_config.threshold = threshold.clamp(0.0, 1.0);
+    }
+
+    /// Configure Voice Activity Detection, including prefill, onset and hangover.
+    pub fn set_vad_config(&mut self, config: VadConfig) {
         self.vad_config = VadConfig {
-            enabled,
-            threshold: threshold.clamp(0.0, 1.0),
+            threshold: config.threshold.clamp(0.0, 1.0),
+            ..config
         };
     }
 
+    /// Configure noise suppression for the next recording.
+    /// Runs before VAD and chunking; `strength` blends denoised and original audio.
+    pub fn set_denoise(&mut self, enabled: bool, strength: f32) {
+        self.denoise_config = DenoiseConfig::new(enabled, strength);
+    }
+
+    /// Stop the next recording automatically after speech ends (or if none starts).
+    ///
+    /// Uses the VAD threshold from `set_vad`; VAD runs detect-only when
+    /// silence removal is off. Watch `auto_stop_receiver` to act on it.
+    pub fn set_auto_stop(&mut self, config: AutoStopConfig) {
+        self.auto_stop_config = config;
+    }
+
+    /// Subscribe to the auto-stop signal of the current recording.
+    ///
+    /// Returns None when not recording or auto-stop is disabled. The value
+    /// becomes `Some(reason)` when the recording should stop; the recorder
+    /// keeps recording until `stop_recording` is called.
+    pub fn auto_stop_receiver(&self) -> Option<watch::Receiver<Option<AutoStopReason>>> {
+        self.auto_stop_tx.as_ref().map(|tx| tx.subscribe())
+    }
+
+    /// Subscribe to the VAD state of the current recording.
+    ///
+    /// Returns None when not recording or VAD is off. Pass it to
+    /// `ProgressiveChunker::consume_stream` to cut chunks at pauses; the
+    /// receiver closes when the recording stops.
+    pub fn vad_state_receiver(&self) -> Option<watch::Receiver<VadState>> {
+        self.vad_state_tx.as_ref().map(|tx| tx.subscribe())
+    }
+
+    /// Subscribe to the live input level of the current recording.
+    ///
+    /// Returns None when not recording. The receiver closes when the
+    /// recording stops.
+    pub fn level_receiver(&self) -> Option<watch::Receiver<AudioLevel>> {
+        self.level_tx.as_ref().map(|tx| tx.subscribe())
+    }
+
     /// Start recording with the default input device.
     pub fn start_recording(&mut self) -> Result<()> {
         self.start_recording_with_device(None)
@@ -174,16 +242,20 @@ impl AudioRecorder {
         let samples = self.samples.clone();
         samples.lock().unwrap().clear();
 
+        let level_tx = Arc::new(watch::channel(AudioLevel::default()).0);
+        self.level_tx = Some(level_tx.clone());
+        let level_meter = LevelMeter::new(level_tx, device_sample_rate, device_channels);
+
         // Build stream using unified builder (no duplication!)
         let stream = match config.sample_format() {
             cpal::SampleFormat::F32 => {
-                self.build_stream_typed::<f32>(&device, &stream_config, samples)?
+                self.build_stream_typed::<f32>(&device, &stream_config, samples, level_meter)?
             }
             cpal::SampleFormat::I16 => {
-                self.build_stream_typed::<i16>(&device, &stream_config, samples)?
+                self.build_stream_typed::<i16>(&device, &stream_config, samples, level_meter)?
             }
             cpal::SampleFormat::U16 => {
-                self.build_stream_typed::<u16>(&device, &stream_config, samples)?
+                self.build_stream_typed::<u16>(&device, &stream_config, samples, level_meter)?
             }
             _ => anyhow::bail!("Unsupported sample format"),
         };
@@ -196,22 +268,65 @@ impl AudioRecorder {
         Ok(())
     }
 
-    /// Create a sample processor with the appropriate VAD configuration.
+    /// Create a sample processor with the appropriate denoise and VAD configuration.
     fn create_processor(
         &mut self,
         resampler: Arc<Mutex<FrameResampler>>,
     ) -> Result<SampleProcessor> {
-        if self.vad_config.enabled {
-            crate::verbose!("VAD enabled (threshold: {:.2})", self.vad_config.threshold);
-            let vad_processor = VadProcessor::new(true, self.vad_config.threshold)
-                .context("Failed to create VAD processor")?;
+        let mut processor = SampleProcessor::new(resampler);
+
+        if self.denoise_config.enabled {
+            crate::verbose!(
+                "Noise suppression enabled (strength: {:.2})",
+                self.denoise_config.strength
+            );
+            let denoiser =
+                Denoiser::new(self.denoise_config.strength).context("Failed to create denoiser")?;
+            processor = processor.with_denoiser(Arc::new(Mutex::new(denoiser)));
+        }
+
+        let auto_stop = self.auto_stop_config.enabled;
+        if self.vad_config.enabled || auto_stop {
+            let vad_processor = if self.vad_config.enabled {
+                crate::verbose!(
+                    "VAD enabled (threshold: {:.2}, prefill: {}, onset: {}, hangover: {} frames)",
+                    self.vad_config.threshold,
+                    self.vad_config.prefill_frames,
+                    self.vad_config.onset_frames,
+                    self.vad_config.hangover_frames
+                );
+                VadProcessor::from_config(&self.vad_config)
+            } else {
+                // Detect speech for auto-stop without dropping silence
+                VadProcessor::monitor(&self.vad_config)
+            }
+            .context("Failed to create VAD processor")?;
+            crate::verbose!("VAD engine: {}", vad_processor.engine());
+            let initial_state = vad_processor.state();
             let vad = Arc::new(Mutex::new(vad_processor));
             self.vad = Some(vad.clone());
-            Ok(SampleProcessor::with_vad(resampler, vad))
+            let vad_state_tx = Arc::new(watch::channel(initial_state).0);
+            self.vad_state_tx = Some(vad_state_tx.clone());
+            processor = processor.with_vad(vad).with_vad_state(vad_state_tx);
         } else {
             self.vad = None;
-            Ok(SampleProcessor::new(resampler))
+            self.vad_state_tx = None;
+        }
+
+        if auto_stop {
+            crate::verbose!(
+                "Auto-stop enabled (silence: {:.1}s, no-speech timeout: {:?})",
+                self.auto_stop_config.silence.as_secs_f32(),
+                self.auto_stop_config.no_speech_timeout
+            );
+            let tx = Arc::new(watch::channel(None).0);
+            self.auto_stop_tx = Some(tx.clone());
+            processor = processor.with_auto_stop(self.auto_stop_config, tx);
+        } else {
+            self.auto_stop_tx = None;
         }
+
+        Ok(processor)
     }
 
     /// Build a typed audio stream (unified implementation, no duplication).
@@ -220,6 +335,7 @@ impl AudioRecorder {
         device: &cpal::Device,
         config: &cpal::StreamConfig,
         samples: Arc<Mutex<Vec<f32>>>,
+        level_meter: LevelMeter,
     ) -> Result<cpal::Stream>
     where
         T: cpal::Sample + cpal::SizedSample,
@@ -228,7 +344,14 @@ impl AudioRecorder {
         // Get the processor - clone it since it's shared with self
         let processor = self.processor.as_ref().unwrap().lock().unwrap().clone();
 
-        stream::build_stream::<T>(device, config, samples, processor, self.stream_tx.clone())
+        stream::build_stream::<T>(
+            device,
+            config,
+            samples,
+            processor,
+            self.stream_tx.clone(),
+            level_meter,
+        )
     }
 
     /// Start recording and stream samples to a channel for real-time processing.
@@ -265,6 +388,11 @@ impl AudioRecorder {
         // Drop the streaming sender to signal end of audio to receivers
         self.stream_tx = None;
 
+        // Close level, VAD state and auto-stop receivers
+        self.level_tx = None;
+        self.vad_state_tx = None;
+        self.auto_stop_tx = None;
+
         // Flush the processor to get any remaining buffered samples
         let flushed_samples = if let Some(processor) = &self.processor {
             processor.lock().unwrap().flush()
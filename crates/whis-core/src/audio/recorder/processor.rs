@@ -1,15 +1,28 @@
-//! Sample processing abstraction for VAD and resampling.
+//! Sample processing abstraction for denoising, VAD and resampling.
 
 use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
 
-use super::super::vad::VadProcessor;
+use super::super::denoise::Denoiser;
+use super::super::vad::{AutoStopConfig, AutoStopReason, VadProcessor, VadState};
 use crate::resample::FrameResampler;
 
-/// Processes raw audio samples through resampling and optional VAD.
+/// Processes raw audio samples through resampling, optional denoising and optional VAD.
 #[derive(Clone)]
 pub(super) struct SampleProcessor {
     resampler: Arc<Mutex<FrameResampler>>,
+    denoiser: Option<Arc<Mutex<Denoiser>>>,
     vad: Option<Arc<Mutex<VadProcessor>>>,
+    /// Publishes VAD state changes (for utterance chunking)
+    vad_state_tx: Option<Arc<watch::Sender<VadState>>>,
+    auto_stop: Option<AutoStop>,
+}
+
+/// Auto-stop check run after each VAD pass
+#[derive(Clone)]
+struct AutoStop {
+    config: AutoStopConfig,
+    tx: Arc<watch::Sender<Option<AutoStopReason>>>,
 }
 
 impl SampleProcessor {
@@ -17,50 +30,111 @@ impl SampleProcessor {
     pub fn new(resampler: Arc<Mutex<FrameResampler>>) -> Self {
         Self {
             resampler,
+            denoiser: None,
             vad: None,
+            vad_state_tx: None,
+            auto_stop: None,
         }
     }
 
-    /// Create a new sample processor with resampling and VAD.
-    pub fn with_vad(resampler: Arc<Mutex<FrameResampler>>, vad: Arc<Mutex<VadProcessor>>) -> Self {
-        Self {
-            resampler,
-            vad: Some(vad),
-        }
+    /// Add VAD after resampling (and denoising).
+    pub fn with_vad(mut self, vad: Arc<Mutex<VadProcessor>>) -> Self {
+        self.vad = Some(vad);
+        self
+    }
+
+    /// Add noise suppression between resampling and VAD.
+    pub fn with_denoiser(mut self, denoiser: Arc<Mutex<Denoiser>>) -> Self {
+        self.denoiser = Some(denoiser);
+        self
+    }
+
+    /// Publish VAD state changes to `tx` (needs `with_vad`).
+    pub fn with_vad_state(mut self, tx: Arc<watch::Sender<VadState>>) -> Self {
+        self.vad_state_tx = Some(tx);
+        self
     }
 
-    /// Process raw audio samples through resampling and optional VAD.
+    /// Signal `tx` once the VAD state satisfies `config` (needs `with_vad`).
+    pub fn with_auto_stop(
+        mut self,
+        config: AutoStopConfig,
+        tx: Arc<watch::Sender<Option<AutoStopReason>>>,
+    ) -> Self {
+        self.auto_stop = Some(AutoStop { config, tx });
+        self
+    }
+
+    /// Process raw audio samples through resampling, denoising and VAD.
     ///
     /// Returns the processed samples (16kHz mono, with silence filtered if VAD enabled).
     pub fn process(&self, raw_samples: &[f32]) -> Vec<f32> {
         // First, resample to 16kHz mono
-        let resampled = self.resampler.lock().unwrap().process(raw_samples);
+        let mut samples = self.resampler.lock().unwrap().process(raw_samples);
 
-        if resampled.is_empty() {
+        // Denoise before VAD so background noise isn't mistaken for speech
+        if let Some(ref denoiser) = self.denoiser {
+            samples = denoiser.lock().unwrap().process(&samples);
+        }
+
+        if samples.is_empty() {
             return Vec::new();
         }
 
         // Then apply VAD if enabled (filters out silence)
         if let Some(ref vad) = self.vad {
-            return vad.lock().unwrap().process(&resampled);
+            let mut vad = vad.lock().unwrap();
+            let output = vad.process(&samples);
+
+            if let Some(ref tx) = self.vad_state_tx {
+                let state = vad.state();
+                tx.send_if_modified(|current| {
+                    let changed = *current != state;
+                    *current = state;
+                    changed
+                });
+            }
+
+            if let Some(ref auto_stop) = self.auto_stop
+                && let Some(reason) = auto_stop.config.check(&vad)
+            {
+                // Only the first reason is reported
+                auto_stop.tx.send_if_modified(|current| {
+                    let first = current.is_none();
+                    if first {
+                        *current = Some(reason);
+                    }
+                    first
+                });
+            }
+
+            return output;
         }
 
-        resampled
+        samples
     }
 
-    /// Flush any buffered samples from the resampler and VAD.
+    /// Flush any buffered samples from the resampler, denoiser and VAD.
     pub fn flush(&mut self) -> Vec<f32> {
         // Flush resampler
-        let flushed_resampler = self.resampler.lock().unwrap().flush();
+        let mut flushed = self.resampler.lock().unwrap().flush();
+
+        // Push the tail through the denoiser and flush it
+        if let Some(ref denoiser) = self.denoiser {
+            let mut denoiser = denoiser.lock().unwrap();
+            let mut remaining = denoiser.process(&flushed);
+            remaining.extend(denoiser.flush());
+            flushed = remaining;
+        }
 
-        // Process flushed resampler samples through VAD and flush VAD
+        // Process flushed samples through VAD and flush VAD
         if let Some(ref vad) = self.vad {
             let mut vad = vad.lock().unwrap();
-            let mut remaining = vad.process(&flushed_resampler);
+            let mut remaining = vad.process(&flushed);
             remaining.extend(vad.flush());
             return remaining;
         }
 
-        flushed_resampler
+        flushed
     }
 }
@@ -7,6 +7,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 use super::AudioStreamSender;
+use super::level::LevelMeter;
 use super::processor::SampleProcessor;
 
 /// Global counter for stream errors (reset per recording session)
@@ -33,6 +34,7 @@ pub(super) fn build_stream<T>(
     samples: Arc<Mutex<Vec<f32>>>,
     processor: SampleProcessor,
     stream_tx: Option<Arc<AudioStreamSender>>,
+    mut level_meter: LevelMeter,
 ) -> Result<Stream>
 where
     T: cpal::Sample + cpal::SizedSample,
@@ -69,6 +71,9 @@ where
             let f32_samples: Vec<f32> =
                 data.iter().map(|&s| cpal::Sample::from_sample(s)).collect();
 
+            // Measure the input level before resampling and VAD drop anything
+            level_meter.measure(&f32_samples);
+
             // Process through resampler and VAD (if enabled)
             let processed_samples = processor.lock().unwrap().process(&f32_samples);
 
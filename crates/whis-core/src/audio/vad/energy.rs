@@ -0,0 +1,153 @@
+//! Energy and zero-crossing speech detector (pure Rust, no model)
+//!
+//! Scores each frame by how far its energy rises above an adaptive noise
+//! floor (the quietest recent frame), and discounts frames whose
+//! zero-crossing rate sounds like hiss rather than voice. Less accurate than
+//! Silero in noisy rooms, but needs no ONNX Runtime and runs on any target.
+
+use std::collections::VecDeque;
+
+/// Frames quieter than this never count as speech (dBFS)
+const SILENCE_FLOOR_DB: f32 = -60.0;
+
+/// Energy above the noise floor that maps to a probability of 0.5 (dB)
+const SNR_MIDPOINT_DB: f32 = 10.0;
+
+/// Softness of the probability curve around the midpoint (dB)
+const SNR_SLOPE_DB: f32 = 2.5;
+
+/// Frames the noise floor is taken from (~3s at 32ms/frame)
+const NOISE_WINDOW_FRAMES: usize = 94;
+
+/// Zero-crossing rate above which a frame sounds like noise or hiss
+const NOISY_ZCR: f32 = 0.35;
+
+/// Probability scale for frames above `NOISY_ZCR`
+const NOISY_ZCR_WEIGHT: f32 = 0.5;
+
+/// Speech detector based on frame energy and zero-crossing rate
+pub(super) struct EnergyDetector {
+    /// Energy of recent frames (dBFS), for the noise floor
+    recent_db: VecDeque<f32>,
+}
+
+impl EnergyDetector {
+    pub fn new() -> Self {
+        Self {
+            recent_db: VecDeque::with_capacity(NOISE_WINDOW_FRAMES + 1),
+        }
+    }
+
+    /// Speech probability of one frame (0.0-1.0)
+    pub fn predict(&mut self, frame: &[f32]) -> f32 {
+        if frame.is_empty() {
+            return 0.0;
+        }
+
+        let energy_db = energy_db(frame);
+        self.recent_db.push_back(energy_db);
+        while self.recent_db.len() > NOISE_WINDOW_FRAMES {
+            self.recent_db.pop_front();
+        }
+
+        // The quietest recent frame approximates the background noise
+        let noise_floor_db = self
+            .recent_db
+            .iter()
+            .copied()
+            .fold(f32::INFINITY, f32::min)
+            .max(SILENCE_FLOOR_DB);
+
+        let snr_db = energy_db - noise_floor_db;
+        let mut probability = 1.0 / (1.0 + (-(snr_db - SNR_MIDPOINT_DB) / SNR_SLOPE_DB).exp());
+
+        if zero_crossing_rate(frame) > NOISY_ZCR {
+            probability *= NOISY_ZCR_WEIGHT;
+        }
+
+        probability
+    }
+
+    /// Forget the noise floor (new recording, possibly a new room)
+    pub fn reset(&mut self) {
+        self.recent_db.clear();
+    }
+}
+
+/// RMS level of a frame in dBFS
+fn energy_db(frame: &[f32]) -> f32 {
+    let mean_square = frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32;
+    10.0 * mean_square.max(1e-12).log10()
+}
+
+/// Fraction of adjacent samples that change sign
+fn zero_crossing_rate(frame: &[f32]) -> f32 {
+    if frame.len() < 2 {
+        return 0.0;
+    }
+    let crossings = frame
+        .windows(2)
+        .filter(|pair| (pair[0] >= 0.0) != (pair[1] >= 0.0))
+        .count();
+    crossings as f32 / (frame.len() - 1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: usize = 512;
+    const SAMPLE_RATE: f32 = 16000.0;
+
+    /// Quiet deterministic noise (linear congruential generator)
+    fn noise(len: usize, amplitude: f32, seed: &mut u32) -> Vec<f32> {
+        (0..len)
+            .map(|_| {
+                *seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (*seed as f32 / u32::MAX as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    /// Voice-like signal: 150Hz fundamental with a few harmonics
+    fn voice(len: usize, offset: usize) -> Vec<f32> {
+        (offset..offset + len)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE;
+                (1..=4)
+                    .map(|h| (2.0 * std::f32::consts::PI * 150.0 * h as f32 * t).sin() / h as f32)
+                    .sum::<f32>()
+                    * 0.2
+            })
+            .collect()
+    }
+
+    #[test]
+    fn silence_is_not_speech() {
+        let mut detector = EnergyDetector::new();
+        for _ in 0..20 {
+            assert!(detector.predict(&[0.0; FRAME]) < 0.1);
+        }
+    }
+
+    #[test]
+    fn voice_over_background_noise_is_speech() {
+        let mut detector = EnergyDetector::new();
+        let mut seed = 1;
+
+        // One second of background noise to settle the noise floor
+        for _ in 0..31 {
+            let p = detector.predict(&noise(FRAME, 0.002, &mut seed));
+            assert!(p < 0.5, "noise scored {p}");
+        }
+
+        for frame in 0..15 {
+            let mut samples = voice(FRAME, frame * FRAME);
+            for (s, n) in samples.iter_mut().zip(noise(FRAME, 0.002, &mut seed)) {
+                *s += n;
+            }
+            let p = detector.predict(&samples);
+            assert!(p > 0.9, "voice scored {p}");
+        }
+    }
+}
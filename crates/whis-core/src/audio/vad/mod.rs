@@ -1,66 +1,245 @@
 //! Voice Activity Detection (VAD) for audio recording
 //!
-//! This module provides VAD functionality that adapts based on feature flags:
-//! - With `feature = "vad"`: Full Silero VAD with speech detection
-//! - Without `feature = "vad"`: No-op passthrough implementation
+//! VadProcessor always exists and works with every feature set. Frames are
+//! scored by one of two engines, picked at runtime (`VadEngine`):
+//! - Silero: neural model via ONNX Runtime, needs `feature = "vad"`
+//! - Energy: pure-Rust energy + zero-crossing detector, always available
 //!
-//! This design eliminates cfg blocks in consuming code - VadProcessor
-//! always exists with the same API regardless of features enabled.
+//! `VadEngine::Auto` uses Silero and falls back to the energy detector when
+//! the model is compiled out or fails to load (e.g. mobile builds).
 
-// Feature-gated module pattern: export real or no-op implementation
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
 
-#[cfg(feature = "vad")]
+use crate::configuration::{
+    DEFAULT_VAD_HANGOVER_FRAMES, DEFAULT_VAD_ONSET_FRAMES, DEFAULT_VAD_PREFILL_FRAMES,
+};
+
+mod energy;
 mod processor;
 
-#[cfg(not(feature = "vad"))]
-mod processor_noop;
+pub use processor::{VadFrame, VadProcessor, VadState};
+
+/// Engine that scores audio frames for speech
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VadEngine {
+    /// Silero when available, otherwise the energy detector
+    #[default]
+    Auto,
+    /// Silero neural model (most accurate, needs ONNX Runtime)
+    Silero,
+    /// Energy and zero-crossing detector (no model, works everywhere)
+    ///
+    /// A threshold of 0.5 means roughly 10 dB above the background noise.
+    Energy,
+}
 
-// Re-export the appropriate implementation
-#[cfg(feature = "vad")]
-pub use processor::{VadProcessor, VadState};
+impl VadEngine {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VadEngine::Auto => "auto",
+            VadEngine::Silero => "silero",
+            VadEngine::Energy => "energy",
+        }
+    }
+
+    /// Whether this engine is compiled in (the model may still fail to load)
+    pub fn is_available(&self) -> bool {
+        match self {
+            VadEngine::Silero => cfg!(feature = "vad"),
+            VadEngine::Auto | VadEngine::Energy => true,
+        }
+    }
+}
 
-#[cfg(not(feature = "vad"))]
-pub use processor_noop::{VadProcessor, VadState};
+impl fmt::Display for VadEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
-// VadConfig is always available (not feature-gated)
+impl std::str::FromStr for VadEngine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(VadEngine::Auto),
+            "silero" => Ok(VadEngine::Silero),
+            "energy" => Ok(VadEngine::Energy),
+            _ => Err(format!(
+                "Unknown VAD engine: {}. Available: auto, silero, energy",
+                s
+            )),
+        }
+    }
+}
 
 /// Configuration for Voice Activity Detection.
+///
+/// Frame counts are in VAD frames (512 samples, 32ms at 16kHz).
 #[derive(Debug, Clone, Copy)]
 pub struct VadConfig {
     /// Whether VAD is enabled
     pub enabled: bool,
+    /// Engine that scores frames
+    pub engine: VadEngine,
     /// VAD threshold (0.0-1.0), higher values are more sensitive
     pub threshold: f32,
+    /// Frames kept from before speech is confirmed (captures word beginnings)
+    pub prefill_frames: usize,
+    /// Consecutive speech frames required to confirm speech
+    pub onset_frames: usize,
+    /// Frames kept after speech stops (captures trailing syllables)
+    pub hangover_frames: usize,
 }
 
 impl Default for VadConfig {
     fn default() -> Self {
-        Self {
-            enabled: false,
-            threshold: 0.5,
-        }
+        Self::new(false, 0.5)
     }
 }
 
 impl VadConfig {
-    /// Create a new VAD configuration.
+    /// Create a new VAD configuration with the default prefill, onset and hangover.
     pub fn new(enabled: bool, threshold: f32) -> Self {
-        Self { enabled, threshold }
+        Self {
+            enabled,
+            engine: VadEngine::Auto,
+            threshold,
+            prefill_frames: DEFAULT_VAD_PREFILL_FRAMES,
+            onset_frames: DEFAULT_VAD_ONSET_FRAMES,
+            hangover_frames: DEFAULT_VAD_HANGOVER_FRAMES,
+        }
     }
 
     /// Create a disabled VAD configuration.
     pub fn disabled() -> Self {
-        Self {
-            enabled: false,
-            threshold: 0.5,
-        }
+        Self::new(false, 0.5)
     }
 
     /// Create an enabled VAD configuration with the given threshold.
     pub fn enabled_with_threshold(threshold: f32) -> Self {
+        Self::new(true, threshold)
+    }
+}
+
+/// Why a recording was stopped automatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoStopReason {
+    /// Speech was followed by the configured trailing silence
+    EndOfSpeech,
+    /// No speech within the no-speech timeout
+    NoSpeech,
+}
+
+/// Configuration for stopping a recording when the speaker is done.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoStopConfig {
+    /// Whether auto-stop is enabled
+    pub enabled: bool,
+    /// Silence after speech that ends the recording
+    pub silence: Duration,
+    /// Stop if no speech is detected within this time (None = wait forever)
+    pub no_speech_timeout: Option<Duration>,
+}
+
+impl AutoStopConfig {
+    /// Create an enabled auto-stop configuration.
+    pub fn new(silence: Duration, no_speech_timeout: Option<Duration>) -> Self {
         Self {
             enabled: true,
-            threshold,
+            silence,
+            no_speech_timeout,
+        }
+    }
+
+    /// Whether `vad`'s state means the recording should stop
+    pub fn check(&self, vad: &VadProcessor) -> Option<AutoStopReason> {
+        if !self.enabled {
+            return None;
         }
+        if vad.speech_detected() {
+            (vad.trailing_silence() >= self.silence).then_some(AutoStopReason::EndOfSpeech)
+        } else {
+            self.no_speech_timeout
+                .filter(|timeout| vad.elapsed() >= *timeout)
+                .map(|_| AutoStopReason::NoSpeech)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use processor::VAD_CHUNK_SIZE;
+
+    /// `frames` VAD frames (32ms each) of a 200Hz tone or of silence
+    fn audio(voice: bool, frames: usize) -> Vec<f32> {
+        (0..frames * VAD_CHUNK_SIZE)
+            .map(|i| {
+                if voice {
+                    (2.0 * std::f32::consts::PI * 200.0 * i as f32 / 16_000.0).sin() * 0.3
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    fn energy_vad() -> VadProcessor {
+        VadProcessor::from_config(&VadConfig {
+            engine: VadEngine::Energy,
+            ..VadConfig::enabled_with_threshold(0.5)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn auto_stop_after_silence_following_speech() {
+        let auto_stop = AutoStopConfig::new(Duration::from_millis(500), None);
+        let mut vad = energy_vad();
+
+        vad.process(&audio(false, 5));
+        vad.process(&audio(true, 10));
+        assert!(vad.speech_detected());
+        assert_eq!(auto_stop.check(&vad), None);
+
+        // 320ms of silence is a pause, 640ms ends the recording
+        vad.process(&audio(false, 10));
+        assert_eq!(auto_stop.check(&vad), None);
+        vad.process(&audio(false, 10));
+        assert_eq!(auto_stop.check(&vad), Some(AutoStopReason::EndOfSpeech));
+
+        // Speaking again resets the silence
+        vad.process(&audio(true, 5));
+        assert_eq!(auto_stop.check(&vad), None);
+    }
+
+    #[test]
+    fn auto_stop_when_nobody_speaks() {
+        let auto_stop =
+            AutoStopConfig::new(Duration::from_millis(500), Some(Duration::from_secs(1)));
+        let mut vad = energy_vad();
+
+        vad.process(&audio(false, 30));
+        assert_eq!(auto_stop.check(&vad), None);
+        vad.process(&audio(false, 2));
+        assert_eq!(auto_stop.check(&vad), Some(AutoStopReason::NoSpeech));
+
+        // Without a timeout it waits for speech forever
+        let wait = AutoStopConfig::new(Duration::from_millis(500), None);
+        assert_eq!(wait.check(&vad), None);
+    }
+
+    #[test]
+    fn disabled_auto_stop_never_stops() {
+        let mut vad = energy_vad();
+        vad.process(&audio(false, 5));
+        vad.process(&audio(true, 10));
+        vad.process(&audio(false, 40));
+
+        assert_eq!(AutoStopConfig::default().check(&vad), None);
     }
 }
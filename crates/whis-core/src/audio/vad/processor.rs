@@ -2,26 +2,26 @@
 //!
 //! This module provides real-time speech detection to skip silence during recording,
 //! reducing audio size and improving transcription quality.
+//!
+//! Frames are scored by the Silero model (`vad` feature) or by the built-in
+//! energy detector, and smoothed by the same prefill/onset/hangover state machine.
 
 use std::collections::VecDeque;
+use std::time::Duration;
 
-use anyhow::{Context, Result};
+#[cfg(feature = "vad")]
+use anyhow::Context;
+use anyhow::Result;
+#[cfg(feature = "vad")]
 use voice_activity_detector::VoiceActivityDetector;
 
+use super::energy::EnergyDetector;
+use super::{VadConfig, VadEngine};
 use crate::resample::WHISPER_SAMPLE_RATE;
 
 /// VAD processes 512 samples at a time (32ms at 16kHz)
 pub const VAD_CHUNK_SIZE: usize = 512;
 
-/// Default prefill frames (~480ms at 32ms/frame)
-const DEFAULT_PREFILL_FRAMES: usize = 15;
-
-/// Default onset frames (require 2 consecutive speech frames)
-const DEFAULT_ONSET_FRAMES: usize = 2;
-
-/// Default hangover frames (~480ms trailing capture)
-const DEFAULT_HANGOVER_FRAMES: usize = 15;
-
 /// VAD state information for external queries
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VadState {
@@ -38,14 +38,96 @@ impl VadState {
     }
 }
 
+/// Result of analyzing a single VAD frame (see `VadProcessor::analyze`)
+#[derive(Debug, Clone, Copy)]
+pub struct VadFrame {
+    /// Speech probability reported by the detector (0.0-1.0)
+    pub probability: f32,
+    /// State after the frame was processed
+    pub state: VadState,
+}
+
+/// Speech detector that scores each frame
+enum Detector {
+    #[cfg(feature = "vad")]
+    Silero(Box<VoiceActivityDetector>),
+    Energy(EnergyDetector),
+}
+
+impl Detector {
+    /// Create the detector for `engine`, falling back to the energy detector
+    /// when the Silero model can't be used
+    fn new(engine: VadEngine) -> Self {
+        match engine {
+            VadEngine::Energy => Detector::Energy(EnergyDetector::new()),
+            VadEngine::Auto | VadEngine::Silero => match Self::silero() {
+                Ok(detector) => detector,
+                Err(e) => {
+                    if engine == VadEngine::Silero {
+                        crate::warn!("Silero VAD unavailable, using the energy VAD: {e:#}");
+                    } else {
+                        crate::verbose!("Silero VAD unavailable, using the energy VAD: {e:#}");
+                    }
+                    Detector::Energy(EnergyDetector::new())
+                }
+            },
+        }
+    }
+
+    #[cfg(feature = "vad")]
+    fn silero() -> Result<Self> {
+        // VAD expects 16kHz audio with 512-sample chunks
+        VoiceActivityDetector::builder()
+            .sample_rate(WHISPER_SAMPLE_RATE as i64)
+            .chunk_size(VAD_CHUNK_SIZE)
+            .build()
+            .map(|detector| Detector::Silero(Box::new(detector)))
+            .context("Failed to create VAD detector")
+    }
+
+    #[cfg(not(feature = "vad"))]
+    fn silero() -> Result<Self> {
+        anyhow::bail!("built without the vad feature")
+    }
+
+    fn engine(&self) -> VadEngine {
+        match self {
+            #[cfg(feature = "vad")]
+            Detector::Silero(_) => VadEngine::Silero,
+            Detector::Energy(_) => VadEngine::Energy,
+        }
+    }
+
+    /// Speech probability of one 512-sample frame
+    fn predict(&mut self, frame: &[f32]) -> f32 {
+        match self {
+            #[cfg(feature = "vad")]
+            Detector::Silero(detector) => detector.predict(frame.iter().copied()),
+            Detector::Energy(detector) => detector.predict(frame),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            // Silero's recurrent state settles within a few frames
+            #[cfg(feature = "vad")]
+            Detector::Silero(_) => {}
+            Detector::Energy(detector) => detector.reset(),
+        }
+    }
+}
+
 /// Voice Activity Detection processor
 ///
-/// Wraps the Silero VAD model to detect speech in real-time audio streams.
+/// Detects speech in real-time audio streams with the Silero model or the
+/// built-in energy detector (see `VadEngine`).
 /// Uses Smoothed VAD approach for better speech capture.
 pub struct VadProcessor {
-    detector: VoiceActivityDetector,
+    detector: Detector,
     threshold: f32,
     is_enabled: bool,
+    /// Whether non-speech is dropped (false = detect only, for auto-stop)
+    filter: bool,
     /// Buffer to accumulate samples until we have a full chunk
     buffer: Vec<f32>,
     /// Whether speech is currently detected
@@ -54,7 +136,7 @@ pub struct VadProcessor {
     // Smoothed VAD fields
     /// Circular buffer of recent frames for prefill
     frame_buffer: VecDeque<Vec<f32>>,
-    /// Number of frames to buffer before speech (captures word beginnings)
+    /// Number of frames kept before the onset frames (captures word beginnings)
     prefill_frames: usize,
     /// Number of consecutive speech frames required to confirm onset
     onset_frames: usize,
@@ -64,6 +146,14 @@ pub struct VadProcessor {
     onset_counter: usize,
     /// Remaining hangover frames before transitioning to silence
     hangover_counter: usize,
+
+    // Auto-stop tracking
+    /// Whether confirmed speech has been seen since the last reset
+    speech_detected: bool,
+    /// Samples since the last frame above the threshold
+    samples_since_voice: usize,
+    /// Samples analyzed since the last reset
+    samples_processed: usize,
 }
 
 impl VadProcessor {
@@ -73,29 +163,51 @@ impl VadProcessor {
     /// * `enabled` - Whether VAD is enabled
     /// * `threshold` - Speech probability threshold (0.0-1.0, default 0.5)
     pub fn new(enabled: bool, threshold: f32) -> Result<Self> {
-        // VAD expects 16kHz audio with 512-sample chunks
-        let detector = VoiceActivityDetector::builder()
-            .sample_rate(WHISPER_SAMPLE_RATE as i64)
-            .chunk_size(VAD_CHUNK_SIZE)
-            .build()
-            .context("Failed to create VAD detector")?;
+        Self::from_config(&VadConfig::new(enabled, threshold))
+    }
+
+    /// Create a VAD processor with custom engine, prefill, onset and hangover
+    pub fn from_config(config: &VadConfig) -> Result<Self> {
+        // A disabled processor never scores frames, skip loading the model
+        let detector = if config.enabled {
+            Detector::new(config.engine)
+        } else {
+            Detector::Energy(EnergyDetector::new())
+        };
 
         Ok(Self {
             detector,
-            threshold: threshold.clamp(0.0, 1.0),
-            is_enabled: enabled,
+            threshold: config.threshold.clamp(0.0, 1.0),
+            is_enabled: config.enabled,
+            filter: true,
             buffer: Vec::with_capacity(VAD_CHUNK_SIZE * 2),
             is_speaking: false,
             // Smoothed VAD initialization
-            frame_buffer: VecDeque::with_capacity(DEFAULT_PREFILL_FRAMES + 1),
-            prefill_frames: DEFAULT_PREFILL_FRAMES,
-            onset_frames: DEFAULT_ONSET_FRAMES,
-            hangover_frames: DEFAULT_HANGOVER_FRAMES,
+            frame_buffer: VecDeque::with_capacity(config.prefill_frames + config.onset_frames),
+            prefill_frames: config.prefill_frames,
+            // At least one speech frame is needed to start speech
+            onset_frames: config.onset_frames.max(1),
+            hangover_frames: config.hangover_frames,
             onset_counter: 0,
             hangover_counter: 0,
+            speech_detected: false,
+            samples_since_voice: 0,
+            samples_processed: 0,
         })
     }
 
+    /// Create a VAD processor that detects speech but keeps all audio
+    ///
+    /// Used for auto-stop when silence removal is off.
+    pub fn monitor(config: &VadConfig) -> Result<Self> {
+        let mut processor = Self::from_config(&VadConfig {
+            enabled: true,
+            ..*config
+        })?;
+        processor.filter = false;
+        Ok(processor)
+    }
+
     /// Create a disabled VAD processor (passthrough)
     pub fn disabled() -> Result<Self> {
         Self::new(false, 0.5)
@@ -106,6 +218,11 @@ impl VadProcessor {
         self.is_enabled
     }
 
+    /// Engine that scores frames (never `Auto`)
+    pub fn engine(&self) -> VadEngine {
+        self.detector.engine()
+    }
+
     /// Check if VAD is currently detecting complete silence
     ///
     /// Returns true when:
@@ -128,9 +245,24 @@ impl VadProcessor {
         }
     }
 
+    /// Whether confirmed speech has been detected since the last reset
+    pub fn speech_detected(&self) -> bool {
+        self.speech_detected
+    }
+
+    /// Time since the last frame that sounded like speech
+    pub fn trailing_silence(&self) -> Duration {
+        samples_to_duration(self.samples_since_voice)
+    }
+
+    /// Audio analyzed since the last reset
+    pub fn elapsed(&self) -> Duration {
+        samples_to_duration(self.samples_processed)
+    }
+
     /// Process audio samples and return samples that contain speech.
     ///
-    /// Uses Smoothed VAD approach:
+    /// Uses Smoothed VAD approach (all three are configurable via `VadConfig`):
     /// - Prefill: Buffers recent frames to emit when speech starts (captures word beginnings)
     /// - Onset: Requires consecutive speech frames to confirm speech (prevents noise false-positives)
     /// - Hangover: Continues recording after silence (captures trailing syllables)
@@ -154,60 +286,114 @@ impl VadProcessor {
         // Process complete chunks
         while self.buffer.len() >= VAD_CHUNK_SIZE {
             let chunk: Vec<f32> = self.buffer.drain(..VAD_CHUNK_SIZE).collect();
+            self.process_frame(chunk, &mut output);
+        }
 
-            // 1. Buffer for prefill (keep last N frames)
-            self.frame_buffer.push_back(chunk.clone());
-            while self.frame_buffer.len() > self.prefill_frames + 1 {
-                self.frame_buffer.pop_front();
-            }
+        // Detect-only: state is tracked, audio passes through untouched
+        if !self.filter {
+            return samples.to_vec();
+        }
+
+        output
+    }
 
-            // 2. Get VAD prediction
-            let probability = self.detector.predict(chunk.iter().copied());
-            let is_voice = probability >= self.threshold;
-
-            // 3. State machine (Smoothed VAD approach)
-            match (self.is_speaking, is_voice) {
-                // Potential speech onset - waiting for confirmation
-                (false, true) => {
-                    self.onset_counter += 1;
-                    if self.onset_counter >= self.onset_frames {
-                        // Confirmed speech - emit prefill + current frame
-                        self.is_speaking = true;
-                        self.hangover_counter = self.hangover_frames;
-                        self.onset_counter = 0;
-
-                        // Emit all buffered frames (prefill captures word beginning)
-                        for frame in &self.frame_buffer {
-                            output.extend_from_slice(frame);
-                        }
+    /// Run audio through the VAD and report every frame instead of the kept audio.
+    ///
+    /// Used to tune the threshold, prefill, onset and hangover for a microphone.
+    /// Returns nothing when VAD is disabled.
+    pub fn analyze(&mut self, samples: &[f32]) -> Vec<VadFrame> {
+        if !self.is_enabled {
+            return Vec::new();
+        }
+
+        let mut frames = Vec::with_capacity(samples.len() / VAD_CHUNK_SIZE + 1);
+        let mut discarded = Vec::new();
+        self.buffer.extend_from_slice(samples);
+
+        while self.buffer.len() >= VAD_CHUNK_SIZE {
+            let chunk: Vec<f32> = self.buffer.drain(..VAD_CHUNK_SIZE).collect();
+            let probability = self.process_frame(chunk, &mut discarded);
+            discarded.clear();
+            frames.push(VadFrame {
+                probability,
+                state: self.state(),
+            });
+        }
+
+        frames
+    }
+
+    /// Run one 512-sample frame through the state machine.
+    ///
+    /// Appends kept audio to `output` and returns the speech probability.
+    fn process_frame(&mut self, chunk: Vec<f32>, output: &mut Vec<f32>) -> f32 {
+        // 1. Get VAD prediction
+        let probability = self.detector.predict(&chunk);
+        let is_voice = probability >= self.threshold;
+
+        // 2. Buffer for prefill (keep the prefill plus the onset frames)
+        self.frame_buffer.push_back(chunk);
+        while self.frame_buffer.len() > self.prefill_frames + self.onset_frames {
+            self.frame_buffer.pop_front();
+        }
+
+        self.samples_processed += VAD_CHUNK_SIZE;
+        if is_voice {
+            self.samples_since_voice = 0;
+        } else {
+            self.samples_since_voice += VAD_CHUNK_SIZE;
+        }
+
+        // 3. State machine (Smoothed VAD approach)
+        match (self.is_speaking, is_voice) {
+            // Potential speech onset - waiting for confirmation
+            (false, true) => {
+                self.onset_counter += 1;
+                if self.onset_counter >= self.onset_frames {
+                    // Confirmed speech - emit prefill + onset frames
+                    self.is_speaking = true;
+                    self.hangover_counter = self.hangover_frames;
+                    self.onset_counter = 0;
+
+                    // Emit all buffered frames (prefill captures word beginning)
+                    for frame in &self.frame_buffer {
+                        output.extend_from_slice(frame);
                     }
-                    // else: still waiting for onset confirmation
                 }
+                // else: still waiting for onset confirmation
+            }
 
-                // Ongoing speech - reset hangover and emit
-                (true, true) => {
-                    self.hangover_counter = self.hangover_frames;
-                    output.extend_from_slice(&chunk);
+            // Ongoing speech - reset hangover and emit
+            (true, true) => {
+                self.hangover_counter = self.hangover_frames;
+                if let Some(frame) = self.frame_buffer.back() {
+                    output.extend_from_slice(frame);
                 }
+            }
 
-                // Potential speech end - use hangover
-                (true, false) => {
-                    if self.hangover_counter > 0 {
-                        self.hangover_counter -= 1;
-                        output.extend_from_slice(&chunk);
-                    } else {
-                        self.is_speaking = false;
+            // Potential speech end - use hangover
+            (true, false) => {
+                if self.hangover_counter > 0 {
+                    self.hangover_counter -= 1;
+                    if let Some(frame) = self.frame_buffer.back() {
+                        output.extend_from_slice(frame);
                     }
+                } else {
+                    self.is_speaking = false;
                 }
+            }
 
-                // Confirmed silence - reset onset counter
-                (false, false) => {
-                    self.onset_counter = 0;
-                }
+            // Confirmed silence - reset onset counter
+            (false, false) => {
+                self.onset_counter = 0;
             }
         }
 
-        output
+        if self.is_speaking {
+            self.speech_detected = true;
+        }
+
+        probability
     }
 
     /// Reset the VAD state for a new recording session.
@@ -219,6 +405,10 @@ impl VadProcessor {
         self.hangover_counter = 0;
         self.is_speaking = false;
         self.buffer.clear();
+        self.speech_detected = false;
+        self.samples_since_voice = 0;
+        self.samples_processed = 0;
+        self.detector.reset();
     }
 
     /// Flush remaining buffered samples.
@@ -232,8 +422,9 @@ impl VadProcessor {
 
         let mut output = Vec::new();
 
-        // If we were speaking, return remaining buffer
-        if self.is_speaking {
+        // If we were speaking, return remaining buffer (detect-only mode has
+        // already passed it through)
+        if self.is_speaking && self.filter {
             output.extend(std::mem::take(&mut self.buffer));
         }
 
@@ -243,3 +434,133 @@ impl VadProcessor {
         output
     }
 }
+
+fn samples_to_duration(samples: usize) -> Duration {
+    Duration::from_secs_f64(samples as f64 / WHISPER_SAMPLE_RATE as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SILENT: usize = 0;
+    const VOICE: usize = 1;
+
+    /// 200Hz tone (clearly speech to the energy detector) or digital silence
+    fn frames(kind: usize, count: usize) -> Vec<f32> {
+        (0..count * VAD_CHUNK_SIZE)
+            .map(|i| match kind {
+                VOICE => {
+                    (2.0 * std::f32::consts::PI * 200.0 * i as f32 / WHISPER_SAMPLE_RATE as f32)
+                        .sin()
+                        * 0.3
+                }
+                _ => 0.0,
+            })
+            .collect()
+    }
+
+    fn energy_vad(prefill: usize, onset: usize, hangover: usize) -> VadProcessor {
+        VadProcessor::from_config(&VadConfig {
+            enabled: true,
+            engine: VadEngine::Energy,
+            threshold: 0.5,
+            prefill_frames: prefill,
+            onset_frames: onset,
+            hangover_frames: hangover,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn energy_engine_is_used_when_requested() {
+        assert_eq!(energy_vad(3, 2, 4).engine(), VadEngine::Energy);
+    }
+
+    #[cfg(not(feature = "vad"))]
+    #[test]
+    fn auto_falls_back_to_energy_without_silero() {
+        for engine in [VadEngine::Auto, VadEngine::Silero] {
+            let vad = VadProcessor::from_config(&VadConfig {
+                engine,
+                ..VadConfig::enabled_with_threshold(0.5)
+            })
+            .unwrap();
+            assert_eq!(vad.engine(), VadEngine::Energy);
+        }
+    }
+
+    #[test]
+    fn monitor_keeps_all_audio_and_tracks_speech() {
+        let mut vad = VadProcessor::monitor(&VadConfig {
+            engine: VadEngine::Energy,
+            ..VadConfig::enabled_with_threshold(0.5)
+        })
+        .unwrap();
+        let mut input = frames(SILENT, 10);
+        input.extend(frames(VOICE, 5));
+
+        assert_eq!(vad.process(&input), input);
+        assert!(vad.state().is_speaking);
+        assert!(vad.flush().is_empty());
+    }
+
+    #[test]
+    fn speech_start_keeps_prefill_and_onset_frames() {
+        let mut vad = energy_vad(3, 2, 4);
+        let mut input = frames(SILENT, 10);
+        input.extend(frames(VOICE, 6));
+
+        let kept = vad.process(&input);
+
+        // 3 prefill + 2 onset frames when speech is confirmed, then 4 more
+        assert_eq!(kept.len(), 9 * VAD_CHUNK_SIZE);
+        assert!(kept[..3 * VAD_CHUNK_SIZE].iter().all(|&s| s == 0.0));
+        assert!(kept[3 * VAD_CHUNK_SIZE..].iter().any(|&s| s != 0.0));
+    }
+
+    #[test]
+    fn hangover_keeps_frames_after_speech() {
+        let mut vad = energy_vad(0, 1, 4);
+        let mut input = frames(SILENT, 5);
+        input.extend(frames(VOICE, 3));
+        input.extend(frames(SILENT, 8));
+
+        // 3 speech frames, then the 4 hangover frames
+        assert_eq!(vad.process(&input).len(), 7 * VAD_CHUNK_SIZE);
+        assert!(vad.is_silence());
+    }
+
+    #[test]
+    fn speech_shorter_than_onset_is_dropped() {
+        let mut vad = energy_vad(2, 3, 4);
+        let mut input = frames(SILENT, 5);
+        input.extend(frames(VOICE, 2));
+        input.extend(frames(SILENT, 5));
+
+        assert!(vad.process(&input).is_empty());
+        assert!(!vad.speech_detected());
+    }
+
+    #[test]
+    fn analyze_reports_onset_and_hangover_per_frame() {
+        let mut vad = energy_vad(3, 2, 2);
+        let mut input = frames(SILENT, 4);
+        input.extend(frames(VOICE, 3));
+        input.extend(frames(SILENT, 4));
+
+        let speaking: Vec<bool> = vad
+            .analyze(&input)
+            .iter()
+            .map(|frame| frame.state.is_speaking)
+            .collect();
+
+        // Confirmed on the second voice frame, held for 2 hangover frames
+        let expected = [
+            false, false, false, false, // silence
+            false, true, true, // voice (onset of 2)
+            true, true, false, false, // hangover, then silence
+        ];
+        assert_eq!(speaking, expected);
+    }
+}
@@ -97,6 +97,47 @@ pub const DEFAULT_VAD_ENABLED: bool = false;
 /// and capturing soft speech. Adjust via `whis config vad-threshold <value>`.
 pub const DEFAULT_VAD_THRESHOLD: f32 = 0.5;
 
+/// Default VAD prefill (32ms frames kept from before speech is confirmed)
+///
+/// 15 frames (~480ms) capture word beginnings that start below the threshold.
+/// Raise via `whis config vad-prefill <frames>` if first syllables are clipped.
+pub const DEFAULT_VAD_PREFILL_FRAMES: usize = 15;
+
+/// Default VAD onset (consecutive speech frames that confirm speech)
+///
+/// 2 frames (~64ms) ignore single clicks and pops.
+/// Adjust via `whis config vad-onset <frames>`.
+pub const DEFAULT_VAD_ONSET_FRAMES: usize = 2;
+
+/// Default VAD hangover (32ms frames kept after speech stops)
+///
+/// 15 frames (~480ms) keep trailing syllables and short pauses.
+/// Adjust via `whis config vad-hangover <frames>`.
+pub const DEFAULT_VAD_HANGOVER_FRAMES: usize = 15;
+
+/// Default noise suppression enabled state
+///
+/// Off by default; RNNoise can dull quiet speech in already clean recordings.
+/// Enable with `whis config denoise true` for noisy rooms or laptop mics.
+pub const DEFAULT_DENOISE_ENABLED: bool = false;
+
+/// Default noise suppression strength (0.0 = original, 1.0 = fully denoised)
+///
+/// Lower it via `whis config denoise-strength <value>` if speech sounds muffled.
+pub const DEFAULT_DENOISE_STRENGTH: f32 = 1.0;
+
+/// Default auto-stop enabled state
+///
+/// Off by default so recordings only end on Enter, the shortcut or `--duration`.
+/// Enable with `whis config auto-stop true` (or `whis --auto-stop` once).
+pub const DEFAULT_AUTO_STOP_ENABLED: bool = false;
+
+/// Default silence after speech that ends an auto-stop recording (seconds)
+///
+/// 1.5 seconds tolerates pauses between sentences without waiting long after
+/// the last word. Adjust via `whis config auto-stop-silence <seconds>`.
+pub const DEFAULT_AUTO_STOP_SILENCE_SECS: f32 = 1.5;
+
 /// Default chunk duration for progressive transcription (seconds)
 ///
 /// 90 seconds provides a good balance between transcription quality
@@ -104,6 +145,13 @@ pub const DEFAULT_VAD_THRESHOLD: f32 = 0.5;
 /// Smaller values (30s) feel more real-time, larger values (120s) improve accuracy.
 pub const DEFAULT_CHUNK_DURATION_SECS: u64 = 90;
 
+/// Default number of chunks transcribed at once by cloud providers
+///
+/// Long recordings and files produce a backlog of chunks; sending a few in
+/// parallel hides upload and inference time. Lowered automatically when the
+/// provider rate-limits. Adjust via `whis config chunk-concurrency <n>`.
+pub const DEFAULT_CHUNK_CONCURRENCY: usize = 3;
+
 // =============================================================================
 // SERVICE DEFAULTS
 // =============================================================================
@@ -10,5 +10,5 @@ mod preset;
 mod provider;
 
 pub use defaults::*;
-pub use preset::{Preset, PresetSource};
+pub use preset::{Preset, PresetSource, PresetVad};
 pub use provider::TranscriptionProvider;
@@ -21,7 +21,10 @@
 //!   "description": "What this preset does",
 //!   "prompt": "System prompt for the LLM",
 //!   "post_processor": "openai",  // optional override
-//!   "model": "gpt-4"             // optional override
+//!   "model": "gpt-4",            // optional override
+//!   "vocabulary": ["whis", "Tauri"], // optional, added to the global vocabulary
+//!   "translate": true,               // optional, output English
+//!   "vad": { "prefill_frames": 25 }  // optional, overrides VAD tuning
 //! }
 //! ```
 //!
@@ -53,7 +56,7 @@ pub struct Preset {
     /// Human-readable description
     pub description: String,
 
-    /// The system prompt for the LLM
+    /// The system prompt for the LLM (`{language}` is replaced by the detected language)
     pub prompt: String,
 
     /// Optional: Override the post-processor for this preset (openai, mistral)
@@ -63,6 +66,38 @@ pub struct Preset {
     /// Optional: Override the model for this preset
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+
+    /// Optional: Extra terms to bias transcription towards (added to the global vocabulary)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vocabulary: Vec<String>,
+
+    /// Optional: Translate the transcript to English
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub translate: bool,
+
+    /// Optional: Override VAD tuning while this preset is active
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad: Option<PresetVad>,
+}
+
+/// VAD tuning overrides for a preset (unset values use the VAD settings)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PresetVad {
+    /// Speech probability threshold (0.0-1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f32>,
+
+    /// Frames (32ms each) kept from before speech is confirmed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefill_frames: Option<usize>,
+
+    /// Consecutive speech frames required to confirm speech
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub onset_frames: Option<usize>,
+
+    /// Frames (32ms each) kept after speech stops
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hangover_frames: Option<usize>,
 }
 
 /// Where a preset was loaded from
@@ -105,6 +140,9 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                vocabulary: Vec::new(),
+                translate: false,
+                vad: None,
             },
             Preset {
                 name: "email".to_string(),
@@ -117,6 +155,9 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                vocabulary: Vec::new(),
+                translate: false,
+                vad: None,
             },
             Preset {
                 name: "default".to_string(),
@@ -129,6 +170,9 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                vocabulary: Vec::new(),
+                translate: false,
+                vad: None,
             },
         ]
     }
@@ -237,6 +281,9 @@ impl Preset {
             prompt: "Your system prompt here".to_string(),
             post_processor: None,
             model: None,
+            vocabulary: Vec::new(),
+            translate: false,
+            vad: None,
         }
     }
 
@@ -13,6 +13,10 @@
 //! - **Groq** - Fast inference
 //! - **ElevenLabs** - High quality
 //!
+//! # Custom Endpoint (API key optional)
+//!
+//! - **Custom** - Any OpenAI-compatible server (self-hosted or gateway)
+//!
 //! # Local Providers (no API key, require model download)
 //!
 //! - **LocalWhisper** - Run Whisper locally via ONNX
@@ -25,10 +29,15 @@
 //! - `api_key_env_var()` - Environment variable for API key
 //! - `requires_api_key()` - Whether cloud API key is needed
 //! - `is_local()` - Whether provider runs locally
+//! - `supports_diarization()` - Whether speaker labels are available
+//! - `default_model()` / `known_models()` - Model choices for cloud providers
+//! - `upload_formats()` - Audio formats accepted for uploads
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::audio::UploadFormat;
+
 /// Available transcription providers
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -42,6 +51,7 @@ pub enum TranscriptionProvider {
     #[serde(rename = "deepgram-realtime")]
     DeepgramRealtime,
     ElevenLabs,
+    Custom,
     #[serde(rename = "local-whisper")]
     LocalWhisper,
     #[serde(rename = "local-parakeet")]
@@ -65,6 +75,7 @@ impl TranscriptionProvider {
             TranscriptionProvider::Deepgram => "deepgram",
             TranscriptionProvider::DeepgramRealtime => "deepgram-realtime",
             TranscriptionProvider::ElevenLabs => "elevenlabs",
+            TranscriptionProvider::Custom => "custom",
             TranscriptionProvider::LocalWhisper => "local-whisper",
             TranscriptionProvider::LocalParakeet => "local-parakeet",
         }
@@ -82,6 +93,7 @@ impl TranscriptionProvider {
                 "DEEPGRAM_API_KEY"
             }
             TranscriptionProvider::ElevenLabs => "ELEVENLABS_API_KEY",
+            TranscriptionProvider::Custom => "CUSTOM_API_KEY",
             TranscriptionProvider::LocalWhisper => "LOCAL_WHISPER_MODEL_PATH",
             TranscriptionProvider::LocalParakeet => "LOCAL_PARAKEET_MODEL_PATH",
         }
@@ -100,6 +112,7 @@ impl TranscriptionProvider {
             TranscriptionProvider::Mistral,
             TranscriptionProvider::Groq,
             TranscriptionProvider::ElevenLabs,
+            TranscriptionProvider::Custom,
             TranscriptionProvider::LocalWhisper,
             TranscriptionProvider::LocalParakeet,
         ]
@@ -125,16 +138,21 @@ impl TranscriptionProvider {
             TranscriptionProvider::Deepgram => "Deepgram",
             TranscriptionProvider::DeepgramRealtime => "Deepgram Realtime",
             TranscriptionProvider::ElevenLabs => "ElevenLabs",
+            TranscriptionProvider::Custom => "Custom Endpoint",
             TranscriptionProvider::LocalWhisper => "Local Whisper",
             TranscriptionProvider::LocalParakeet => "Local Parakeet",
         }
     }
 
     /// Whether this provider requires an API key (vs path/URL for local/remote)
+    ///
+    /// The custom endpoint accepts an optional key, so it is not required.
     pub fn requires_api_key(&self) -> bool {
         !matches!(
             self,
-            TranscriptionProvider::LocalWhisper | TranscriptionProvider::LocalParakeet
+            TranscriptionProvider::LocalWhisper
+                | TranscriptionProvider::LocalParakeet
+                | TranscriptionProvider::Custom
         )
     }
 
@@ -146,6 +164,82 @@ impl TranscriptionProvider {
         )
     }
 
+    /// Whether this provider can label speakers (diarization)
+    pub fn supports_diarization(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionProvider::Deepgram | TranscriptionProvider::ElevenLabs
+        )
+    }
+
+    /// Whether this provider can translate speech to English natively.
+    ///
+    /// Other providers need an LLM pass through the post-processor instead.
+    pub fn supports_translation(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionProvider::OpenAI
+                | TranscriptionProvider::Groq
+                | TranscriptionProvider::LocalWhisper
+        )
+    }
+
+    /// Model sent when no override is configured.
+    ///
+    /// `None` for the custom endpoint and local providers, which configure their
+    /// model separately (`custom-model`, model paths).
+    pub fn default_model(&self) -> Option<&'static str> {
+        match self {
+            Self::OpenAI => Some("whisper-1"),
+            Self::OpenAIRealtime => Some("gpt-4o-transcribe"),
+            Self::Mistral => Some("voxtral-mini-latest"),
+            Self::Groq => Some("whisper-large-v3-turbo"),
+            Self::Deepgram => Some("nova-2"),
+            Self::DeepgramRealtime => Some("nova-3"),
+            Self::ElevenLabs => Some("scribe_v1"),
+            Self::Custom | Self::LocalWhisper | Self::LocalParakeet => None,
+        }
+    }
+
+    /// Known models for this provider, default first.
+    ///
+    /// Used for suggestions only; any other model name can be configured.
+    pub fn known_models(&self) -> &'static [&'static str] {
+        match self {
+            Self::OpenAI => &["whisper-1", "gpt-4o-transcribe", "gpt-4o-mini-transcribe"],
+            Self::OpenAIRealtime => &["gpt-4o-transcribe", "gpt-4o-mini-transcribe"],
+            Self::Mistral => &["voxtral-mini-latest", "voxtral-mini-2507"],
+            Self::Groq => &[
+                "whisper-large-v3-turbo",
+                "whisper-large-v3",
+                "distil-whisper-large-v3-en",
+            ],
+            Self::Deepgram => &["nova-2", "nova-3", "nova", "enhanced", "base"],
+            Self::DeepgramRealtime => &["nova-3", "nova-2"],
+            Self::ElevenLabs => &["scribe_v1", "scribe_v1_experimental"],
+            Self::Custom | Self::LocalWhisper | Self::LocalParakeet => &[],
+        }
+    }
+
+    /// Upload formats this provider accepts, preferred first.
+    ///
+    /// Cloud APIs prefer Opus (smallest upload for speech). Custom endpoints
+    /// prefer MP3, which every OpenAI-compatible server decodes; Opus and FLAC
+    /// are only used there when set explicitly. Local and realtime providers
+    /// don't upload files.
+    pub fn upload_formats(&self) -> &'static [UploadFormat] {
+        match self {
+            Self::OpenAI | Self::Mistral | Self::Groq | Self::Deepgram | Self::ElevenLabs => {
+                &[UploadFormat::Opus, UploadFormat::Mp3, UploadFormat::Flac]
+            }
+            Self::Custom => &[UploadFormat::Mp3, UploadFormat::Opus, UploadFormat::Flac],
+            Self::OpenAIRealtime
+            | Self::DeepgramRealtime
+            | Self::LocalWhisper
+            | Self::LocalParakeet => &[UploadFormat::Mp3],
+        }
+    }
+
     /// Get the API key name for this provider.
     ///
     /// Realtime variants share API keys with their base providers:
@@ -196,12 +290,13 @@ impl std::str::FromStr for TranscriptionProvider {
             "deepgram" => Ok(TranscriptionProvider::Deepgram),
             "deepgram-realtime" | "deepgramrealtime" => Ok(TranscriptionProvider::DeepgramRealtime),
             "elevenlabs" => Ok(TranscriptionProvider::ElevenLabs),
+            "custom" | "openai-compatible" => Ok(TranscriptionProvider::Custom),
             "local-whisper" | "localwhisper" | "whisper" => Ok(TranscriptionProvider::LocalWhisper),
             "local-parakeet" | "localparakeet" | "parakeet" => {
                 Ok(TranscriptionProvider::LocalParakeet)
             }
             _ => Err(format!(
-                "Unknown provider: {}. Available: openai, openai-realtime, mistral, groq, deepgram, deepgram-realtime, elevenlabs, local-whisper, local-parakeet",
+                "Unknown provider: {}. Available: openai, openai-realtime, mistral, groq, deepgram, deepgram-realtime, elevenlabs, custom, local-whisper, local-parakeet",
                 s
             )),
         }
@@ -24,25 +24,32 @@ pub mod verbose;
 
 // Re-export audio types
 pub use audio::{
-    AudioDeviceInfo, AudioRecorder, ChunkerConfig, ProgressiveChunk, ProgressiveChunker,
-    RecordingData, VadConfig, list_audio_devices,
+    AudioDeviceInfo, AudioLevel, AudioRecorder, AutoStopConfig, AutoStopReason, ChunkerConfig,
+    DenoiseConfig, ProgressiveChunk, ProgressiveChunker, RawPcmFormat, RecordingData, UploadFormat,
+    VadConfig, VadEngine, chunk_samples, list_audio_devices, stream_raw_pcm,
 };
+#[cfg(feature = "file-decoding")]
+pub use audio::{decode_audio_file, stream_audio};
 
 // Re-export configuration types
 pub use configuration::{
-    DEFAULT_LANGUAGE, DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSOR,
-    DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED,
-    DEFAULT_VAD_THRESHOLD,
+    DEFAULT_AUTO_STOP_ENABLED, DEFAULT_AUTO_STOP_SILENCE_SECS, DEFAULT_CHUNK_CONCURRENCY,
+    DEFAULT_DENOISE_ENABLED, DEFAULT_DENOISE_STRENGTH, DEFAULT_LANGUAGE, DEFAULT_OLLAMA_MODEL,
+    DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSOR, DEFAULT_PROVIDER, DEFAULT_SHORTCUT,
+    DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED, DEFAULT_VAD_HANGOVER_FRAMES,
+    DEFAULT_VAD_ONSET_FRAMES, DEFAULT_VAD_PREFILL_FRAMES, DEFAULT_VAD_THRESHOLD,
 };
-pub use configuration::{Preset, PresetSource, TranscriptionProvider};
+pub use configuration::{Preset, PresetSource, PresetVad, TranscriptionProvider};
 
 // Re-export transcription types
 #[cfg(feature = "local-transcription")]
 pub use transcription::progressive_transcribe_local;
 pub use transcription::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, WarmupConfig,
-    clear_warmup_cache, post_process, preload_ollama, progressive_transcribe_cloud,
-    resolve_post_processor_config, warmup_configured,
+    CloudTranscriptionOptions, DEFAULT_POST_PROCESSING_PROMPT, FallbackProvider,
+    LANGUAGE_PLACEHOLDER, PostProcessConfig, PostProcessor, TRANSLATION_PROMPT,
+    TranscriptionCheckpoint, WarmupConfig, clear_warmup_cache, fill_language_placeholder,
+    post_process, preload_ollama, progressive_transcribe_cloud, resolve_post_processor_config,
+    transcribe_chunks_resumable, transcribe_utterances, translate_transcript, warmup_configured,
 };
 
 // Re-export provider types
@@ -58,11 +65,16 @@ pub use provider::transcribe_raw;
 #[cfg(feature = "local-transcription")]
 pub use provider::transcribe_raw_parakeet;
 pub use provider::{
-    DEFAULT_TIMEOUT_SECS, ProgressCallback, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult, TranscriptionStage, registry,
+    ChunkSource, CustomProvider, DEFAULT_TIMEOUT_SECS, ProgressCallback, TranscriptionBackend,
+    TranscriptionRequest, TranscriptionResult, TranscriptionSegment, TranscriptionStage,
+    TranscriptionWord, registry,
 };
+#[cfg(all(feature = "realtime", feature = "local-transcription"))]
+pub use provider::{LocalStreamingEngine, LocalStreamingProvider};
 #[cfg(feature = "realtime")]
-pub use provider::{RealtimeTranscriptionBackend, get_realtime_backend};
+pub use provider::{
+    RealtimeTranscriptionBackend, TranscriptEvent, TranscriptEventSender, get_realtime_backend,
+};
 #[cfg(feature = "local-transcription")]
 pub use provider::{parakeet_set_keep_loaded, unload_parakeet};
 #[cfg(feature = "local-transcription")]
@@ -94,30 +106,36 @@ pub mod config {
 #[doc(hidden)]
 pub mod defaults {
     pub use crate::configuration::{
-        DEFAULT_LANGUAGE, DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSOR,
-        DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED,
-        DEFAULT_VAD_THRESHOLD,
+        DEFAULT_AUTO_STOP_ENABLED, DEFAULT_AUTO_STOP_SILENCE_SECS, DEFAULT_CHUNK_CONCURRENCY,
+        DEFAULT_DENOISE_ENABLED, DEFAULT_DENOISE_STRENGTH, DEFAULT_LANGUAGE, DEFAULT_OLLAMA_MODEL,
+        DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSOR, DEFAULT_PROVIDER, DEFAULT_SHORTCUT,
+        DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED, DEFAULT_VAD_HANGOVER_FRAMES,
+        DEFAULT_VAD_ONSET_FRAMES, DEFAULT_VAD_PREFILL_FRAMES, DEFAULT_VAD_THRESHOLD,
     };
 }
 
 #[doc(hidden)]
 pub mod preset {
-    pub use crate::configuration::{Preset, PresetSource};
+    pub use crate::configuration::{Preset, PresetSource, PresetVad};
 }
 
 #[doc(hidden)]
 pub mod post_processing {
     pub use crate::transcription::{
-        DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
-        resolve_post_processor_config,
+        DEFAULT_POST_PROCESSING_PROMPT, LANGUAGE_PLACEHOLDER, PostProcessConfig, PostProcessor,
+        TRANSLATION_PROMPT, fill_language_placeholder, post_process, resolve_post_processor_config,
+        translate_transcript,
     };
 }
 
 #[doc(hidden)]
 pub mod transcribe {
-    pub use crate::transcription::progressive_transcribe_cloud;
     #[cfg(feature = "local-transcription")]
     pub use crate::transcription::progressive_transcribe_local;
+    pub use crate::transcription::{
+        CloudTranscriptionOptions, FallbackProvider, TranscriptionCheckpoint,
+        progressive_transcribe_cloud, transcribe_chunks_resumable, transcribe_utterances,
+    };
 }
 
 #[doc(hidden)]
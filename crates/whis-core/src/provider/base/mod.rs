@@ -4,5 +4,6 @@ mod openai_compatible;
 pub(crate) mod retry;
 
 pub(crate) use openai_compatible::{
-    openai_compatible_transcribe_async, openai_compatible_transcribe_sync,
+    ApiAuth, openai_compatible_transcribe_async, openai_compatible_transcribe_async_with_auth,
+    openai_compatible_transcribe_sync, openai_compatible_transcribe_sync_with_auth,
 };
@@ -5,24 +5,108 @@
 //! - OpenAI Whisper API
 //! - Groq Whisper API
 //! - Mistral Voxtral API
+//! - Custom self-hosted endpoints (faster-whisper-server, whisper.cpp, LocalAI)
 //!
-//! All three providers use identical request/response formats:
+//! All of them use identical request/response formats:
 //! - Multipart form upload with `model` and `file` fields
-//! - Authorization via `Bearer` token
+//! - Authorization via `Bearer` token (custom endpoints may use another header or none)
 //! - JSON response with `text` field
+//!
+//! Providers that support `verbose_json` (OpenAI Whisper, Groq) can request
+//! segment and word timestamps, which are parsed into the transcription result
+//! along with the detected language and per-segment confidence.
+//!
+//! Vocabulary terms are sent as the `prompt` field, which Whisper uses to bias
+//! spelling of names and jargon.
+//!
+//! Requests with `translate` set are expected to target a `/audio/translations`
+//! endpoint (OpenAI Whisper, Groq): the language hint is dropped and the result
+//! is marked as translated.
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
 
 use super::super::{
-    DEFAULT_TIMEOUT_SECS, TranscriptionRequest, TranscriptionResult, TranscriptionStage,
+    DEFAULT_TIMEOUT_SECS, TranscriptionRequest, TranscriptionResult, TranscriptionSegment,
+    TranscriptionStage, TranscriptionWord, normalize_language, vocabulary_prompt,
+    weighted_confidence,
 };
 use super::retry::{RetryConfig, is_rate_limited, is_retryable_error, is_retryable_status};
 
 /// Response structure for OpenAI-compatible APIs
+///
+/// `language`, `segments` and `words` are only present for `verbose_json` responses.
 #[derive(Deserialize)]
 struct OpenAICompatibleResponse {
     text: String,
+    /// Detected language as an English name ("english")
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    segments: Option<Vec<Segment>>,
+    #[serde(default)]
+    words: Option<Vec<TranscriptionWord>>,
+}
+
+/// `verbose_json` segment
+#[derive(Deserialize)]
+struct Segment {
+    start: f64,
+    end: f64,
+    text: String,
+    /// Average token log probability, converted to a 0.0-1.0 confidence
+    #[serde(default)]
+    avg_logprob: Option<f64>,
+}
+
+impl From<OpenAICompatibleResponse> for TranscriptionResult {
+    fn from(resp: OpenAICompatibleResponse) -> Self {
+        let segments: Vec<TranscriptionSegment> = resp
+            .segments
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| TranscriptionSegment {
+                start: s.start,
+                end: s.end,
+                text: s.text,
+                speaker: None,
+                confidence: s.avg_logprob.map(f64::exp),
+            })
+            .collect();
+        let confidence =
+            weighted_confidence(segments.iter().map(|s| (s.end - s.start, s.confidence)));
+
+        Self {
+            text: resp.text,
+            segments,
+            words: resp.words.unwrap_or_default(),
+            detected_language: resp.language.as_deref().and_then(normalize_language),
+            confidence,
+            ..Default::default()
+        }
+    }
+}
+
+/// How requests are authenticated against an OpenAI-compatible API
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ApiAuth<'a> {
+    /// `Authorization: Bearer <key>`
+    Bearer(&'a str),
+    /// `<header>: <key>`
+    Header { name: &'a str, key: &'a str },
+    /// No authentication header
+    None,
+}
+
+impl ApiAuth<'_> {
+    /// Header name and value to send, if any
+    fn header(&self) -> Option<(&str, String)> {
+        match self {
+            ApiAuth::Bearer(key) => Some(("Authorization", format!("Bearer {key}"))),
+            ApiAuth::Header { name, key } => Some((*name, key.to_string())),
+            ApiAuth::None => None,
+        }
+    }
 }
 
 /// Transcribe audio using an OpenAI-compatible API (synchronous).
@@ -31,14 +115,33 @@ struct OpenAICompatibleResponse {
 /// - `api_url`: The API endpoint URL (e.g., "https://api.openai.com/v1/audio/transcriptions")
 /// - `model`: The model name to use (e.g., "whisper-1")
 /// - `api_key`: Bearer token for authentication
+/// - `timestamps`: Request `verbose_json` with segment and word timestamps
 /// - `request`: Transcription request with audio data and options
 ///
 /// # Returns
-/// Transcription result containing the text transcript
+/// Transcription result containing the text transcript (and timings if requested)
 pub(crate) fn openai_compatible_transcribe_sync(
     api_url: &str,
     model: &str,
     api_key: &str,
+    timestamps: bool,
+    request: TranscriptionRequest,
+) -> Result<TranscriptionResult> {
+    openai_compatible_transcribe_sync_with_auth(
+        api_url,
+        model,
+        ApiAuth::Bearer(api_key),
+        timestamps,
+        request,
+    )
+}
+
+/// Synchronous variant of the OpenAI-compatible request with explicit authentication.
+pub(crate) fn openai_compatible_transcribe_sync_with_auth(
+    api_url: &str,
+    model: &str,
+    auth: ApiAuth<'_>,
+    timestamps: bool,
     request: TranscriptionRequest,
 ) -> Result<TranscriptionResult> {
     // Report uploading stage
@@ -51,6 +154,7 @@ pub(crate) fn openai_compatible_transcribe_sync(
 
     let config = RetryConfig::default();
     let mut attempt = 0;
+    let mut rate_limited = 0;
 
     loop {
         let mut form = reqwest::blocking::multipart::Form::new()
@@ -62,18 +166,33 @@ pub(crate) fn openai_compatible_transcribe_sync(
                     .mime_str(&request.mime_type)?,
             );
 
-        if let Some(lang) = request.language.clone() {
+        // The translations endpoint always outputs English and takes no language
+        if let Some(lang) = request.language.clone().filter(|_| !request.translate) {
             form = form.text("language", lang);
         }
 
+        if let Some(prompt) = vocabulary_prompt(&request.vocabulary) {
+            form = form.text("prompt", prompt);
+        }
+
+        if timestamps {
+            form = form.text("response_format", "verbose_json");
+            // Translations return segments only, without granularity options
+            if !request.translate {
+                form = form
+                    .text("timestamp_granularities[]", "segment")
+                    .text("timestamp_granularities[]", "word");
+            }
+        }
+
         // Report transcribing stage (request sent, waiting for response)
         request.report(TranscriptionStage::Transcribing);
 
-        let result = client
-            .post(api_url)
-            .header("Authorization", format!("Bearer {api_key}"))
-            .multipart(form)
-            .send();
+        let mut builder = client.post(api_url);
+        if let Some((name, value)) = auth.header() {
+            builder = builder.header(name, value);
+        }
+        let result = builder.multipart(form).send();
 
         match result {
             Ok(response) => {
@@ -83,7 +202,14 @@ pub(crate) fn openai_compatible_transcribe_sync(
                     let text = response.text().context("Failed to get response text")?;
                     let resp: OpenAICompatibleResponse =
                         serde_json::from_str(&text).context("Failed to parse API response")?;
-                    return Ok(TranscriptionResult { text: resp.text });
+                    let mut result: TranscriptionResult = resp.into();
+                    result.translated = request.translate;
+                    result.rate_limited = rate_limited;
+                    return Ok(result);
+                }
+
+                if is_rate_limited(status) {
+                    rate_limited += 1;
                 }
 
                 // Check if error is retryable
@@ -136,15 +262,37 @@ pub(crate) fn openai_compatible_transcribe_sync(
 /// - `api_url`: The API endpoint URL
 /// - `model`: The model name to use
 /// - `api_key`: Bearer token for authentication
+/// - `timestamps`: Request `verbose_json` with segment and word timestamps
 /// - `request`: Transcription request with audio data and options
 ///
 /// # Returns
-/// Transcription result containing the text transcript
+/// Transcription result containing the text transcript (and timings if requested)
 pub(crate) async fn openai_compatible_transcribe_async(
     client: &reqwest::Client,
     api_url: &str,
     model: &str,
     api_key: &str,
+    timestamps: bool,
+    request: TranscriptionRequest,
+) -> Result<TranscriptionResult> {
+    openai_compatible_transcribe_async_with_auth(
+        client,
+        api_url,
+        model,
+        ApiAuth::Bearer(api_key),
+        timestamps,
+        request,
+    )
+    .await
+}
+
+/// Asynchronous variant of the OpenAI-compatible request with explicit authentication.
+pub(crate) async fn openai_compatible_transcribe_async_with_auth(
+    client: &reqwest::Client,
+    api_url: &str,
+    model: &str,
+    auth: ApiAuth<'_>,
+    timestamps: bool,
     request: TranscriptionRequest,
 ) -> Result<TranscriptionResult> {
     // Report uploading stage
@@ -152,6 +300,7 @@ pub(crate) async fn openai_compatible_transcribe_async(
 
     let config = RetryConfig::default();
     let mut attempt = 0;
+    let mut rate_limited = 0;
 
     loop {
         let mut form = reqwest::multipart::Form::new()
@@ -163,19 +312,33 @@ pub(crate) async fn openai_compatible_transcribe_async(
                     .mime_str(&request.mime_type)?,
             );
 
-        if let Some(lang) = request.language.clone() {
+        // The translations endpoint always outputs English and takes no language
+        if let Some(lang) = request.language.clone().filter(|_| !request.translate) {
             form = form.text("language", lang);
         }
 
+        if let Some(prompt) = vocabulary_prompt(&request.vocabulary) {
+            form = form.text("prompt", prompt);
+        }
+
+        if timestamps {
+            form = form.text("response_format", "verbose_json");
+            // Translations return segments only, without granularity options
+            if !request.translate {
+                form = form
+                    .text("timestamp_granularities[]", "segment")
+                    .text("timestamp_granularities[]", "word");
+            }
+        }
+
         // Report transcribing stage
         request.report(TranscriptionStage::Transcribing);
 
-        let result = client
-            .post(api_url)
-            .header("Authorization", format!("Bearer {api_key}"))
-            .multipart(form)
-            .send()
-            .await;
+        let mut builder = client.post(api_url);
+        if let Some((name, value)) = auth.header() {
+            builder = builder.header(name, value);
+        }
+        let result = builder.multipart(form).send().await;
 
         match result {
             Ok(response) => {
@@ -188,7 +351,14 @@ pub(crate) async fn openai_compatible_transcribe_async(
                         .context("Failed to get response text")?;
                     let resp: OpenAICompatibleResponse =
                         serde_json::from_str(&text).context("Failed to parse API response")?;
-                    return Ok(TranscriptionResult { text: resp.text });
+                    let mut result: TranscriptionResult = resp.into();
+                    result.translated = request.translate;
+                    result.rate_limited = rate_limited;
+                    return Ok(result);
+                }
+
+                if is_rate_limited(status) {
+                    rate_limited += 1;
                 }
 
                 // Check if error is retryable
@@ -5,6 +5,10 @@
 //! - 429 Rate Limited
 //! - 5xx Server Errors
 //! - Network/connection errors
+//!
+//! Providers count the rate-limit responses they retried in
+//! `TranscriptionResult::rate_limited`, so callers that run several requests at
+//! once (parallel chunk transcription) can back off.
 
 use std::time::Duration;
 
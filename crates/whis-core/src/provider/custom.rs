@@ -0,0 +1,92 @@
+//! Custom OpenAI-compatible transcription provider
+//!
+//! Targets self-hosted or gateway endpoints that speak the OpenAI
+//! `/audio/transcriptions` API (faster-whisper-server, whisper.cpp `server`,
+//! LocalAI, corporate proxies). Base URL, model, and auth style come from the
+//! `CustomEndpointConfig` carried by each `TranscriptionRequest`.
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+
+use super::{
+    ApiAuth, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    openai_compatible_transcribe_async_with_auth, openai_compatible_transcribe_sync_with_auth,
+};
+use crate::settings::{CustomAuthStyle, CustomEndpointConfig};
+
+/// Take the endpoint configuration off a request
+fn endpoint(request: &mut TranscriptionRequest) -> Result<(CustomEndpointConfig, String)> {
+    let config = request
+        .custom_endpoint
+        .take()
+        .ok_or_else(|| anyhow!("Custom endpoint not configured"))?;
+    let url = config
+        .transcription_url()
+        .ok_or_else(|| anyhow!("Custom endpoint URL not set. Run: whis config custom-url <url>"))?;
+    Ok((config, url))
+}
+
+/// Build the auth for a request (an empty key sends no header)
+fn auth<'a>(config: &'a CustomEndpointConfig, api_key: &'a str) -> ApiAuth<'a> {
+    if api_key.is_empty() {
+        return ApiAuth::None;
+    }
+    match config.auth {
+        CustomAuthStyle::Bearer => ApiAuth::Bearer(api_key),
+        CustomAuthStyle::Header => ApiAuth::Header {
+            name: config.auth_header(),
+            key: api_key,
+        },
+        CustomAuthStyle::None => ApiAuth::None,
+    }
+}
+
+/// Custom OpenAI-compatible transcription provider
+///
+/// API key is optional; many self-hosted servers accept unauthenticated requests.
+#[derive(Debug, Default, Clone)]
+pub struct CustomProvider;
+
+#[async_trait]
+impl TranscriptionBackend for CustomProvider {
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Custom Endpoint"
+    }
+
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        mut request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let (config, url) = endpoint(&mut request)?;
+        openai_compatible_transcribe_sync_with_auth(
+            &url,
+            config.model(),
+            auth(&config, api_key),
+            config.timestamps,
+            request,
+        )
+    }
+
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        mut request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let (config, url) = endpoint(&mut request)?;
+        openai_compatible_transcribe_async_with_auth(
+            client,
+            &url,
+            config.model(),
+            auth(&config, api_key),
+            config.timestamps,
+            request,
+        )
+        .await
+    }
+}
@@ -4,6 +4,9 @@
 //! - Raw audio bytes in request body (not multipart form)
 //! - Options passed as query parameters
 //! - Different response JSON structure
+//! - Speaker diarization via `diarize=true` (per-word speaker index)
+//! - Vocabulary via `keywords` (Nova-2) or `keyterm` (Nova-3) query parameters
+//! - Language detection via `detect_language=true` when no language is set
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -12,11 +15,11 @@ use serde::Deserialize;
 use super::base::retry::{RetryConfig, is_rate_limited, is_retryable_error, is_retryable_status};
 use super::{
     DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
-    TranscriptionStage,
+    TranscriptionStage, TranscriptionWord, model_for, normalize_language,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.deepgram.com/v1/listen";
-const MODEL: &str = "nova-2";
 
 #[derive(Deserialize)]
 struct Response {
@@ -31,16 +34,91 @@ struct Results {
 #[derive(Deserialize)]
 struct Channel {
     alternatives: Vec<Alternative>,
+    /// Present when `detect_language` is enabled
+    detected_language: Option<String>,
+    language_confidence: Option<f64>,
 }
 
 #[derive(Deserialize)]
 struct Alternative {
     transcript: String,
+    confidence: Option<f64>,
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    word: String,
+    start: f64,
+    end: f64,
+    /// Present when `smart_format` is enabled (capitalized + punctuated)
+    punctuated_word: Option<String>,
+    /// Present when `diarize` is enabled (zero-based)
+    speaker: Option<u32>,
+    confidence: Option<f64>,
+}
+
+/// Add vocabulary terms as query parameters.
+///
+/// Nova-3 uses `keyterm` prompting; older models use `keywords` boosting.
+fn append_vocabulary(url: &mut reqwest::Url, model: &str, vocabulary: &[String]) {
+    let param = if model.starts_with("nova-3") {
+        "keyterm"
+    } else {
+        "keywords"
+    };
+    let mut pairs = url.query_pairs_mut();
+    for term in vocabulary.iter().map(|t| t.trim()) {
+        if !term.is_empty() {
+            pairs.append_pair(param, term);
+        }
+    }
+}
+
+/// Parse a Deepgram response body into a transcription result with word timings
+fn parse_response(body: &str) -> Result<TranscriptionResult> {
+    let resp: Response =
+        serde_json::from_str(body).context("Failed to parse Deepgram API response")?;
+
+    let channel = resp.results.channels.into_iter().next();
+    let (detected_language, language_confidence) = channel
+        .as_ref()
+        .map(|c| (c.detected_language.clone(), c.language_confidence))
+        .unwrap_or_default();
+
+    let alternative = channel
+        .and_then(|c| c.alternatives.into_iter().next())
+        .ok_or_else(|| {
+            anyhow::anyhow!("Deepgram API returned unexpected response format: no transcript found")
+        })?;
+
+    let words = alternative
+        .words
+        .into_iter()
+        .map(|w| TranscriptionWord {
+            start: w.start,
+            end: w.end,
+            word: w.punctuated_word.unwrap_or(w.word),
+            speaker: w.speaker,
+            confidence: w.confidence,
+        })
+        .collect();
+
+    Ok(TranscriptionResult {
+        text: alternative.transcript,
+        segments: Vec::new(),
+        words,
+        detected_language: detected_language.as_deref().and_then(normalize_language),
+        language_confidence,
+        confidence: alternative.confidence,
+        ..Default::default()
+    })
 }
 
 /// Deepgram Nova transcription provider
 ///
-/// Uses Deepgram's REST API with Nova-2 model.
+/// Uses Deepgram's REST API with the Nova-2 model by default (see `model_for`).
 /// Offers fast transcription at $0.26/hour with good accuracy.
 #[derive(Debug, Default, Clone)]
 pub struct DeepgramProvider;
@@ -69,16 +147,29 @@ impl TranscriptionBackend for DeepgramProvider {
             .context("Failed to create HTTP client")?;
 
         let mut url = reqwest::Url::parse(API_URL).context("Failed to parse Deepgram URL")?;
+        let model = model_for(&TranscriptionProvider::Deepgram, request.model.as_deref());
         url.query_pairs_mut()
-            .append_pair("model", MODEL)
+            .append_pair("model", &model)
             .append_pair("smart_format", "true");
 
-        if let Some(lang) = &request.language {
-            url.query_pairs_mut().append_pair("language", lang);
+        match &request.language {
+            Some(lang) => {
+                url.query_pairs_mut().append_pair("language", lang);
+            }
+            None => {
+                url.query_pairs_mut().append_pair("detect_language", "true");
+            }
+        }
+
+        if request.diarize {
+            url.query_pairs_mut().append_pair("diarize", "true");
         }
 
+        append_vocabulary(&mut url, &model, &request.vocabulary);
+
         let config = RetryConfig::default();
         let mut attempt = 0;
+        let mut rate_limited = 0;
 
         loop {
             // Report transcribing stage
@@ -97,22 +188,14 @@ impl TranscriptionBackend for DeepgramProvider {
 
                     if status.is_success() {
                         let text = response.text().context("Failed to get response text")?;
-                        let resp: Response = serde_json::from_str(&text)
-                            .context("Failed to parse Deepgram API response")?;
-
-                        let transcript = resp
-                            .results
-                            .channels
-                            .first()
-                            .and_then(|c| c.alternatives.first())
-                            .map(|a| a.transcript.clone())
-                            .ok_or_else(|| {
-                                anyhow::anyhow!(
-                                    "Deepgram API returned unexpected response format: no transcript found"
-                                )
-                            })?;
-
-                        return Ok(TranscriptionResult { text: transcript });
+                        return parse_response(&text).map(|result| TranscriptionResult {
+                            rate_limited,
+                            ..result
+                        });
+                    }
+
+                    if is_rate_limited(status) {
+                        rate_limited += 1;
                     }
 
                     // Check if error is retryable
@@ -168,16 +251,29 @@ impl TranscriptionBackend for DeepgramProvider {
         request.report(TranscriptionStage::Uploading);
 
         let mut url = reqwest::Url::parse(API_URL).context("Failed to parse Deepgram URL")?;
+        let model = model_for(&TranscriptionProvider::Deepgram, request.model.as_deref());
         url.query_pairs_mut()
-            .append_pair("model", MODEL)
+            .append_pair("model", &model)
             .append_pair("smart_format", "true");
 
-        if let Some(lang) = &request.language {
-            url.query_pairs_mut().append_pair("language", lang);
+        match &request.language {
+            Some(lang) => {
+                url.query_pairs_mut().append_pair("language", lang);
+            }
+            None => {
+                url.query_pairs_mut().append_pair("detect_language", "true");
+            }
+        }
+
+        if request.diarize {
+            url.query_pairs_mut().append_pair("diarize", "true");
         }
 
+        append_vocabulary(&mut url, &model, &request.vocabulary);
+
         let config = RetryConfig::default();
         let mut attempt = 0;
+        let mut rate_limited = 0;
 
         loop {
             // Report transcribing stage
@@ -200,22 +296,14 @@ impl TranscriptionBackend for DeepgramProvider {
                             .text()
                             .await
                             .context("Failed to get response text")?;
-                        let resp: Response = serde_json::from_str(&text)
-                            .context("Failed to parse Deepgram API response")?;
-
-                        let transcript = resp
-                            .results
-                            .channels
-                            .first()
-                            .and_then(|c| c.alternatives.first())
-                            .map(|a| a.transcript.clone())
-                            .ok_or_else(|| {
-                                anyhow::anyhow!(
-                                    "Deepgram API returned unexpected response format: no transcript found"
-                                )
-                            })?;
-
-                        return Ok(TranscriptionResult { text: transcript });
+                        return parse_response(&text).map(|result| TranscriptionResult {
+                            rate_limited,
+                            ..result
+                        });
+                    }
+
+                    if is_rate_limited(status) {
+                        rate_limited += 1;
                     }
 
                     // Check if error is retryable
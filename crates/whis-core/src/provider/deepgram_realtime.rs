@@ -26,13 +26,14 @@ use tokio_tungstenite::{
     },
 };
 
+use super::realtime::{TranscriptEvent, TranscriptEventSender, send_event};
 use super::{
     DeepgramProvider, RealtimeTranscriptionBackend, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult,
+    TranscriptionResult, model_for,
 };
+use crate::config::TranscriptionProvider;
 
 const WS_URL: &str = "wss://api.deepgram.com/v1/listen";
-const MODEL: &str = "nova-3";
 const SAMPLE_RATE: u32 = 16000;
 /// Keepalive interval - 4s gives wider margin vs 10s server timeout
 const KEEPALIVE_INTERVAL_SECS: u64 = 4;
@@ -42,7 +43,10 @@ const KEEPALIVE_INTERVAL_SECS: u64 = 4;
 /// Streams audio via WebSocket for real-time, low-latency transcription.
 /// Uses the same API key as batch Deepgram (DEEPGRAM_API_KEY).
 #[derive(Debug, Default, Clone)]
-pub struct DeepgramRealtimeProvider;
+pub struct DeepgramRealtimeProvider {
+    /// Model override (None = the provider's default model)
+    model: Option<String>,
+}
 
 // Response message types
 
@@ -81,18 +85,26 @@ const MAX_RETRIES: u32 = 3;
 const BASE_BACKOFF_SECS: u64 = 1;
 
 impl DeepgramRealtimeProvider {
+    /// Provider sending `model` instead of the default model (when set)
+    pub fn new(model: Option<String>) -> Self {
+        Self { model }
+    }
+
     /// Transcribe audio from a channel of f32 samples (16kHz mono)
     ///
     /// Connects to Deepgram Live Streaming API via WebSocket and streams audio chunks
     /// as they arrive. Returns the final transcript when the channel closes.
+    /// Interim and final results are forwarded to `events` when provided.
     async fn transcribe_stream_impl(
         api_key: &str,
+        model: String,
         mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        events: Option<TranscriptEventSender>,
     ) -> Result<String> {
         // 1. Build WebSocket URL with query params
         let mut url = format!(
-            "{WS_URL}?model={MODEL}&encoding=linear16&sample_rate={SAMPLE_RATE}\
+            "{WS_URL}?model={model}&encoding=linear16&sample_rate={SAMPLE_RATE}\
              &channels=1&smart_format=true&interim_results=true"
         );
 
@@ -168,7 +180,8 @@ impl DeepgramRealtimeProvider {
         let (done_tx, done_rx) = oneshot::channel::<usize>();
 
         // 5. Spawn read task to collect transcripts
-        let read_handle = tokio::spawn(async move { collect_transcripts(read, done_rx).await });
+        let read_handle =
+            tokio::spawn(async move { collect_transcripts(read, done_rx, events).await });
 
         // 6. Spawn keepalive task
         let (keepalive_cancel_tx, keepalive_cancel_rx) = oneshot::channel();
@@ -252,8 +265,10 @@ impl DeepgramRealtimeProvider {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        model: Option<&str>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        let model = model_for(&TranscriptionProvider::DeepgramRealtime, model);
+        Self::transcribe_stream_impl(api_key, model, audio_rx, language, None).await
     }
 }
 
@@ -305,6 +320,7 @@ where
 async fn collect_transcripts<S>(
     mut read: S,
     mut done_rx: oneshot::Receiver<usize>,
+    events: Option<TranscriptEventSender>,
 ) -> Result<String>
 where
     S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
@@ -328,7 +344,7 @@ where
 
             // Process WebSocket messages
             msg = read.next() => {
-                if let Some(result) = process_message(msg, &mut final_transcript)? {
+                if let Some(result) = process_message(msg, &mut final_transcript, events.as_ref())? {
                     return Ok(result);
                 }
             }
@@ -371,7 +387,7 @@ where
             }
 
             msg = read.next() => {
-                if let Some(result) = process_message(msg, &mut final_transcript)? {
+                if let Some(result) = process_message(msg, &mut final_transcript, events.as_ref())? {
                     return Ok(result);
                 }
                 // Continue waiting - don't reset the deadline, just process more messages
@@ -386,6 +402,7 @@ where
 fn process_message(
     msg: Option<Result<Message, tokio_tungstenite::tungstenite::Error>>,
     final_transcript: &mut String,
+    events: Option<&TranscriptEventSender>,
 ) -> Result<Option<String>> {
     match msg {
         Some(Ok(Message::Text(text))) => {
@@ -401,14 +418,19 @@ fn process_message(
 
             match event.event_type.as_str() {
                 "Results" => {
-                    // Only collect final results (ignore interim results where is_final=false)
-                    if event.is_final
-                        && let Some(channel) = event.channel
+                    // Only final results go into the transcript; interim results
+                    // (is_final=false) are forwarded as partials for live display
+                    if let Some(channel) = event.channel
                         && let Some(alt) = channel.alternatives.first()
                         && !alt.transcript.is_empty()
                     {
-                        final_transcript.push_str(&alt.transcript);
-                        final_transcript.push(' ');
+                        if event.is_final {
+                            final_transcript.push_str(&alt.transcript);
+                            final_transcript.push(' ');
+                            send_event(events, TranscriptEvent::Final(alt.transcript.clone()));
+                        } else {
+                            send_event(events, TranscriptEvent::Partial(alt.transcript.clone()));
+                        }
                     }
 
                     // Note: Don't return immediately on from_finalize.
@@ -463,13 +485,18 @@ fn process_message(
 
 #[async_trait]
 impl RealtimeTranscriptionBackend for DeepgramRealtimeProvider {
-    async fn transcribe_stream(
+    async fn transcribe_stream_with_events(
         &self,
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        events: Option<TranscriptEventSender>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        let model = model_for(
+            &TranscriptionProvider::DeepgramRealtime,
+            self.model.as_deref(),
+        );
+        Self::transcribe_stream_impl(api_key, model, audio_rx, language, events).await
     }
 
     fn sample_rate(&self) -> u32 {
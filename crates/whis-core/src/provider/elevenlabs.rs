@@ -2,6 +2,10 @@
 //!
 //! ElevenLabs Scribe claims the highest accuracy in the market with ~3.3% English WER.
 //! Uses multipart form upload with a different response structure.
+//! Speaker diarization is requested with the `diarize` form field; each word
+//! then carries a `speaker_id` like `"speaker_0"`. Vocabulary terms are sent
+//! as repeated `keyterms` fields. The detected language is returned as an
+//! ISO 639-3 `language_code` and normalized to ISO 639-1.
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -10,15 +14,67 @@ use serde::Deserialize;
 use super::base::retry::{RetryConfig, is_rate_limited, is_retryable_error, is_retryable_status};
 use super::{
     DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
-    TranscriptionStage,
+    TranscriptionStage, TranscriptionWord, model_for, normalize_language, weighted_confidence,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.elevenlabs.io/v1/speech-to-text";
-const MODEL: &str = "scribe_v1";
 
 #[derive(Deserialize)]
 struct Response {
     text: String,
+    /// Detected (or requested) language as ISO 639-3 ("eng")
+    language_code: Option<String>,
+    language_probability: Option<f64>,
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+/// Timed token from Scribe (words, spacing, and audio events like "(laughter)")
+#[derive(Deserialize)]
+struct Word {
+    text: String,
+    start: f64,
+    end: f64,
+    #[serde(rename = "type", default)]
+    kind: String,
+    /// Present when `diarize` is enabled (e.g., "speaker_0")
+    speaker_id: Option<String>,
+    /// Log probability of the token, converted to a 0.0-1.0 confidence
+    logprob: Option<f64>,
+}
+
+/// Parse a Scribe speaker id ("speaker_0") into a zero-based index
+fn parse_speaker_id(id: &str) -> Option<u32> {
+    id.strip_prefix("speaker_")?.parse().ok()
+}
+
+impl From<Response> for TranscriptionResult {
+    fn from(resp: Response) -> Self {
+        let words: Vec<TranscriptionWord> = resp
+            .words
+            .into_iter()
+            .filter(|w| w.kind == "word")
+            .map(|w| TranscriptionWord {
+                start: w.start,
+                end: w.end,
+                speaker: w.speaker_id.as_deref().and_then(parse_speaker_id),
+                confidence: w.logprob.map(f64::exp),
+                word: w.text,
+            })
+            .collect();
+        let confidence = weighted_confidence(words.iter().map(|w| (w.end - w.start, w.confidence)));
+
+        Self {
+            text: resp.text,
+            segments: Vec::new(),
+            words,
+            detected_language: resp.language_code.as_deref().and_then(normalize_language),
+            language_confidence: resp.language_probability,
+            confidence,
+            ..Default::default()
+        }
+    }
 }
 
 /// ElevenLabs Scribe transcription provider
@@ -52,12 +108,14 @@ impl TranscriptionBackend for ElevenLabsProvider {
             .build()
             .context("Failed to create HTTP client")?;
 
+        let model = model_for(&TranscriptionProvider::ElevenLabs, request.model.as_deref());
         let config = RetryConfig::default();
         let mut attempt = 0;
+        let mut rate_limited = 0;
 
         loop {
             let mut form = reqwest::blocking::multipart::Form::new()
-                .text("model_id", MODEL)
+                .text("model_id", model.clone())
                 .part(
                     "file",
                     reqwest::blocking::multipart::Part::bytes(request.audio_data.clone())
@@ -69,6 +127,16 @@ impl TranscriptionBackend for ElevenLabsProvider {
                 form = form.text("language_code", lang);
             }
 
+            if request.diarize {
+                form = form.text("diarize", "true");
+            }
+
+            for term in request.vocabulary.iter().map(|t| t.trim()) {
+                if !term.is_empty() {
+                    form = form.text("keyterms", term.to_string());
+                }
+            }
+
             // Report transcribing stage
             request.report(TranscriptionStage::Transcribing);
 
@@ -86,7 +154,14 @@ impl TranscriptionBackend for ElevenLabsProvider {
                         let text = response.text().context("Failed to get response text")?;
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse ElevenLabs API response")?;
-                        return Ok(TranscriptionResult { text: resp.text });
+                        return Ok(TranscriptionResult {
+                            rate_limited,
+                            ..TranscriptionResult::from(resp)
+                        });
+                    }
+
+                    if is_rate_limited(status) {
+                        rate_limited += 1;
                     }
 
                     // Check if error is retryable
@@ -141,12 +216,14 @@ impl TranscriptionBackend for ElevenLabsProvider {
         // Report uploading stage
         request.report(TranscriptionStage::Uploading);
 
+        let model = model_for(&TranscriptionProvider::ElevenLabs, request.model.as_deref());
         let config = RetryConfig::default();
         let mut attempt = 0;
+        let mut rate_limited = 0;
 
         loop {
             let mut form = reqwest::multipart::Form::new()
-                .text("model_id", MODEL)
+                .text("model_id", model.clone())
                 .part(
                     "file",
                     reqwest::multipart::Part::bytes(request.audio_data.clone())
@@ -158,6 +235,16 @@ impl TranscriptionBackend for ElevenLabsProvider {
                 form = form.text("language_code", lang);
             }
 
+            if request.diarize {
+                form = form.text("diarize", "true");
+            }
+
+            for term in request.vocabulary.iter().map(|t| t.trim()) {
+                if !term.is_empty() {
+                    form = form.text("keyterms", term.to_string());
+                }
+            }
+
             // Report transcribing stage
             request.report(TranscriptionStage::Transcribing);
 
@@ -179,7 +266,14 @@ impl TranscriptionBackend for ElevenLabsProvider {
                             .context("Failed to get response text")?;
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse ElevenLabs API response")?;
-                        return Ok(TranscriptionResult { text: resp.text });
+                        return Ok(TranscriptionResult {
+                            rate_limited,
+                            ..TranscriptionResult::from(resp)
+                        });
+                    }
+
+                    if is_rate_limited(status) {
+                        rate_limited += 1;
                     }
 
                     // Check if error is retryable
@@ -7,12 +7,17 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use super::{
-    TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult, model_for,
     openai_compatible_transcribe_async, openai_compatible_transcribe_sync,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.groq.com/openai/v1/audio/transcriptions";
-const MODEL: &str = "whisper-large-v3-turbo";
+const TRANSLATION_API_URL: &str = "https://api.groq.com/openai/v1/audio/translations";
+/// The turbo model doesn't support translation
+const TRANSLATION_MODEL: &str = "whisper-large-v3";
+/// Groq Whisper supports `verbose_json` with segment and word timestamps
+const TIMESTAMPS: bool = true;
 
 /// Groq Whisper transcription provider
 ///
@@ -21,6 +26,18 @@ const MODEL: &str = "whisper-large-v3-turbo";
 #[derive(Debug, Default, Clone)]
 pub struct GroqProvider;
 
+/// API URL and model for a request (translations use a separate endpoint)
+fn endpoint(request: &TranscriptionRequest) -> (&'static str, String) {
+    if request.translate {
+        (TRANSLATION_API_URL, TRANSLATION_MODEL.to_string())
+    } else {
+        (
+            API_URL,
+            model_for(&TranscriptionProvider::Groq, request.model.as_deref()),
+        )
+    }
+}
+
 #[async_trait]
 impl TranscriptionBackend for GroqProvider {
     fn name(&self) -> &'static str {
@@ -36,7 +53,8 @@ impl TranscriptionBackend for GroqProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_sync(API_URL, MODEL, api_key, request)
+        let (url, model) = endpoint(&request);
+        openai_compatible_transcribe_sync(url, &model, api_key, TIMESTAMPS, request)
     }
 
     async fn transcribe_async(
@@ -45,6 +63,7 @@ impl TranscriptionBackend for GroqProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_async(client, API_URL, MODEL, api_key, request).await
+        let (url, model) = endpoint(&request);
+        openai_compatible_transcribe_async(client, url, &model, api_key, TIMESTAMPS, request).await
     }
 }
@@ -0,0 +1,146 @@
+//! Language code normalization
+//!
+//! Providers report the detected language in different forms:
+//! - OpenAI / Groq `verbose_json`: lowercase English name ("english")
+//! - Deepgram: ISO 639-1, sometimes with a region ("en", "en-US")
+//! - ElevenLabs: ISO 639-3 ("eng")
+//!
+//! Everything is normalized to ISO 639-1 so callers can compare results
+//! against the `language` setting.
+
+/// Languages supported by Whisper: (ISO 639-1, ISO 639-3, English name)
+const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("af", "afr", "afrikaans"),
+    ("am", "amh", "amharic"),
+    ("ar", "ara", "arabic"),
+    ("as", "asm", "assamese"),
+    ("az", "aze", "azerbaijani"),
+    ("ba", "bak", "bashkir"),
+    ("be", "bel", "belarusian"),
+    ("bg", "bul", "bulgarian"),
+    ("bn", "ben", "bengali"),
+    ("bo", "bod", "tibetan"),
+    ("br", "bre", "breton"),
+    ("bs", "bos", "bosnian"),
+    ("ca", "cat", "catalan"),
+    ("cs", "ces", "czech"),
+    ("cy", "cym", "welsh"),
+    ("da", "dan", "danish"),
+    ("de", "deu", "german"),
+    ("el", "ell", "greek"),
+    ("en", "eng", "english"),
+    ("es", "spa", "spanish"),
+    ("et", "est", "estonian"),
+    ("eu", "eus", "basque"),
+    ("fa", "fas", "persian"),
+    ("fi", "fin", "finnish"),
+    ("fo", "fao", "faroese"),
+    ("fr", "fra", "french"),
+    ("gl", "glg", "galician"),
+    ("gu", "guj", "gujarati"),
+    ("ha", "hau", "hausa"),
+    ("haw", "haw", "hawaiian"),
+    ("he", "heb", "hebrew"),
+    ("hi", "hin", "hindi"),
+    ("hr", "hrv", "croatian"),
+    ("ht", "hat", "haitian creole"),
+    ("hu", "hun", "hungarian"),
+    ("hy", "hye", "armenian"),
+    ("id", "ind", "indonesian"),
+    ("is", "isl", "icelandic"),
+    ("it", "ita", "italian"),
+    ("ja", "jpn", "japanese"),
+    ("jv", "jav", "javanese"),
+    ("ka", "kat", "georgian"),
+    ("kk", "kaz", "kazakh"),
+    ("km", "khm", "khmer"),
+    ("kn", "kan", "kannada"),
+    ("ko", "kor", "korean"),
+    ("la", "lat", "latin"),
+    ("lb", "ltz", "luxembourgish"),
+    ("ln", "lin", "lingala"),
+    ("lo", "lao", "lao"),
+    ("lt", "lit", "lithuanian"),
+    ("lv", "lav", "latvian"),
+    ("mg", "mlg", "malagasy"),
+    ("mi", "mri", "maori"),
+    ("mk", "mkd", "macedonian"),
+    ("ml", "mal", "malayalam"),
+    ("mn", "mon", "mongolian"),
+    ("mr", "mar", "marathi"),
+    ("ms", "msa", "malay"),
+    ("mt", "mlt", "maltese"),
+    ("my", "mya", "myanmar"),
+    ("ne", "nep", "nepali"),
+    ("nl", "nld", "dutch"),
+    ("nn", "nno", "nynorsk"),
+    ("no", "nor", "norwegian"),
+    ("oc", "oci", "occitan"),
+    ("pa", "pan", "punjabi"),
+    ("pl", "pol", "polish"),
+    ("ps", "pus", "pashto"),
+    ("pt", "por", "portuguese"),
+    ("ro", "ron", "romanian"),
+    ("ru", "rus", "russian"),
+    ("sa", "san", "sanskrit"),
+    ("sd", "snd", "sindhi"),
+    ("si", "sin", "sinhala"),
+    ("sk", "slk", "slovak"),
+    ("sl", "slv", "slovenian"),
+    ("sn", "sna", "shona"),
+    ("so", "som", "somali"),
+    ("sq", "sqi", "albanian"),
+    ("sr", "srp", "serbian"),
+    ("su", "sun", "sundanese"),
+    ("sv", "swe", "swedish"),
+    ("sw", "swa", "swahili"),
+    ("ta", "tam", "tamil"),
+    ("te", "tel", "telugu"),
+    ("tg", "tgk", "tajik"),
+    ("th", "tha", "thai"),
+    ("tk", "tuk", "turkmen"),
+    ("tl", "tgl", "tagalog"),
+    ("tr", "tur", "turkish"),
+    ("tt", "tat", "tatar"),
+    ("uk", "ukr", "ukrainian"),
+    ("ur", "urd", "urdu"),
+    ("uz", "uzb", "uzbek"),
+    ("vi", "vie", "vietnamese"),
+    ("yi", "yid", "yiddish"),
+    ("yo", "yor", "yoruba"),
+    ("yue", "yue", "cantonese"),
+    ("zh", "zho", "chinese"),
+];
+
+/// Codes some providers use in place of the table entries
+const ALIASES: &[(&str, &str)] = &[
+    ("cmn", "zh"),
+    ("fil", "tl"),
+    ("jw", "jv"),
+    ("nob", "no"),
+    ("zsm", "ms"),
+];
+
+/// Normalize a provider-reported language to ISO 639-1
+///
+/// Accepts ISO 639-1/639-3 codes (optionally with a region suffix) and the
+/// English names Whisper reports. Unknown values are returned lowercased.
+/// Returns `None` for an empty value.
+pub(crate) fn normalize_language(value: &str) -> Option<String> {
+    let value = value.trim().to_lowercase();
+    if value.is_empty() {
+        return None;
+    }
+
+    // "en-US" / "pt_BR" → "en" / "pt"
+    let code = value.split(['-', '_']).next().unwrap_or(&value);
+
+    if let Some((_, iso1)) = ALIASES.iter().find(|(alias, _)| *alias == code) {
+        return Some(iso1.to_string());
+    }
+
+    let known = LANGUAGES
+        .iter()
+        .find(|(iso1, iso3, name)| *iso1 == code || *iso3 == code || *name == value);
+    Some(known.map_or(value.clone(), |(iso1, _, _)| iso1.to_string()))
+}
@@ -10,7 +10,9 @@ use async_trait::async_trait;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
-use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+use super::{
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult, TranscriptionSegment,
+};
 
 /// Local Parakeet transcription provider
 #[derive(Debug, Default, Clone)]
@@ -61,9 +63,25 @@ pub fn transcribe_raw(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
 
 /// Internal function to transcribe PCM samples using Parakeet
 ///
-/// ONNX Runtime has memory constraints with long audio in Parakeet models.
-/// This function automatically chunks audio longer than 90 seconds to avoid ORT errors.
+/// Unloads the engine afterwards unless keep-loaded is set.
 fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<TranscriptionResult> {
+    let result = transcribe_window(model_path, samples);
+
+    // Conditionally unload based on KEEP_LOADED flag
+    maybe_unload();
+
+    result
+}
+
+/// Transcribe samples without unloading the engine afterwards.
+///
+/// Used by streaming and progressive transcription, which release the engine
+/// once at the end. ONNX Runtime has memory constraints with long audio in
+/// Parakeet models, so audio longer than 90 seconds is chunked to avoid ORT errors.
+pub(crate) fn transcribe_window(
+    model_path: &str,
+    samples: Vec<f32>,
+) -> Result<TranscriptionResult> {
     use transcribe_rs::engines::parakeet::{ParakeetInferenceParams, TimestampGranularity};
 
     // Empirically tested: Parakeet works well up to ~90 seconds
@@ -89,18 +107,19 @@ fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
     let result = if samples.len() <= CHUNK_SIZE {
         transcribe_chunk_with_engine(&mut cached.engine, samples, &params)?
     } else {
-        // Split long audio into chunks with overlap
+        // Split long audio into chunks with overlap (remember each chunk's start sample)
         let mut chunks = Vec::new();
         let mut start = 0;
         while start < samples.len() {
             let end = (start + CHUNK_SIZE).min(samples.len());
-            chunks.push(&samples[start..end]);
+            chunks.push((start, &samples[start..end]));
             start += CHUNK_SIZE - OVERLAP;
         }
 
         // Transcribe each chunk using the same engine instance
         let mut results = Vec::new();
-        for (i, chunk) in chunks.iter().enumerate() {
+        let mut segments: Vec<TranscriptionSegment> = Vec::new();
+        for (i, (chunk_start, chunk)) in chunks.iter().enumerate() {
             crate::verbose!(
                 "Transcribing chunk {}/{} ({:.1}s)...",
                 i + 1,
@@ -108,29 +127,36 @@ fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
                 chunk.len() as f32 / 16000.0
             );
 
-            let chunk_result =
+            let mut chunk_result =
                 transcribe_chunk_with_engine(&mut cached.engine, chunk.to_vec(), &params)?;
+            chunk_result.shift(*chunk_start as f64 / 16000.0);
+
+            // Segments inside the overlap were already emitted by the previous chunk
+            let last_end = segments.last().map_or(0.0, |seg| seg.end);
+            segments.extend(
+                chunk_result
+                    .segments
+                    .into_iter()
+                    .filter(|seg| seg.start >= last_end),
+            );
             results.push(chunk_result.text);
         }
 
         // Concatenate chunk results with space separator
         TranscriptionResult {
             text: results.join(" "),
+            segments,
+            words: Vec::new(),
+            ..Default::default()
         }
     };
 
-    // Release the lock before maybe_unload
-    drop(cache);
-
-    // Conditionally unload based on KEEP_LOADED flag
-    maybe_unload();
-
     Ok(result)
 }
 
 /// Transcribe a single chunk of audio using an already-loaded engine
 ///
-/// This function is used internally by `transcribe_samples()` to reuse the same
+/// This function is used internally by `transcribe_window()` to reuse the same
 /// engine instance across multiple chunks, avoiding repeated model loading.
 fn transcribe_chunk_with_engine(
     engine: &mut transcribe_rs::engines::parakeet::ParakeetEngine,
@@ -145,8 +171,25 @@ fn transcribe_chunk_with_engine(
         .transcribe_samples(samples, Some(params.clone()))
         .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?;
 
+    let segments = result
+        .segments
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|seg| !seg.text.trim().is_empty())
+        .map(|seg| TranscriptionSegment {
+            start: f64::from(seg.start),
+            end: f64::from(seg.end),
+            text: seg.text.trim().to_string(),
+            speaker: None,
+            confidence: None,
+        })
+        .collect();
+
     Ok(TranscriptionResult {
         text: result.text.trim().to_string(),
+        segments,
+        words: Vec::new(),
+        ..Default::default()
     })
 }
 
@@ -294,7 +337,7 @@ pub fn unload_parakeet() {
 }
 
 /// Called after transcription to conditionally unload the model.
-fn maybe_unload() {
+pub(crate) fn maybe_unload() {
     if !should_keep_loaded() {
         unload_parakeet();
     }
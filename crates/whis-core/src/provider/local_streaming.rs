@@ -0,0 +1,304 @@
+//! Local streaming transcription (Whisper / Parakeet)
+//!
+//! Implements `RealtimeTranscriptionBackend` on top of the cached local engines so
+//! offline users get live text while speaking and almost nothing left to decode
+//! when recording stops.
+//!
+//! # Sliding Window
+//!
+//! ```text
+//! audio ──► window (uncommitted audio, ≤ 20s)
+//!              │  every ~1s of new audio
+//!              ▼
+//!           decode ──► segments ──► compare with previous decode
+//!                                      │
+//!              ┌───────────────────────┴──────────────────────┐
+//!              ▼                                              ▼
+//!   leading segments that agree                     remaining segments
+//!   → Final event, trim window                      → Partial event
+//! ```
+//!
+//! A segment is committed once two consecutive decodes produce the same text for
+//! it and it is not the last segment (the last one may still be cut mid-word).
+//! If the window grows past its limit without agreement, everything but the
+//! last segment (or a single runaway segment) is committed anyway to bound
+//! latency. On stop, the remaining window is decoded once more and committed.
+//!
+//! The `api_key` argument of `transcribe_stream` carries the model path, matching
+//! how local providers are configured everywhere else. Vocabulary and translation
+//! are set on the provider and only apply to Whisper.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use super::realtime::{TranscriptEvent, TranscriptEventSender, send_event};
+use super::{
+    RealtimeTranscriptionBackend, TranscriptionResult, TranscriptionSegment, local_parakeet,
+    local_whisper,
+};
+
+const SAMPLE_RATE: u32 = 16000;
+/// Decode again after this much new audio (1s)
+const STEP_SAMPLES: usize = SAMPLE_RATE as usize;
+/// Don't decode windows shorter than this (1s) while recording
+const MIN_WINDOW_SAMPLES: usize = SAMPLE_RATE as usize;
+/// Force a commit once uncommitted audio exceeds this (20s)
+const MAX_WINDOW_SAMPLES: usize = 20 * SAMPLE_RATE as usize;
+/// Skip the final decode for trailing audio shorter than this (0.3s)
+const MIN_FINAL_SAMPLES: usize = (SAMPLE_RATE as usize * 3) / 10;
+
+/// Local engine used for streaming decoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalStreamingEngine {
+    Whisper,
+    Parakeet,
+}
+
+/// Local streaming provider (sliding-window decoding with the cached engines)
+#[derive(Debug, Clone)]
+pub struct LocalStreamingProvider {
+    engine: LocalStreamingEngine,
+    /// Terms to bias recognition toward (Whisper only)
+    vocabulary: Vec<String>,
+    /// Translate to English while decoding (Whisper only)
+    translate: bool,
+}
+
+impl LocalStreamingProvider {
+    pub fn new(engine: LocalStreamingEngine) -> Self {
+        Self {
+            engine,
+            vocabulary: Vec::new(),
+            translate: false,
+        }
+    }
+
+    /// Bias recognition toward these terms (Whisper only)
+    pub fn with_vocabulary(mut self, vocabulary: Vec<String>) -> Self {
+        self.vocabulary = vocabulary;
+        self
+    }
+
+    /// Translate to English while decoding (Whisper only)
+    pub fn with_translate(mut self, translate: bool) -> Self {
+        self.translate = translate;
+        self
+    }
+
+    /// Decode one window on the blocking pool (engines hold a global lock)
+    async fn decode(
+        &self,
+        model_path: &str,
+        samples: Vec<f32>,
+        language: Option<&str>,
+    ) -> Result<TranscriptionResult> {
+        let engine = self.engine;
+        let model_path = model_path.to_string();
+        let language = language.map(str::to_string);
+        let vocabulary = self.vocabulary.clone();
+        let translate = self.translate;
+
+        tokio::task::spawn_blocking(move || match engine {
+            LocalStreamingEngine::Whisper => local_whisper::transcribe_window(
+                &model_path,
+                &samples,
+                language.as_deref(),
+                &vocabulary,
+                translate,
+            ),
+            LocalStreamingEngine::Parakeet => {
+                local_parakeet::transcribe_window(&model_path, samples)
+            }
+        })
+        .await
+        .context("Local decode task panicked")?
+    }
+
+    /// Release the engine according to the keep-loaded setting
+    fn release(&self) {
+        match self.engine {
+            LocalStreamingEngine::Whisper => local_whisper::maybe_unload(),
+            LocalStreamingEngine::Parakeet => local_parakeet::maybe_unload(),
+        }
+    }
+
+    async fn stream(
+        &self,
+        model_path: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        events: Option<TranscriptEventSender>,
+    ) -> Result<String> {
+        let mut window: Vec<f32> = Vec::new();
+        let mut since_decode = 0usize;
+        let mut previous: Vec<TranscriptionSegment> = Vec::new();
+        let mut committed: Vec<String> = Vec::new();
+
+        while let Some(samples) = audio_rx.recv().await {
+            since_decode += samples.len();
+            window.extend_from_slice(&samples);
+
+            // Pick up audio that queued while the last decode was running
+            while let Ok(samples) = audio_rx.try_recv() {
+                since_decode += samples.len();
+                window.extend_from_slice(&samples);
+            }
+
+            if since_decode < STEP_SAMPLES || window.len() < MIN_WINDOW_SAMPLES {
+                continue;
+            }
+            since_decode = 0;
+
+            let hypothesis = self
+                .decode(model_path, window.clone(), language.as_deref())
+                .await?
+                .segments;
+
+            // Past the window limit, commit without waiting for agreement
+            let force = window.len() >= MAX_WINDOW_SAMPLES;
+            let stable = match (force, hypothesis.len()) {
+                (false, _) => stable_prefix(&previous, &hypothesis),
+                // A single runaway segment is committed rather than grown forever
+                (true, 1) => 1,
+                (true, n) => n.saturating_sub(1),
+            };
+
+            // Commit stable segments and drop their audio from the window
+            let cut_secs = if stable > 0 {
+                hypothesis[stable - 1].end
+            } else {
+                0.0
+            };
+            for segment in &hypothesis[..stable] {
+                send_event(
+                    events.as_ref(),
+                    TranscriptEvent::Final(segment.text.clone()),
+                );
+                committed.push(segment.text.clone());
+            }
+            if stable > 0 {
+                let cut = ((cut_secs * f64::from(SAMPLE_RATE)) as usize).min(window.len());
+                window.drain(..cut);
+            } else if force {
+                // Nothing recognized in a full window: it's silence, keep only the tail
+                window.drain(..window.len() - MIN_WINDOW_SAMPLES);
+            }
+
+            previous = hypothesis[stable..]
+                .iter()
+                .map(|segment| TranscriptionSegment {
+                    start: segment.start - cut_secs,
+                    end: segment.end - cut_secs,
+                    ..segment.clone()
+                })
+                .collect();
+
+            let partial = previous
+                .iter()
+                .map(|segment| segment.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !partial.is_empty() {
+                send_event(events.as_ref(), TranscriptEvent::Partial(partial));
+            }
+        }
+
+        crate::verbose!(
+            "[local-streaming] Finalizing {:.1}s of uncommitted audio",
+            window.len() as f64 / f64::from(SAMPLE_RATE)
+        );
+
+        // Finalize: decode whatever hasn't been committed yet
+        if window.len() >= MIN_FINAL_SAMPLES {
+            let tail = self.decode(model_path, window, language.as_deref()).await?;
+            let text = tail.text.trim();
+            if !text.is_empty() {
+                send_event(events.as_ref(), TranscriptEvent::Final(text.to_string()));
+                committed.push(text.to_string());
+            }
+        }
+
+        Ok(committed.join(" "))
+    }
+}
+
+/// Number of leading segments that two consecutive decodes agree on.
+///
+/// The last segment of the current decode is never considered stable, since the
+/// window may end in the middle of it.
+fn stable_prefix(previous: &[TranscriptionSegment], current: &[TranscriptionSegment]) -> usize {
+    previous
+        .iter()
+        .zip(current.iter().take(current.len().saturating_sub(1)))
+        .take_while(|(a, b)| a.text.trim() == b.text.trim())
+        .count()
+}
+
+#[async_trait]
+impl RealtimeTranscriptionBackend for LocalStreamingProvider {
+    async fn transcribe_stream_with_events(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        events: Option<TranscriptEventSender>,
+    ) -> Result<String> {
+        let result = self.stream(api_key, audio_rx, language, events).await;
+        self.release();
+        result
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn translates(&self) -> bool {
+        self.translate && self.engine == LocalStreamingEngine::Whisper
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(texts: &[&str]) -> Vec<TranscriptionSegment> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| TranscriptionSegment {
+                start: i as f64,
+                end: i as f64 + 1.0,
+                text: text.to_string(),
+                speaker: None,
+                confidence: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stable_prefix_counts_leading_segments_that_agree() {
+        let previous = segments(&["Hello there.", "How are", "you"]);
+        let current = segments(&[" Hello there. ", "How are", "you doing", "today"]);
+
+        assert_eq!(stable_prefix(&previous, &current), 2);
+    }
+
+    #[test]
+    fn stable_prefix_never_includes_the_last_current_segment() {
+        let previous = segments(&["Hello there.", "How are you"]);
+        let current = segments(&["Hello there.", "How are you"]);
+
+        assert_eq!(stable_prefix(&previous, &current), 1);
+        assert_eq!(stable_prefix(&previous, &segments(&["Hello there."])), 0);
+    }
+
+    #[test]
+    fn stable_prefix_stops_at_the_first_difference() {
+        let previous = segments(&["Hello their.", "How are", "you"]);
+        let current = segments(&["Hello there.", "How are", "you", "doing"]);
+
+        assert_eq!(stable_prefix(&previous, &current), 0);
+        assert_eq!(stable_prefix(&[], &current), 0);
+    }
+}
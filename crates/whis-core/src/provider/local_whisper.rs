@@ -5,6 +5,9 @@
 //!
 //! Uses engine-level caching to avoid reloading the model on every
 //! transcription (saves 200ms-2s per call in listen mode).
+//!
+//! transcribe-rs doesn't expose whisper.cpp's language probabilities or token
+//! log probabilities, so results carry no `detected_language` or confidence.
 
 #![warn(missing_unsafe_on_extern)]
 
@@ -14,7 +17,10 @@ use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
-use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+use super::{
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult, TranscriptionSegment,
+    vocabulary_prompt,
+};
 
 // ============================================================================
 // stderr Suppression for GGML Vulkan Output
@@ -158,12 +164,16 @@ impl TranscriptionBackend for LocalWhisperProvider {
 /// * `model_path` - Path to the whisper.cpp model file (.bin)
 /// * `samples` - Raw f32 audio samples (must be 16kHz mono)
 /// * `language` - Optional language code (e.g., "en", "de")
+/// * `vocabulary` - Terms to bias recognition towards (sent as the initial prompt)
+/// * `translate` - Translate speech to English instead of transcribing it
 pub fn transcribe_raw(
     model_path: &str,
     samples: &[f32],
     language: Option<&str>,
+    vocabulary: &[String],
+    translate: bool,
 ) -> Result<TranscriptionResult> {
-    transcribe_samples(model_path, samples, language)
+    transcribe_samples(model_path, samples, language, vocabulary, translate)
 }
 
 // ============================================================================
@@ -240,6 +250,27 @@ fn transcribe_samples(
     model_path: &str,
     samples: &[f32],
     language: Option<&str>,
+    vocabulary: &[String],
+    translate: bool,
+) -> Result<TranscriptionResult> {
+    let result = transcribe_window(model_path, samples, language, vocabulary, translate);
+
+    // Conditionally unload based on KEEP_LOADED flag
+    maybe_unload();
+
+    result
+}
+
+/// Transcribe samples without unloading the engine afterwards.
+///
+/// Used by the local streaming backend and progressive transcription, which
+/// decode many times per recording and call [`maybe_unload`] once when they finish.
+pub(crate) fn transcribe_window(
+    model_path: &str,
+    samples: &[f32],
+    language: Option<&str>,
+    vocabulary: &[String],
+    translate: bool,
 ) -> Result<TranscriptionResult> {
     use transcribe_rs::TranscriptionEngine;
     use transcribe_rs::engines::whisper::WhisperInferenceParams;
@@ -248,7 +279,7 @@ fn transcribe_samples(
     get_or_load_engine(model_path)?;
 
     // Perform transcription with locked access to engine
-    let text = {
+    let result = {
         let mut cache = get_cache().lock().unwrap();
         let cached = cache
             .as_mut()
@@ -257,7 +288,7 @@ fn transcribe_samples(
         // Configure inference parameters
         let params = WhisperInferenceParams {
             language: language.map(|s| s.to_string()),
-            translate: false,
+            translate,
             print_special: false,
             print_progress: false,
             print_realtime: false,
@@ -265,7 +296,7 @@ fn transcribe_samples(
             suppress_blank: true,
             suppress_non_speech_tokens: true,
             no_speech_thold: 0.2,
-            initial_prompt: None,
+            initial_prompt: vocabulary_prompt(vocabulary),
         };
 
         // Suppress stderr during transcription to hide whisper.cpp noise
@@ -279,14 +310,29 @@ fn transcribe_samples(
 
         drop(_stderr_guard);
 
-        result.text
+        result
     };
 
-    // Conditionally unload based on KEEP_LOADED flag
-    maybe_unload();
+    let segments = result
+        .segments
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|seg| !seg.text.trim().is_empty())
+        .map(|seg| TranscriptionSegment {
+            start: f64::from(seg.start),
+            end: f64::from(seg.end),
+            text: seg.text.trim().to_string(),
+            speaker: None,
+            confidence: None,
+        })
+        .collect();
 
     Ok(TranscriptionResult {
-        text: text.trim().to_string(),
+        text: result.text.trim().to_string(),
+        segments,
+        words: Vec::new(),
+        translated: translate,
+        ..Default::default()
     })
 }
 
@@ -324,7 +370,7 @@ pub fn unload_model() {
 }
 
 /// Called after transcription to conditionally unload the model.
-fn maybe_unload() {
+pub(crate) fn maybe_unload() {
     if !should_keep_loaded() {
         unload_model();
     }
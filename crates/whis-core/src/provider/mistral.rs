@@ -4,12 +4,20 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use super::{
-    TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult, model_for,
     openai_compatible_transcribe_async, openai_compatible_transcribe_sync,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.mistral.ai/v1/audio/transcriptions";
-const MODEL: &str = "voxtral-mini-latest";
+/// Voxtral returns plain text only (no `verbose_json`)
+const TIMESTAMPS: bool = false;
+
+/// Voxtral has no Whisper-style `prompt` field, so vocabulary is not sent
+fn without_vocabulary(mut request: TranscriptionRequest) -> TranscriptionRequest {
+    request.vocabulary.clear();
+    request
+}
 
 /// Mistral Voxtral transcription provider
 #[derive(Debug, Default, Clone)]
@@ -30,7 +38,13 @@ impl TranscriptionBackend for MistralProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_sync(API_URL, MODEL, api_key, request)
+        openai_compatible_transcribe_sync(
+            API_URL,
+            &model_for(&TranscriptionProvider::Mistral, request.model.as_deref()),
+            api_key,
+            TIMESTAMPS,
+            without_vocabulary(request),
+        )
     }
 
     async fn transcribe_async(
@@ -39,6 +53,14 @@ impl TranscriptionBackend for MistralProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_async(client, API_URL, MODEL, api_key, request).await
+        openai_compatible_transcribe_async(
+            client,
+            API_URL,
+            &model_for(&TranscriptionProvider::Mistral, request.model.as_deref()),
+            api_key,
+            TIMESTAMPS,
+            without_vocabulary(request),
+        )
+        .await
     }
 }
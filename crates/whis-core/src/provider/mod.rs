@@ -11,6 +11,7 @@
 //!   ├── Base         - Shared HTTP logic (OpenAI-compatible APIs)
 //!   └── Providers    - Individual provider implementations
 //!       ├── Cloud    - OpenAI, Mistral, Groq, Deepgram, ElevenLabs
+//!       ├── Custom   - Any OpenAI-compatible endpoint
 //!       └── Local    - Whisper, Parakeet
 //! ```
 //!
@@ -20,6 +21,7 @@
 //! - OpenAI Whisper API
 //! - Groq Whisper API
 //! - Mistral Voxtral API
+//! - Custom endpoint (self-hosted or gateway, configurable URL/model/auth)
 //!
 //! **Cloud Providers** (Custom format):
 //! - Deepgram Nova API
@@ -41,6 +43,7 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, OnceLock};
 
@@ -79,17 +82,22 @@ impl TranscriptionStage {
 pub type ProgressCallback = Arc<dyn Fn(TranscriptionStage) + Send + Sync>;
 
 mod base;
+mod custom;
 mod deepgram;
 #[cfg(feature = "realtime")]
 mod deepgram_realtime;
 mod elevenlabs;
 pub mod error;
 mod groq;
+mod language;
 #[cfg(feature = "local-transcription")]
 mod local_parakeet;
+#[cfg(all(feature = "realtime", feature = "local-transcription"))]
+mod local_streaming;
 #[cfg(feature = "local-transcription")]
 pub mod local_whisper;
 mod mistral;
+mod models;
 mod openai;
 #[cfg(feature = "realtime")]
 mod openai_realtime;
@@ -99,6 +107,7 @@ mod realtime;
 /// Default timeout for API requests (5 minutes)
 pub const DEFAULT_TIMEOUT_SECS: u64 = 300;
 
+pub use custom::CustomProvider;
 pub use deepgram::DeepgramProvider;
 #[cfg(feature = "realtime")]
 pub use deepgram_realtime::DeepgramRealtimeProvider;
@@ -113,6 +122,8 @@ pub use local_parakeet::preload_parakeet;
 pub use local_parakeet::transcribe_raw as transcribe_raw_parakeet;
 #[cfg(feature = "local-transcription")]
 pub use local_parakeet::{set_keep_loaded as parakeet_set_keep_loaded, unload_parakeet};
+#[cfg(all(feature = "realtime", feature = "local-transcription"))]
+pub use local_streaming::{LocalStreamingEngine, LocalStreamingProvider};
 #[cfg(feature = "local-transcription")]
 pub use local_whisper::LocalWhisperProvider;
 #[cfg(feature = "local-transcription")]
@@ -127,9 +138,10 @@ pub use openai::OpenAIProvider;
 #[cfg(feature = "realtime")]
 pub use openai_realtime::OpenAIRealtimeProvider;
 #[cfg(feature = "realtime")]
-pub use realtime::RealtimeTranscriptionBackend;
+pub use realtime::{RealtimeTranscriptionBackend, TranscriptEvent, TranscriptEventSender};
 
 use crate::config::TranscriptionProvider;
+use crate::settings::CustomEndpointConfig;
 
 /// Request data for transcription
 #[derive(Clone)]
@@ -138,6 +150,16 @@ pub struct TranscriptionRequest {
     pub language: Option<String>,
     pub filename: String,
     pub mime_type: String,
+    /// Request speaker labels (Deepgram, ElevenLabs; ignored by other providers)
+    pub diarize: bool,
+    /// Terms to bias recognition towards, sent in each provider's native form
+    pub vocabulary: Vec<String>,
+    /// Translate speech to English (OpenAI, Groq; check `supports_translation` first)
+    pub translate: bool,
+    /// Model override (None = the provider's default model)
+    pub model: Option<String>,
+    /// Endpoint for the custom provider (ignored by other providers)
+    pub custom_endpoint: Option<CustomEndpointConfig>,
     /// Optional progress callback for status updates
     pub progress: Option<ProgressCallback>,
 }
@@ -150,10 +172,39 @@ impl TranscriptionRequest {
             language,
             filename: "audio.mp3".to_string(),
             mime_type: "audio/mpeg".to_string(),
+            diarize: false,
+            vocabulary: Vec::new(),
+            translate: false,
+            model: None,
+            custom_endpoint: None,
             progress: None,
         }
     }
 
+    /// Set the model override (None = the provider's default model)
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Set the endpoint used by the custom provider
+    pub fn with_custom_endpoint(mut self, endpoint: Option<CustomEndpointConfig>) -> Self {
+        self.custom_endpoint = endpoint;
+        self
+    }
+
+    /// Set the vocabulary (product names, jargon) to bias recognition towards
+    pub fn with_vocabulary(mut self, vocabulary: Vec<String>) -> Self {
+        self.vocabulary = vocabulary;
+        self
+    }
+
+    /// Enable or disable speaker diarization
+    pub fn with_diarization(mut self, diarize: bool) -> Self {
+        self.diarize = diarize;
+        self
+    }
+
     /// Set the progress callback
     pub fn with_progress(mut self, callback: ProgressCallback) -> Self {
         self.progress = Some(callback);
@@ -168,13 +219,274 @@ impl TranscriptionRequest {
     }
 }
 
+/// Render a vocabulary list as a Whisper-style prompt ("term1, term2, ...")
+///
+/// Whisper models condition on the prompt text, so listing the terms makes them
+/// more likely to be spelled correctly. Returns `None` for an empty list.
+pub(crate) fn vocabulary_prompt(vocabulary: &[String]) -> Option<String> {
+    let terms: Vec<&str> = vocabulary
+        .iter()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(", "))
+}
+
+/// A timed span of transcript text (times in seconds from the start of the audio)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSegment {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    /// Zero-based speaker index (only set when diarization was requested)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<u32>,
+    /// Provider confidence from 0.0 to 1.0 (when the provider reports one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+impl TranscriptionSegment {
+    /// Display label for the speaker ("Speaker 1", "Speaker 2", ...)
+    pub fn speaker_label(&self) -> Option<String> {
+        self.speaker.map(|id| format!("Speaker {}", id + 1))
+    }
+}
+
+/// A single timed word (times in seconds from the start of the audio)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionWord {
+    pub start: f64,
+    pub end: f64,
+    pub word: String,
+    /// Zero-based speaker index (only set when diarization was requested)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<u32>,
+    /// Provider confidence from 0.0 to 1.0 (when the provider reports one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+/// Pause between words that starts a new segment when grouping words
+const SEGMENT_PAUSE_SECS: f64 = 0.8;
+
+/// Maximum length of a segment built from words (keeps subtitle cues readable)
+const SEGMENT_MAX_SECS: f64 = 6.0;
+
+/// Provider that transcribed one chunk of a progressive transcription
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkSource {
+    /// Chunk index within the recording
+    pub index: usize,
+    /// Chunk start within the recording (seconds)
+    pub start: f64,
+    /// Chunk end within the recording (seconds)
+    pub end: f64,
+    /// Provider that produced the chunk's text (the primary or a fallback)
+    pub provider: TranscriptionProvider,
+}
+
 /// Result of a transcription
+///
+/// `segments` and `words` are empty when the provider doesn't return timings.
+/// `chunks` is only filled by progressive transcription.
+///
+/// `detected_language` is an ISO 639-1 code ("en", "de") when the provider
+/// reports the spoken language (OpenAI, Groq, Deepgram, ElevenLabs). Confidence
+/// values range from 0.0 to 1.0 and are `None` when the provider doesn't report them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TranscriptionResult {
     pub text: String,
+    pub segments: Vec<TranscriptionSegment>,
+    pub words: Vec<TranscriptionWord>,
+    pub chunks: Vec<ChunkSource>,
+    /// Text was translated to English by the provider itself
+    pub translated: bool,
+    /// Spoken language reported by the provider (ISO 639-1)
+    pub detected_language: Option<String>,
+    /// Provider confidence in `detected_language`
+    pub language_confidence: Option<f64>,
+    /// Overall transcript confidence
+    pub confidence: Option<f64>,
+    /// Rate-limit (429) responses retried before this result came back
+    #[serde(skip)]
+    pub rate_limited: u32,
+}
+
+impl TranscriptionResult {
+    /// Create a result that carries text only (no timings)
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the provider returned any segment or word timings
+    pub fn has_timestamps(&self) -> bool {
+        !self.segments.is_empty() || !self.words.is_empty()
+    }
+
+    /// Whether the provider returned speaker labels
+    pub fn has_speakers(&self) -> bool {
+        self.segments.iter().any(|s| s.speaker.is_some())
+            || self.words.iter().any(|w| w.speaker.is_some())
+    }
+
+    /// Render the transcript as speaker turns, one paragraph per turn:
+    ///
+    /// ```text
+    /// Speaker 1: Thanks for joining.
+    ///
+    /// Speaker 2: Happy to be here.
+    /// ```
+    ///
+    /// Returns `None` when the provider returned no speaker labels.
+    pub fn speaker_text(&self) -> Option<String> {
+        if !self.has_speakers() {
+            return None;
+        }
+
+        let mut turns: Vec<(Option<String>, String)> = Vec::new();
+        for segment in self.timed_segments() {
+            let text = segment.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let label = segment.speaker_label();
+            match turns.last_mut() {
+                Some((speaker, turn)) if *speaker == label => {
+                    turn.push(' ');
+                    turn.push_str(text);
+                }
+                _ => turns.push((label, text.to_string())),
+            }
+        }
+
+        let paragraphs: Vec<String> = turns
+            .into_iter()
+            .map(|(label, text)| match label {
+                Some(label) => format!("{label}: {text}"),
+                None => text,
+            })
+            .collect();
+        Some(paragraphs.join("\n\n"))
+    }
+
+    /// Shift all timings by `offset_secs` (used when a chunk starts mid-recording)
+    pub fn shift(&mut self, offset_secs: f64) {
+        for segment in &mut self.segments {
+            segment.start += offset_secs;
+            segment.end += offset_secs;
+        }
+        for word in &mut self.words {
+            word.start += offset_secs;
+            word.end += offset_secs;
+        }
+    }
+
+    /// Segments suitable for subtitles
+    ///
+    /// Returns provider segments when available, otherwise groups words into
+    /// segments at sentence ends, long pauses, speaker changes, or after
+    /// `SEGMENT_MAX_SECS`.
+    pub fn timed_segments(&self) -> Vec<TranscriptionSegment> {
+        if !self.segments.is_empty() {
+            return self.segments.clone();
+        }
+
+        let mut segments = Vec::new();
+        let mut current: Option<TranscriptionSegment> = None;
+        // Words of the current segment, for its confidence
+        let mut current_words: Vec<&TranscriptionWord> = Vec::new();
+
+        for word in &self.words {
+            let text = word.word.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            if let Some(seg) = current.as_mut() {
+                if word.start - seg.end > SEGMENT_PAUSE_SECS
+                    || word.end - seg.start > SEGMENT_MAX_SECS
+                    || word.speaker != seg.speaker
+                {
+                    segments.extend(current.take().map(|s| finish(s, &mut current_words)));
+                } else {
+                    seg.text.push(' ');
+                    seg.text.push_str(text);
+                    seg.end = word.end;
+                }
+            }
+
+            let seg = current.get_or_insert_with(|| TranscriptionSegment {
+                start: word.start,
+                end: word.end,
+                text: String::new(),
+                speaker: word.speaker,
+                confidence: None,
+            });
+            if seg.text.is_empty() {
+                seg.text.push_str(text);
+            }
+            current_words.push(word);
+
+            if text.ends_with(['.', '!', '?']) {
+                segments.extend(current.take().map(|s| finish(s, &mut current_words)));
+            }
+        }
+
+        segments.extend(current.map(|s| finish(s, &mut current_words)));
+        segments
+    }
 }
 
+/// Close a segment built from words, averaging their confidences
+fn finish(
+    segment: TranscriptionSegment,
+    words: &mut Vec<&TranscriptionWord>,
+) -> TranscriptionSegment {
+    let confidence = weighted_confidence(words.drain(..).map(|w| (w.end - w.start, w.confidence)));
+    TranscriptionSegment {
+        confidence,
+        ..segment
+    }
+}
+
+/// Duration-weighted mean of the confidences that are present
+///
+/// Takes `(duration_secs, confidence)` pairs. Falls back to a plain mean when
+/// all durations are zero. Returns `None` if no confidence is present.
+pub(crate) fn weighted_confidence(
+    spans: impl IntoIterator<Item = (f64, Option<f64>)>,
+) -> Option<f64> {
+    let (mut weighted, mut total_weight, mut sum, mut count) = (0.0, 0.0, 0.0, 0usize);
+    for (duration, confidence) in spans {
+        let Some(confidence) = confidence else {
+            continue;
+        };
+        let weight = duration.max(0.0);
+        weighted += confidence * weight;
+        total_weight += weight;
+        sum += confidence;
+        count += 1;
+    }
+
+    match (count, total_weight > 0.0) {
+        (0, _) => None,
+        (_, true) => Some(weighted / total_weight),
+        (n, false) => Some(sum / n as f64),
+    }
+}
+
+pub(crate) use language::normalize_language;
+pub(crate) use models::model_for;
+
 // Import shared helpers from base module
-pub(crate) use base::{openai_compatible_transcribe_async, openai_compatible_transcribe_sync};
+pub(crate) use base::{
+    ApiAuth, openai_compatible_transcribe_async, openai_compatible_transcribe_async_with_auth,
+    openai_compatible_transcribe_sync, openai_compatible_transcribe_sync_with_auth,
+};
 
 /// Trait for transcription providers
 ///
@@ -215,13 +527,20 @@ impl ProviderRegistry {
 
         providers.insert("openai", Arc::new(OpenAIProvider));
         #[cfg(feature = "realtime")]
-        providers.insert("openai-realtime", Arc::new(OpenAIRealtimeProvider));
+        providers.insert(
+            "openai-realtime",
+            Arc::new(OpenAIRealtimeProvider::default()),
+        );
         providers.insert("mistral", Arc::new(MistralProvider));
         providers.insert("groq", Arc::new(GroqProvider));
         providers.insert("deepgram", Arc::new(DeepgramProvider));
         #[cfg(feature = "realtime")]
-        providers.insert("deepgram-realtime", Arc::new(DeepgramRealtimeProvider));
+        providers.insert(
+            "deepgram-realtime",
+            Arc::new(DeepgramRealtimeProvider::default()),
+        );
         providers.insert("elevenlabs", Arc::new(ElevenLabsProvider));
+        providers.insert("custom", Arc::new(CustomProvider));
         #[cfg(feature = "local-transcription")]
         providers.insert("local-whisper", Arc::new(LocalWhisperProvider));
         #[cfg(feature = "local-transcription")]
@@ -270,39 +589,240 @@ pub fn registry() -> &'static ProviderRegistry {
     REGISTRY.get_or_init(ProviderRegistry::new)
 }
 
-/// Check if a provider supports realtime WebSocket streaming
+/// Check if a provider supports realtime streaming
 ///
 /// Returns true for providers that implement RealtimeTranscriptionBackend
-/// and should bypass the chunking pipeline for lower latency.
-pub fn is_realtime_provider(provider: &TranscriptionProvider) -> bool {
-    matches!(
-        provider,
-        TranscriptionProvider::OpenAIRealtime | TranscriptionProvider::DeepgramRealtime
-    )
+/// and should bypass the chunking pipeline for lower latency. Local providers
+/// qualify only with `local_streaming` (the `local_models.streaming` setting)
+/// in builds with both `realtime` and `local-transcription`; recorded audio
+/// then goes through `LocalStreamingProvider` instead of the chunking pipeline.
+pub fn is_realtime_provider(provider: &TranscriptionProvider, local_streaming: bool) -> bool {
+    match provider {
+        TranscriptionProvider::OpenAIRealtime | TranscriptionProvider::DeepgramRealtime => true,
+        TranscriptionProvider::LocalWhisper | TranscriptionProvider::LocalParakeet => {
+            cfg!(all(feature = "realtime", feature = "local-transcription")) && local_streaming
+        }
+        _ => false,
+    }
 }
 
 /// Get the realtime backend for a provider
 ///
 /// Returns a trait object implementing RealtimeTranscriptionBackend for
-/// providers that support WebSocket streaming.
+/// providers that support WebSocket streaming, and for local providers when
+/// `local_streaming` is set. `model` overrides the cloud provider's default
+/// model. `vocabulary` and `translate` apply to local Whisper streaming; cloud
+/// realtime APIs ignore them.
 ///
 /// # Errors
 /// Returns an error if the provider does not support realtime streaming.
 #[cfg(feature = "realtime")]
 pub fn get_realtime_backend(
     provider: &TranscriptionProvider,
+    model: Option<String>,
+    vocabulary: &[String],
+    translate: bool,
+    local_streaming: bool,
 ) -> anyhow::Result<std::sync::Arc<dyn RealtimeTranscriptionBackend>> {
+    #[cfg(not(feature = "local-transcription"))]
+    let _ = (vocabulary, translate, local_streaming);
+
     match provider {
-        TranscriptionProvider::OpenAIRealtime => Ok(std::sync::Arc::new(OpenAIRealtimeProvider)
-            as std::sync::Arc<dyn RealtimeTranscriptionBackend>),
+        TranscriptionProvider::OpenAIRealtime => {
+            Ok(std::sync::Arc::new(OpenAIRealtimeProvider::new(model))
+                as std::sync::Arc<dyn RealtimeTranscriptionBackend>)
+        }
         TranscriptionProvider::DeepgramRealtime => {
-            Ok(std::sync::Arc::new(DeepgramRealtimeProvider)
+            Ok(std::sync::Arc::new(DeepgramRealtimeProvider::new(model))
                 as std::sync::Arc<dyn RealtimeTranscriptionBackend>)
         }
+        #[cfg(feature = "local-transcription")]
+        TranscriptionProvider::LocalWhisper if local_streaming => {
+            let backend = LocalStreamingProvider::new(LocalStreamingEngine::Whisper)
+                .with_vocabulary(vocabulary.to_vec())
+                .with_translate(translate);
+            Ok(std::sync::Arc::new(backend) as std::sync::Arc<dyn RealtimeTranscriptionBackend>)
+        }
+        #[cfg(feature = "local-transcription")]
+        TranscriptionProvider::LocalParakeet if local_streaming => {
+            let backend = LocalStreamingProvider::new(LocalStreamingEngine::Parakeet);
+            Ok(std::sync::Arc::new(backend) as std::sync::Arc<dyn RealtimeTranscriptionBackend>)
+        }
         _ => Err(anyhow::anyhow!(
             "Provider '{}' does not support realtime streaming. \
-             Available realtime providers: openai-realtime, deepgram-realtime",
+             Available realtime providers: openai-realtime, deepgram-realtime \
+             (and local-whisper/local-parakeet with local streaming enabled)",
             provider.as_str()
         )),
     }
 }
+
+/// Transcribe samples with a local engine, keeping it loaded afterwards.
+///
+/// Progressive transcription decodes many chunks per recording; pair with
+/// [`release_local_engine`] once all chunks are done so the keep-loaded
+/// setting is applied once per recording for both engines. `language`,
+/// `vocabulary` and `translate` only apply to Whisper.
+#[cfg(feature = "local-transcription")]
+pub(crate) fn transcribe_local_window(
+    provider: &TranscriptionProvider,
+    model_path: &str,
+    samples: Vec<f32>,
+    language: Option<&str>,
+    vocabulary: &[String],
+    translate: bool,
+) -> Result<TranscriptionResult> {
+    match provider {
+        TranscriptionProvider::LocalWhisper => {
+            local_whisper::transcribe_window(model_path, &samples, language, vocabulary, translate)
+        }
+        TranscriptionProvider::LocalParakeet => {
+            local_parakeet::transcribe_window(model_path, samples)
+        }
+        _ => anyhow::bail!("Provider '{}' is not a local provider", provider),
+    }
+}
+
+/// Unload a local engine unless keep-loaded is set (no-op for cloud providers)
+#[cfg(feature = "local-transcription")]
+pub(crate) fn release_local_engine(provider: &TranscriptionProvider) {
+    match provider {
+        TranscriptionProvider::LocalWhisper => local_whisper::maybe_unload(),
+        TranscriptionProvider::LocalParakeet => local_parakeet::maybe_unload(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(word: &str, start: f64, end: f64, speaker: Option<u32>) -> TranscriptionWord {
+        TranscriptionWord {
+            start,
+            end,
+            word: word.to_string(),
+            speaker,
+            confidence: None,
+        }
+    }
+
+    fn from_words(words: Vec<TranscriptionWord>) -> TranscriptionResult {
+        TranscriptionResult {
+            words,
+            ..Default::default()
+        }
+    }
+
+    fn texts(segments: &[TranscriptionSegment]) -> Vec<&str> {
+        segments.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn vocabulary_prompt_lists_trimmed_terms() {
+        let vocabulary = vec![
+            " Kubernetes ".to_string(),
+            String::new(),
+            "whis".to_string(),
+            "  ".to_string(),
+            "PostgreSQL".to_string(),
+        ];
+
+        assert_eq!(
+            vocabulary_prompt(&vocabulary).as_deref(),
+            Some("Kubernetes, whis, PostgreSQL")
+        );
+    }
+
+    #[test]
+    fn vocabulary_prompt_is_none_without_terms() {
+        assert_eq!(vocabulary_prompt(&[]), None);
+        assert_eq!(vocabulary_prompt(&[" ".to_string()]), None);
+    }
+
+    #[test]
+    fn timed_segments_split_at_long_pauses() {
+        let result = from_words(vec![
+            word("hello", 0.0, 0.4, None),
+            word("world", 0.5, 0.9, None),
+            word("again", 2.0, 2.4, None),
+        ]);
+
+        let segments = result.timed_segments();
+        assert_eq!(texts(&segments), vec!["hello world", "again"]);
+        assert_eq!((segments[0].start, segments[0].end), (0.0, 0.9));
+        assert_eq!((segments[1].start, segments[1].end), (2.0, 2.4));
+    }
+
+    #[test]
+    fn timed_segments_split_after_max_length() {
+        // One word per second without pauses long enough to split
+        let words = (0..8)
+            .map(|i| word(&format!("w{i}"), i as f64, i as f64 + 0.9, None))
+            .collect();
+
+        let segments = from_words(words).timed_segments();
+        assert_eq!(texts(&segments), vec!["w0 w1 w2 w3 w4 w5", "w6 w7"]);
+        assert!(segments.iter().all(|s| s.end - s.start <= SEGMENT_MAX_SECS));
+    }
+
+    #[test]
+    fn timed_segments_split_at_sentence_ends_and_speaker_changes() {
+        let result = from_words(vec![
+            word("Thanks", 0.0, 0.3, Some(0)),
+            word("for", 0.35, 0.5, Some(0)),
+            word("joining.", 0.55, 0.9, Some(0)),
+            word("Happy", 1.0, 1.3, Some(1)),
+            word("to", 1.35, 1.5, Some(1)),
+            word("be", 1.55, 1.7, Some(1)),
+            word("here", 1.75, 2.0, Some(0)),
+        ]);
+
+        let segments = result.timed_segments();
+        assert_eq!(
+            texts(&segments),
+            vec!["Thanks for joining.", "Happy to be", "here"]
+        );
+        let speakers: Vec<_> = segments.iter().map(|s| s.speaker_label()).collect();
+        assert_eq!(
+            speakers,
+            vec![
+                Some("Speaker 1".to_string()),
+                Some("Speaker 2".to_string()),
+                Some("Speaker 1".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn timed_segments_prefer_provider_segments() {
+        let segment = TranscriptionSegment {
+            start: 0.0,
+            end: 3.0,
+            text: "hello world again".to_string(),
+            speaker: None,
+            confidence: None,
+        };
+        let result = TranscriptionResult {
+            segments: vec![segment.clone()],
+            ..from_words(vec![word("hello", 0.0, 0.4, None)])
+        };
+
+        assert_eq!(result.timed_segments(), vec![segment]);
+    }
+
+    #[test]
+    fn speaker_text_joins_segments_into_turns() {
+        let result = from_words(vec![
+            word("Thanks.", 0.0, 0.5, Some(0)),
+            word("Sure.", 0.6, 1.0, Some(0)),
+            word("Hi", 1.1, 1.4, Some(1)),
+        ]);
+
+        assert_eq!(
+            result.speaker_text().as_deref(),
+            Some("Speaker 1: Thanks. Sure.\n\nSpeaker 2: Hi")
+        );
+        assert_eq!(from_words(Vec::new()).speaker_text(), None);
+    }
+}
@@ -0,0 +1,18 @@
+//! Per-provider model selection
+//!
+//! Cloud providers send the model from `TranscriptionProvider::default_model`
+//! unless the request carries an override (`TranscriptionRequest::model`, filled
+//! from `TranscriptionSettings::models` by the caller). Names are sent as-is, so
+//! models newer than the known lists work without code changes.
+
+use crate::config::TranscriptionProvider;
+
+/// Model to send for a provider: the override (if any) or the default
+pub(crate) fn model_for(provider: &TranscriptionProvider, model: Option<&str>) -> String {
+    model
+        .map(str::trim)
+        .filter(|model| !model.is_empty())
+        .or_else(|| provider.default_model())
+        .map(str::to_string)
+        .unwrap_or_default()
+}
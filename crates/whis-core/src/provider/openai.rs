@@ -4,17 +4,38 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use super::{
-    TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult, model_for,
     openai_compatible_transcribe_async, openai_compatible_transcribe_sync,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.openai.com/v1/audio/transcriptions";
-const MODEL: &str = "whisper-1";
+const TRANSLATION_API_URL: &str = "https://api.openai.com/v1/audio/translations";
+/// Only whisper-1 is available on the translations endpoint
+const TRANSLATION_MODEL: &str = "whisper-1";
+
+/// Whisper models support `verbose_json` with segment and word timestamps;
+/// the gpt-4o transcribe models only return plain `json`
+fn supports_timestamps(model: &str) -> bool {
+    model.starts_with("whisper")
+}
 
 /// OpenAI Whisper transcription provider
 #[derive(Debug, Default, Clone)]
 pub struct OpenAIProvider;
 
+/// API URL and model for a request (translations use a separate endpoint)
+fn endpoint(request: &TranscriptionRequest) -> (&'static str, String) {
+    if request.translate {
+        (TRANSLATION_API_URL, TRANSLATION_MODEL.to_string())
+    } else {
+        (
+            API_URL,
+            model_for(&TranscriptionProvider::OpenAI, request.model.as_deref()),
+        )
+    }
+}
+
 #[async_trait]
 impl TranscriptionBackend for OpenAIProvider {
     fn name(&self) -> &'static str {
@@ -30,7 +51,9 @@ impl TranscriptionBackend for OpenAIProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_sync(API_URL, MODEL, api_key, request)
+        let (url, model) = endpoint(&request);
+        let timestamps = supports_timestamps(&model);
+        openai_compatible_transcribe_sync(url, &model, api_key, timestamps, request)
     }
 
     async fn transcribe_async(
@@ -39,6 +62,8 @@ impl TranscriptionBackend for OpenAIProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_async(client, API_URL, MODEL, api_key, request).await
+        let (url, model) = endpoint(&request);
+        let timestamps = supports_timestamps(&model);
+        openai_compatible_transcribe_async(client, url, &model, api_key, timestamps, request).await
     }
 }
@@ -2,7 +2,8 @@
 //!
 //! Streams audio via WebSocket for real-time transcription.
 //!
-//! Model: `gpt-4o-transcribe` (speech-to-text only, no AI responses).
+//! Model: `gpt-4o-transcribe` by default (speech-to-text only, no AI responses),
+//! configurable per provider (e.g., `gpt-4o-mini-transcribe`).
 //! No `gpt-5-transcribe` yet as of 2026-01-11.
 
 use anyhow::{Context, Result, anyhow};
@@ -21,20 +22,32 @@ use tokio_tungstenite::{
     },
 };
 
+use super::realtime::{TranscriptEvent, TranscriptEventSender, send_event};
 use super::{
     OpenAIProvider, RealtimeTranscriptionBackend, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult,
+    TranscriptionResult, model_for,
 };
+use crate::config::TranscriptionProvider;
 
 const WS_URL: &str = "wss://api.openai.com/v1/realtime?intent=transcription";
 const REALTIME_SAMPLE_RATE: u32 = 24000;
+const DELTA_EVENT: &str = "conversation.item.input_audio_transcription.delta";
+const COMPLETED_EVENT: &str = "conversation.item.input_audio_transcription.completed";
+const FAILED_EVENT: &str = "conversation.item.input_audio_transcription.failed";
+const COMMITTED_EVENT: &str = "input_audio_buffer.committed";
+const SESSION_UPDATED_EVENT: &str = "session.updated";
+/// Error code for committing an empty buffer (server VAD already committed it)
+const COMMIT_EMPTY_CODE: &str = "input_audio_buffer_commit_empty";
 
 /// OpenAI Realtime transcription provider
 ///
 /// Streams audio via WebSocket for lower-latency transcription.
 /// Uses the same API key as regular OpenAI.
 #[derive(Debug, Default, Clone)]
-pub struct OpenAIRealtimeProvider;
+pub struct OpenAIRealtimeProvider {
+    /// Model override (None = the provider's default model)
+    model: Option<String>,
+}
 
 // WebSocket protocol messages (GA API format)
 
@@ -76,7 +89,7 @@ struct AudioFormat {
 
 #[derive(Serialize)]
 struct TranscriptionConfig {
-    model: &'static str,
+    model: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     language: Option<String>,
 }
@@ -106,6 +119,12 @@ struct RealtimeEvent {
     event_type: String,
     #[serde(default)]
     transcript: Option<String>,
+    /// Incremental text from `...input_audio_transcription.delta` events
+    #[serde(default)]
+    delta: Option<String>,
+    /// Conversation item (one per server VAD turn) the event belongs to
+    #[serde(default)]
+    item_id: Option<String>,
     #[serde(default)]
     error: Option<RealtimeError>,
 }
@@ -114,7 +133,6 @@ struct RealtimeEvent {
 struct RealtimeError {
     message: String,
     #[serde(default)]
-    #[allow(dead_code)]
     code: Option<String>,
 }
 
@@ -123,14 +141,26 @@ const MAX_RETRIES: u32 = 3;
 const BASE_BACKOFF_SECS: u64 = 1;
 
 impl OpenAIRealtimeProvider {
+    /// Provider sending `model` instead of the default model (when set)
+    pub fn new(model: Option<String>) -> Self {
+        Self { model }
+    }
+
     /// Transcribe audio from a channel of f32 samples (16kHz mono)
     ///
     /// Connects to OpenAI Realtime API via WebSocket and streams audio chunks
     /// as they arrive. Returns the final transcript when the channel closes.
+    /// Transcription deltas are forwarded to `events` when provided.
+    ///
+    /// Server VAD commits an item whenever the speaker pauses, so deltas arrive
+    /// while still speaking. The final commit flushes the tail, and the transcript
+    /// is every item's text in commit order.
     async fn transcribe_stream_impl(
         api_key: &str,
+        model: String,
         mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        events: Option<TranscriptEventSender>,
     ) -> Result<String> {
         // 1. Connect to WebSocket with retry logic
         let ws_stream = {
@@ -207,23 +237,27 @@ impl OpenAIRealtimeProvider {
                             rate: REALTIME_SAMPLE_RATE,
                         },
                         transcription: TranscriptionConfig {
-                            model: "gpt-4o-transcribe",
+                            model,
                             language: language.clone(),
                         },
-                        turn_detection: None, // Server VAD disabled for manual control
+                        // Server VAD splits the recording into items at pauses
+                        turn_detection: Some(TurnDetection {
+                            detection_type: "server_vad",
+                        }),
                     },
                 },
             },
         };
+        let session_update = serde_json::to_string(&session_update)?;
 
         write
-            .send(Message::Text(
-                serde_json::to_string(&session_update)?.into(),
-            ))
+            .send(Message::Text(session_update.clone().into()))
             .await
             .context("Failed to send session configuration")?;
 
-        // Wait for session.created or session.updated confirmation with timeout
+        // Wait for session.updated (configuration applied) with timeout.
+        // Waiting for this exact event keeps it out of the read task, which uses
+        // the next session.updated as a marker after the final commit.
         let setup_result = timeout(Duration::from_secs(30), async {
             loop {
                 match read.next().await {
@@ -237,9 +271,7 @@ impl OpenAIRealtimeProvider {
                             return Err(anyhow!("OpenAI Realtime error: {}", err.message));
                         }
 
-                        if event.event_type == "session.created"
-                            || event.event_type == "session.updated"
-                        {
+                        if event.event_type == SESSION_UPDATED_EVENT {
                             return Ok(());
                         }
                     }
@@ -271,7 +303,7 @@ impl OpenAIRealtimeProvider {
         let (done_tx, done_rx) = oneshot::channel::<usize>(); // Now sends total_samples
 
         let read_handle =
-            tokio::spawn(async move { collect_transcripts(read, error_tx, done_rx).await });
+            tokio::spawn(async move { collect_transcripts(read, error_tx, done_rx, events).await });
 
         // 5. (No keepalive needed for OpenAI)
 
@@ -337,7 +369,9 @@ impl OpenAIRealtimeProvider {
             );
         }
 
-        // 7. Commit audio buffer to trigger transcription (GA API - no response.create needed)
+        // 7. Commit the rest of the buffer to transcribe the last item (GA API - no
+        // response.create needed). An empty-buffer error is expected if server VAD
+        // already committed everything.
         let commit = AudioBufferCommit {
             msg_type: "input_audio_buffer.commit",
         };
@@ -347,6 +381,13 @@ impl OpenAIRealtimeProvider {
             .await
             .context("Failed to commit audio buffer")?;
 
+        // Server events are in order, so the reply to this (unchanged) session
+        // update tells the read task that every commit has been answered
+        write
+            .send(Message::Text(session_update.into()))
+            .await
+            .context("Failed to send end-of-stream marker")?;
+
         // 8. Signal done_tx to notify read task with total_samples for dynamic timeout
         let _ = done_tx.send(total_samples);
 
@@ -371,19 +412,80 @@ impl OpenAIRealtimeProvider {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        model: Option<&str>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        let model = model_for(&TranscriptionProvider::OpenAIRealtime, model);
+        Self::transcribe_stream_impl(api_key, model, audio_rx, language, None).await
+    }
+}
+
+/// Transcripts of the items committed so far, in commit order
+#[derive(Default)]
+struct Items {
+    /// Item IDs in commit order
+    order: Vec<String>,
+    /// Finished transcripts by item ID
+    texts: std::collections::HashMap<String, String>,
+    /// Item receiving deltas and its text so far (sent as partials)
+    partial: Option<(String, String)>,
+}
+
+impl Items {
+    /// Handle a transcript event, forwarding it to `events`
+    fn handle(&mut self, event: RealtimeEvent, events: Option<&TranscriptEventSender>) {
+        let item_id = event.item_id.unwrap_or_default();
+        match event.event_type.as_str() {
+            COMMITTED_EVENT => self.order.push(item_id),
+            DELTA_EVENT => {
+                if let Some(delta) = event.delta {
+                    let partial = match &mut self.partial {
+                        Some((id, text)) if *id == item_id => text,
+                        partial => &mut partial.insert((item_id, String::new())).1,
+                    };
+                    partial.push_str(&delta);
+                    send_event(events, TranscriptEvent::Partial(partial.clone()));
+                }
+            }
+            COMPLETED_EVENT => {
+                let transcript = event.transcript.unwrap_or_default();
+                if self.partial.as_ref().is_some_and(|(id, _)| *id == item_id) {
+                    self.partial = None;
+                }
+                send_event(events, TranscriptEvent::Final(transcript.clone()));
+                self.texts.insert(item_id, transcript);
+            }
+            FAILED_EVENT => {
+                crate::warn!("OpenAI Realtime could not transcribe part of the recording");
+                self.texts.insert(item_id, String::new());
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether every committed item has a transcript
+    fn is_complete(&self) -> bool {
+        self.order.iter().all(|id| self.texts.contains_key(id))
+    }
+
+    /// Item transcripts joined in commit order
+    fn transcript(&self) -> String {
+        self.order
+            .iter()
+            .filter_map(|id| self.texts.get(id))
+            .map(|text| text.trim())
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 }
 
 /// Collect transcripts from WebSocket messages.
 ///
 /// Two-phase approach (matching Deepgram implementation pattern):
-/// - Phase 1: During streaming, monitor for errors AND collect any early transcripts
-/// - Phase 2: After done signal, wait for final transcript (if not already received)
-///
-/// IMPORTANT: OpenAI may send the transcript event during Phase 1 if the recording
-/// is short. We must capture it to avoid losing transcripts.
+/// - Phase 1: During streaming, monitor for errors AND collect the items server
+///   VAD commits while the user is speaking
+/// - Phase 2: After done signal, wait for the reply to the final commit (marked
+///   by `session.updated`) and for every committed item's transcript
 ///
 /// The done_rx channel receives total_samples to calculate a dynamic timeout for Phase 2.
 /// Longer recordings need more processing time after Finalize.
@@ -391,12 +493,12 @@ async fn collect_transcripts<S>(
     mut read: S,
     error_tx: oneshot::Sender<anyhow::Error>,
     mut done_rx: oneshot::Receiver<usize>,
+    events: Option<TranscriptEventSender>,
 ) -> Result<String>
 where
     S: futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
 {
-    // Store transcript if received early (during Phase 1)
-    let mut early_transcript: Option<String> = None;
+    let mut items = Items::default();
 
     // Phase 1: Monitor for errors AND collect transcripts during audio streaming
     // total_samples will be received when streaming completes
@@ -409,13 +511,6 @@ where
                 if crate::verbose::is_verbose() {
                     eprintln!("[openai-realtime] Finalize sent, switching to post-finalize phase");
                 }
-                // If we already got a transcript during streaming, return it now
-                if let Some(transcript) = early_transcript {
-                    if crate::verbose::is_verbose() {
-                        eprintln!("[openai-realtime] Returning early transcript from Phase 1");
-                    }
-                    return Ok(transcript);
-                }
                 break;
             }
 
@@ -440,15 +535,7 @@ where
                             return Err(err);
                         }
 
-                        // Capture transcript if it arrives early (short recordings)
-                        if event.event_type == "conversation.item.input_audio_transcription.completed"
-                            && let Some(transcript) = event.transcript
-                        {
-                            if crate::verbose::is_verbose() {
-                                eprintln!("[openai-realtime] Received transcript during Phase 1 (early)");
-                            }
-                            early_transcript = Some(transcript);
-                        }
+                        items.handle(event, events.as_ref());
                     }
                     Some(Ok(Message::Close(frame))) => {
                         let err = anyhow!("WebSocket closed during streaming: {:?}", frame);
@@ -490,26 +577,32 @@ where
 
     let timeout_duration = Duration::from_secs(phase2_timeout_secs);
     let deadline = tokio::time::Instant::now() + timeout_duration;
+    // Set once the final commit has been answered (no more items will follow)
+    let mut commit_done = false;
 
     loop {
+        if commit_done && items.is_complete() {
+            return Ok(items.transcript());
+        }
+
         let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
         if remaining.is_zero() {
-            // Graceful degradation: return empty string with warning instead of error
+            // Graceful degradation: return what was transcribed with a warning
             eprintln!(
-                "[openai-realtime] WARNING: Phase 2 timeout after {}s - no transcript received",
+                "[openai-realtime] WARNING: Phase 2 timeout after {}s - transcript may be incomplete",
                 phase2_timeout_secs
             );
-            return Ok(String::new());
+            return Ok(items.transcript());
         }
 
         tokio::select! {
             _ = tokio::time::sleep(remaining) => {
-                // Graceful degradation: return empty string with warning instead of error
+                // Graceful degradation: return what was transcribed with a warning
                 eprintln!(
-                    "[openai-realtime] WARNING: Phase 2 timeout after {}s - no transcript received",
+                    "[openai-realtime] WARNING: Phase 2 timeout after {}s - transcript may be incomplete",
                     phase2_timeout_secs
                 );
-                return Ok(String::new());
+                return Ok(items.transcript());
             }
 
             msg = read.next() => {
@@ -525,30 +618,30 @@ where
                         match event.event_type.as_str() {
                             "error" => {
                                 if let Some(err) = event.error {
+                                    // Nothing was left to commit after server VAD's last item
+                                    if err.code.as_deref() == Some(COMMIT_EMPTY_CODE) {
+                                        continue;
+                                    }
                                     return Err(anyhow!("OpenAI Realtime error: {}", err.message));
                                 }
                             }
-                            "conversation.item.input_audio_transcription.completed" => {
-                                if let Some(transcript) = event.transcript {
-                                    return Ok(transcript);
-                                }
-                            }
-                            // Ignore other events (response.created, response.done, etc.)
-                            _ => {}
+                            SESSION_UPDATED_EVENT => commit_done = true,
+                            // Items and transcripts (other events are ignored)
+                            _ => items.handle(event, events.as_ref()),
                         }
                     }
                     Some(Ok(Message::Close(_))) => {
-                        // Graceful degradation: connection closed, return empty string
+                        // Graceful degradation: connection closed, return what we have
                         eprintln!("[openai-realtime] WARNING: WebSocket closed before receiving transcription");
-                        return Ok(String::new());
+                        return Ok(items.transcript());
                     }
                     Some(Err(e)) => {
                         return Err(anyhow!("WebSocket error: {e}"));
                     }
                     None => {
-                        // Graceful degradation: connection ended, return empty string
+                        // Graceful degradation: connection ended, return what we have
                         eprintln!("[openai-realtime] WARNING: Connection ended before receiving transcription");
-                        return Ok(String::new());
+                        return Ok(items.transcript());
                     }
                     _ => {} // Ignore Ping, Pong, Binary
                 }
@@ -585,13 +678,18 @@ fn resample_16k_to_24k(samples: &[f32]) -> Vec<f32> {
 
 #[async_trait]
 impl RealtimeTranscriptionBackend for OpenAIRealtimeProvider {
-    async fn transcribe_stream(
+    async fn transcribe_stream_with_events(
         &self,
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        events: Option<TranscriptEventSender>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        let model = model_for(
+            &TranscriptionProvider::OpenAIRealtime,
+            self.model.as_deref(),
+        );
+        Self::transcribe_stream_impl(api_key, model, audio_rx, language, events).await
     }
 
     fn sample_rate(&self) -> u32 {
@@ -639,3 +737,108 @@ impl TranscriptionBackend for OpenAIRealtimeProvider {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_type: &str, item_id: &str) -> RealtimeEvent {
+        RealtimeEvent {
+            event_type: event_type.to_string(),
+            transcript: None,
+            delta: None,
+            item_id: Some(item_id.to_string()),
+            error: None,
+        }
+    }
+
+    fn delta(item_id: &str, delta: &str) -> RealtimeEvent {
+        RealtimeEvent {
+            delta: Some(delta.to_string()),
+            ..event(DELTA_EVENT, item_id)
+        }
+    }
+
+    fn completed(item_id: &str, transcript: &str) -> RealtimeEvent {
+        RealtimeEvent {
+            transcript: Some(transcript.to_string()),
+            ..event(COMPLETED_EVENT, item_id)
+        }
+    }
+
+    fn received(rx: &mut mpsc::UnboundedReceiver<TranscriptEvent>) -> Vec<TranscriptEvent> {
+        std::iter::from_fn(|| rx.try_recv().ok()).collect()
+    }
+
+    #[test]
+    fn deltas_accumulate_into_partials_until_completed() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut items = Items::default();
+
+        items.handle(event(COMMITTED_EVENT, "a"), Some(&tx));
+        items.handle(delta("a", "Hello"), Some(&tx));
+        items.handle(delta("a", " world"), Some(&tx));
+        assert!(!items.is_complete());
+        items.handle(completed("a", "Hello world."), Some(&tx));
+
+        assert_eq!(
+            received(&mut rx),
+            vec![
+                TranscriptEvent::Partial("Hello".to_string()),
+                TranscriptEvent::Partial("Hello world".to_string()),
+                TranscriptEvent::Final("Hello world.".to_string()),
+            ]
+        );
+        assert!(items.partial.is_none());
+        assert!(items.is_complete());
+        assert_eq!(items.transcript(), "Hello world.");
+    }
+
+    #[test]
+    fn transcript_follows_commit_order_not_completion_order() {
+        let mut items = Items::default();
+
+        items.handle(event(COMMITTED_EVENT, "a"), None);
+        items.handle(event(COMMITTED_EVENT, "b"), None);
+        items.handle(completed("b", "second"), None);
+        assert!(!items.is_complete());
+        items.handle(completed("a", "first"), None);
+
+        assert!(items.is_complete());
+        assert_eq!(items.transcript(), "first second");
+    }
+
+    #[test]
+    fn deltas_of_a_new_item_start_a_new_partial() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut items = Items::default();
+
+        items.handle(delta("a", "one"), Some(&tx));
+        items.handle(delta("b", "two"), Some(&tx));
+        // A late completion of the earlier item keeps the newer partial
+        items.handle(completed("a", "one"), Some(&tx));
+
+        assert_eq!(
+            received(&mut rx),
+            vec![
+                TranscriptEvent::Partial("one".to_string()),
+                TranscriptEvent::Partial("two".to_string()),
+                TranscriptEvent::Final("one".to_string()),
+            ]
+        );
+        assert_eq!(items.partial, Some(("b".to_string(), "two".to_string())));
+    }
+
+    #[test]
+    fn failed_items_count_as_done_without_text() {
+        let mut items = Items::default();
+
+        items.handle(event(COMMITTED_EVENT, "a"), None);
+        items.handle(event(COMMITTED_EVENT, "b"), None);
+        items.handle(event(FAILED_EVENT, "a"), None);
+        items.handle(completed("b", "still here"), None);
+
+        assert!(items.is_complete());
+        assert_eq!(items.transcript(), "still here");
+    }
+}
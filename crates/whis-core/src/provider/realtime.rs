@@ -42,11 +42,57 @@
 //! | Finalize | `commit` + `response.create` | `{"type":"Finalize"}` |
 //! | KeepAlive | Not needed | Required (every 5s) |
 //! | Final Event | `conversation.item.input_audio_transcription.completed` | `Results` with `from_finalize=true` |
+//! | Partial Event | `conversation.item.input_audio_transcription.delta` | `Results` with `is_final=false` |
+//!
+//! # Live Transcript Events
+//!
+//! Callers that want to show text while the user is still speaking pass a
+//! [`TranscriptEventSender`] to `transcribe_stream_with_events`. Providers send
+//! [`TranscriptEvent::Partial`] for the in-progress segment (each one replaces the
+//! previous partial) and [`TranscriptEvent::Final`] once a segment is committed.
 
 use anyhow::Result;
 use async_trait::async_trait;
+use serde::Serialize;
 use tokio::sync::mpsc;
 
+/// Incremental transcript update emitted while audio is still streaming.
+///
+/// Serializes as `{"kind": "partial" | "final", "text": "..."}` for frontends.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "text", rename_all = "lowercase")]
+pub enum TranscriptEvent {
+    /// Unstable text for the segment currently being spoken.
+    /// Replaces any previous partial.
+    Partial(String),
+    /// Committed text for a finished segment. Clears the current partial.
+    Final(String),
+}
+
+impl TranscriptEvent {
+    /// Text carried by this event
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Partial(text) | Self::Final(text) => text,
+        }
+    }
+
+    /// Whether this event commits its segment
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Final(_))
+    }
+}
+
+/// Channel used by realtime providers to publish live transcript events.
+pub type TranscriptEventSender = mpsc::UnboundedSender<TranscriptEvent>;
+
+/// Send an event if a listener is attached. A dropped receiver is not an error.
+pub(crate) fn send_event(events: Option<&TranscriptEventSender>, event: TranscriptEvent) {
+    if let Some(tx) = events {
+        let _ = tx.send(event);
+    }
+}
+
 /// Trait for realtime (WebSocket-based) transcription providers.
 ///
 /// Realtime providers stream audio during recording rather than buffering
@@ -84,6 +130,22 @@ pub trait RealtimeTranscriptionBackend: Send + Sync {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+    ) -> Result<String> {
+        self.transcribe_stream_with_events(api_key, audio_rx, language, None)
+            .await
+    }
+
+    /// Like [`transcribe_stream`](Self::transcribe_stream), but also publishes
+    /// partial and final segments to `events` as they arrive.
+    ///
+    /// The returned string is the same final transcript; events are purely for
+    /// live display and may be dropped by the receiver at any time.
+    async fn transcribe_stream_with_events(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        events: Option<TranscriptEventSender>,
     ) -> Result<String>;
 
     /// Required sample rate for this provider's WebSocket API.
@@ -98,4 +160,11 @@ pub trait RealtimeTranscriptionBackend: Send + Sync {
     fn requires_keepalive(&self) -> bool {
         false
     }
+
+    /// Whether the returned transcript is already translated to English.
+    ///
+    /// Callers translate with the post-processor when this is false.
+    fn translates(&self) -> bool {
+        false
+    }
 }
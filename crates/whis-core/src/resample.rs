@@ -33,8 +33,15 @@ impl FrameResampler {
     /// * `source_rate` - Source sample rate in Hz (e.g., 44100, 48000)
     /// * `channels` - Number of input channels (1 for mono, 2 for stereo)
     pub fn new(source_rate: u32, channels: u16) -> Result<Self> {
-        // If already 16kHz mono, no resampling needed
-        if source_rate == WHISPER_SAMPLE_RATE && channels == 1 {
+        Self::with_rates(source_rate, WHISPER_SAMPLE_RATE, channels)
+    }
+
+    /// Create a frame resampler with a target rate other than 16kHz.
+    ///
+    /// Output is always mono. Used by the denoiser, which runs at 48kHz.
+    pub fn with_rates(source_rate: u32, target_rate: u32, channels: u16) -> Result<Self> {
+        // If already at the target rate and mono, no resampling needed
+        if source_rate == target_rate && channels == 1 {
             return Ok(Self {
                 resampler: None,
                 channels,
@@ -43,10 +50,10 @@ impl FrameResampler {
             });
         }
 
-        // Create resampler: source_rate -> 16kHz
+        // Create resampler: source_rate -> target_rate
         let resampler = Fft::<f32>::new(
             source_rate as usize,
-            WHISPER_SAMPLE_RATE as usize,
+            target_rate as usize,
             1024,             // chunk size
             2,                // sub-chunks for better quality
             1,                // output channels (mono)
@@ -6,7 +6,7 @@
 //!
 //! ```text
 //! Settings (Aggregate Root)
-//!   ├── Transcription  - Provider, API keys, local models
+//!   ├── Transcription  - Provider, API keys, local models, custom endpoint, vocabulary
 //!   ├── PostProcessing - LLM processor, prompts
 //!   ├── Services       - Ollama, external services
 //!   ├── Shortcuts      - CLI and Desktop keyboard shortcuts
@@ -54,8 +54,14 @@ mod ui;
 pub use post_processing::PostProcessingSettings;
 pub use services::{OllamaConfig, ServicesSettings};
 pub use shortcuts::{CliShortcutMode, ShortcutsSettings};
-pub use transcription::{LocalModelsConfig, TranscriptionSettings};
-pub use ui::{BubbleSettings, ModelMemorySettings, UiSettings, VadSettings};
+pub use transcription::{
+    CustomAuthStyle, CustomEndpointConfig, LocalModelsConfig, TranscriptionSettings,
+    merge_vocabulary,
+};
+pub use ui::{
+    AutoStopSettings, BubbleSettings, ContinuousSettings, DenoiseSettings, ModelMemorySettings,
+    UiSettings, VadSettings,
+};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -25,7 +25,13 @@
 //! cli_push_to_talk      → shortcuts.cli_push_to_talk
 //! desktop_key           → shortcuts.desktop_key
 //! vad_enabled           → ui.vad.enabled
+//! vad_engine            → ui.vad.engine
 //! vad_threshold         → ui.vad.threshold
+//! vad_prefill_frames    → ui.vad.prefill_frames
+//! vad_onset_frames      → ui.vad.onset_frames
+//! vad_hangover_frames   → ui.vad.hangover_frames
+//! denoise_enabled       → ui.denoise.enabled
+//! denoise_strength      → ui.denoise.strength
 //! ```
 //!
 //! # Usage
@@ -133,12 +139,40 @@ impl Settings {
             settings.ui.vad.enabled = *enabled;
         }
 
+        if let Some(Value::String(engine)) = map.get("vad_engine")
+            && let Ok(engine) = engine.parse()
+        {
+            settings.ui.vad.engine = engine;
+        }
+
         if let Some(threshold) = map.get("vad_threshold")
             && let Some(t) = threshold.as_f64()
         {
             settings.ui.vad.threshold = t as f32;
         }
 
+        if let Some(frames) = map.get("vad_prefill_frames").and_then(Value::as_u64) {
+            settings.ui.vad.prefill_frames = frames as usize;
+        }
+
+        if let Some(frames) = map.get("vad_onset_frames").and_then(Value::as_u64) {
+            settings.ui.vad.onset_frames = frames as usize;
+        }
+
+        if let Some(frames) = map.get("vad_hangover_frames").and_then(Value::as_u64) {
+            settings.ui.vad.hangover_frames = frames as usize;
+        }
+
+        if let Some(Value::Bool(enabled)) = map.get("denoise_enabled") {
+            settings.ui.denoise.enabled = *enabled;
+        }
+
+        if let Some(strength) = map.get("denoise_strength")
+            && let Some(s) = strength.as_f64()
+        {
+            settings.ui.denoise.strength = s as f32;
+        }
+
         // Services settings
         if let Some(Value::String(url)) = map.get("ollama_url")
             && !url.is_empty()
@@ -216,11 +250,41 @@ impl Settings {
 
         map.insert("vad_enabled".to_string(), Value::Bool(self.ui.vad.enabled));
 
+        map.insert(
+            "vad_engine".to_string(),
+            Value::String(self.ui.vad.engine.to_string()),
+        );
+
         map.insert(
             "vad_threshold".to_string(),
             Value::Number(serde_json::Number::from_f64(self.ui.vad.threshold as f64).unwrap()),
         );
 
+        map.insert(
+            "vad_prefill_frames".to_string(),
+            Value::from(self.ui.vad.prefill_frames),
+        );
+
+        map.insert(
+            "vad_onset_frames".to_string(),
+            Value::from(self.ui.vad.onset_frames),
+        );
+
+        map.insert(
+            "vad_hangover_frames".to_string(),
+            Value::from(self.ui.vad.hangover_frames),
+        );
+
+        map.insert(
+            "denoise_enabled".to_string(),
+            Value::Bool(self.ui.denoise.enabled),
+        );
+
+        map.insert(
+            "denoise_strength".to_string(),
+            Value::Number(serde_json::Number::from_f64(self.ui.denoise.strength as f64).unwrap()),
+        );
+
         // Services settings
         if let Some(ref url) = self.services.ollama.url {
             map.insert("ollama_url".to_string(), Value::String(url.clone()));
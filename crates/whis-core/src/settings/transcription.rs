@@ -2,8 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
+use crate::audio::UploadFormat;
 use crate::config::TranscriptionProvider;
+use crate::transcription::FallbackProvider;
 
 #[cfg(feature = "local-transcription")]
 use crate::model::{ModelType, ParakeetModel};
@@ -24,9 +27,37 @@ pub struct TranscriptionSettings {
     #[serde(default)]
     pub api_keys: HashMap<String, String>,
 
+    /// Model overrides stored by provider name (e.g., "deepgram" -> "nova-3").
+    /// Providers without an entry use `TranscriptionProvider::default_model`.
+    #[serde(default)]
+    pub models: HashMap<String, String>,
+
     /// Local model configuration
     #[serde(default)]
     pub local_models: LocalModelsConfig,
+
+    /// Custom OpenAI-compatible endpoint (used by the "custom" provider)
+    #[serde(default)]
+    pub custom_endpoint: CustomEndpointConfig,
+
+    /// Terms to bias recognition towards (product names, jargon)
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+
+    /// Providers tried in order when the active provider fails on a chunk
+    /// (e.g., `["groq", "local-whisper"]`)
+    #[serde(default)]
+    pub fallback_providers: Vec<TranscriptionProvider>,
+
+    /// Translate transcripts to English (natively where the provider supports it,
+    /// otherwise with the post-processor)
+    #[serde(default)]
+    pub translate: bool,
+
+    /// Audio format for cloud uploads (None = pick per provider, see
+    /// `upload_format_for`)
+    #[serde(default)]
+    pub upload_format: Option<UploadFormat>,
 }
 
 impl Default for TranscriptionSettings {
@@ -35,7 +66,13 @@ impl Default for TranscriptionSettings {
             provider: crate::configuration::DEFAULT_PROVIDER,
             language: crate::configuration::DEFAULT_LANGUAGE.map(String::from),
             api_keys: HashMap::new(),
+            models: HashMap::new(),
             local_models: LocalModelsConfig::default(),
+            custom_endpoint: CustomEndpointConfig::default(),
+            vocabulary: Vec::new(),
+            fallback_providers: Vec::new(),
+            translate: false,
+            upload_format: None,
         }
     }
 }
@@ -52,9 +89,200 @@ pub struct LocalModelsConfig {
     /// (e.g., ~/.local/share/whis/models/parakeet/parakeet-tdt-0.6b-v3-int8)
     #[serde(default)]
     pub parakeet_path: Option<String>,
+
+    /// Decode a sliding window while recording (live text, near-instant stop)
+    /// instead of transcribing ~90s chunks
+    #[serde(default)]
+    pub streaming: bool,
+}
+
+/// How the API key is sent to a custom endpoint.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomAuthStyle {
+    /// `Authorization: Bearer <key>` (OpenAI style)
+    #[default]
+    Bearer,
+    /// `<auth_header>: <key>` (e.g., `X-API-Key` for gateways)
+    Header,
+    /// No authentication header
+    None,
+}
+
+impl fmt::Display for CustomAuthStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomAuthStyle::Bearer => write!(f, "bearer"),
+            CustomAuthStyle::Header => write!(f, "header"),
+            CustomAuthStyle::None => write!(f, "none"),
+        }
+    }
+}
+
+impl std::str::FromStr for CustomAuthStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bearer" => Ok(CustomAuthStyle::Bearer),
+            "header" => Ok(CustomAuthStyle::Header),
+            "none" => Ok(CustomAuthStyle::None),
+            _ => Err(format!(
+                "Unknown auth style: {}. Available: bearer, header, none",
+                s
+            )),
+        }
+    }
+}
+
+/// Configuration for a self-hosted or gateway OpenAI-compatible endpoint
+/// (faster-whisper-server, whisper.cpp `server`, LocalAI, ...).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CustomEndpointConfig {
+    /// Base URL of the API (e.g., "http://localhost:8000/v1")
+    #[serde(default)]
+    pub base_url: Option<String>,
+
+    /// Model name sent with each request (None = "whisper-1")
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// How the API key is sent
+    #[serde(default)]
+    pub auth: CustomAuthStyle,
+
+    /// Header name for `CustomAuthStyle::Header` (None = "X-API-Key")
+    #[serde(default)]
+    pub auth_header: Option<String>,
+
+    /// Request `verbose_json` with segment and word timestamps
+    #[serde(default)]
+    pub timestamps: bool,
+}
+
+impl CustomEndpointConfig {
+    /// Default model name for OpenAI-compatible servers
+    pub const DEFAULT_MODEL: &'static str = "whisper-1";
+
+    /// Default header name for `CustomAuthStyle::Header`
+    pub const DEFAULT_AUTH_HEADER: &'static str = "X-API-Key";
+
+    /// Full transcription URL (appends `/audio/transcriptions` unless already present)
+    pub fn transcription_url(&self) -> Option<String> {
+        let base = self.base_url.as_deref()?.trim().trim_end_matches('/');
+        if base.is_empty() {
+            return None;
+        }
+        if base.ends_with("/audio/transcriptions") {
+            Some(base.to_string())
+        } else {
+            Some(format!("{base}/audio/transcriptions"))
+        }
+    }
+
+    /// Model name, falling back to `DEFAULT_MODEL`
+    pub fn model(&self) -> &str {
+        self.model
+            .as_deref()
+            .filter(|m| !m.is_empty())
+            .unwrap_or(Self::DEFAULT_MODEL)
+    }
+
+    /// Header name used with `CustomAuthStyle::Header`
+    pub fn auth_header(&self) -> &str {
+        self.auth_header
+            .as_deref()
+            .filter(|h| !h.is_empty())
+            .unwrap_or(Self::DEFAULT_AUTH_HEADER)
+    }
+}
+
+/// Combine vocabulary lists, dropping blank entries and case-insensitive duplicates.
+///
+/// Order is preserved (earlier lists win), so global terms come before preset terms.
+pub fn merge_vocabulary(lists: &[&[String]]) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    for term in lists.iter().flat_map(|list| list.iter()) {
+        let term = term.trim();
+        if !term.is_empty() && !merged.iter().any(|t| t.eq_ignore_ascii_case(term)) {
+            merged.push(term.to_string());
+        }
+    }
+    merged
 }
 
 impl TranscriptionSettings {
+    /// Vocabulary for a transcription: global terms plus the preset's terms (if any)
+    pub fn vocabulary_for(&self, preset: Option<&crate::Preset>) -> Vec<String> {
+        let preset_terms = preset.map(|p| p.vocabulary.as_slice()).unwrap_or_default();
+        merge_vocabulary(&[&self.vocabulary, preset_terms])
+    }
+
+    /// Whether transcripts should be translated to English (setting or preset)
+    pub fn translate_for(&self, preset: Option<&crate::Preset>) -> bool {
+        self.translate || preset.is_some_and(|p| p.translate)
+    }
+
+    /// Configured model override for a provider (None = provider default)
+    pub fn model_override(&self, provider: &TranscriptionProvider) -> Option<&str> {
+        self.models
+            .get(provider.as_str())
+            .map(|m| m.trim())
+            .filter(|m| !m.is_empty())
+    }
+
+    /// Model sent to a cloud provider: the override, else the provider default
+    pub fn model_for(&self, provider: &TranscriptionProvider) -> Option<String> {
+        self.model_override(provider)
+            .or(provider.default_model())
+            .map(str::to_string)
+    }
+
+    /// Set or clear (None) the model override for a provider
+    pub fn set_model(&mut self, provider: &TranscriptionProvider, model: Option<String>) {
+        match model.filter(|m| !m.trim().is_empty()) {
+            Some(model) => {
+                self.models
+                    .insert(provider.as_str().to_string(), model.trim().to_string());
+            }
+            None => {
+                self.models.remove(provider.as_str());
+            }
+        }
+    }
+
+    /// Resolve the configured fallback providers with their credentials.
+    ///
+    /// Entries equal to the active provider, duplicates, and providers that aren't
+    /// configured (no API key, endpoint URL, or model path) are skipped. Like the
+    /// primary, local providers carry their model path in `api_key`.
+    pub fn fallback_chain(&self) -> Vec<FallbackProvider> {
+        let mut chain: Vec<FallbackProvider> = Vec::new();
+        for provider in &self.fallback_providers {
+            if *provider == self.provider || chain.iter().any(|f| f.provider == *provider) {
+                continue;
+            }
+            let api_key = match provider {
+                TranscriptionProvider::LocalWhisper => self.whisper_model_path(),
+                TranscriptionProvider::LocalParakeet => self.parakeet_model_path(),
+                TranscriptionProvider::Custom => self
+                    .custom_endpoint
+                    .transcription_url()
+                    .map(|_| self.api_key_for(provider).unwrap_or_default()),
+                _ => self.api_key_for(provider),
+            };
+            match api_key {
+                Some(api_key) => chain.push(FallbackProvider {
+                    provider: provider.clone(),
+                    api_key,
+                    model: self.model_override(provider).map(str::to_string),
+                }),
+                None => crate::verbose!("Skipping fallback '{}': not configured", provider),
+            }
+        }
+        chain
+    }
+
     /// Get the API key for the current provider, falling back to environment variables.
     pub fn api_key(&self) -> Option<String> {
         self.api_key_for(&self.provider)
@@ -117,6 +345,7 @@ impl TranscriptionSettings {
     /// Check if the current provider is properly configured.
     ///
     /// For cloud providers: checks for API key
+    /// For Custom: checks for a base URL
     /// For LocalWhisper: checks for model path AND that file exists
     /// For LocalParakeet: checks for model directory AND it's valid
     pub fn is_configured(&self) -> bool {
@@ -132,6 +361,8 @@ impl TranscriptionSettings {
                 .unwrap_or(false),
             #[cfg(not(feature = "local-transcription"))]
             TranscriptionProvider::LocalParakeet => false,
+            // API key is optional for self-hosted endpoints
+            TranscriptionProvider::Custom => self.custom_endpoint.transcription_url().is_some(),
             _ => self.has_api_key(),
         }
     }
@@ -163,3 +394,123 @@ impl TranscriptionSettings {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(terms: &[&str]) -> Vec<String> {
+        terms.iter().map(|t| t.to_string()).collect()
+    }
+
+    fn endpoint(base_url: &str) -> CustomEndpointConfig {
+        CustomEndpointConfig {
+            base_url: Some(base_url.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn transcription_url_appends_the_transcriptions_path() {
+        assert_eq!(
+            endpoint("http://localhost:8000/v1")
+                .transcription_url()
+                .as_deref(),
+            Some("http://localhost:8000/v1/audio/transcriptions")
+        );
+        assert_eq!(
+            endpoint(" http://localhost:8000/v1/ ")
+                .transcription_url()
+                .as_deref(),
+            Some("http://localhost:8000/v1/audio/transcriptions")
+        );
+    }
+
+    #[test]
+    fn transcription_url_keeps_an_existing_transcriptions_path() {
+        assert_eq!(
+            endpoint("https://gateway.example/v1/audio/transcriptions/")
+                .transcription_url()
+                .as_deref(),
+            Some("https://gateway.example/v1/audio/transcriptions")
+        );
+    }
+
+    #[test]
+    fn transcription_url_requires_a_base_url() {
+        assert_eq!(CustomEndpointConfig::default().transcription_url(), None);
+        assert_eq!(endpoint("  /  ").transcription_url(), None);
+    }
+
+    #[test]
+    fn merge_vocabulary_keeps_first_spelling_and_order() {
+        let global = terms(&["Kubernetes", " whis ", ""]);
+        let preset = terms(&["WHIS", "PostgreSQL", "kubernetes", "  "]);
+
+        assert_eq!(
+            merge_vocabulary(&[&global, &preset]),
+            terms(&["Kubernetes", "whis", "PostgreSQL"])
+        );
+    }
+
+    #[test]
+    fn vocabulary_for_without_preset_is_the_global_list() {
+        let settings = TranscriptionSettings {
+            vocabulary: terms(&["whis", "Whis", "Tauri"]),
+            ..Default::default()
+        };
+
+        assert_eq!(settings.vocabulary_for(None), terms(&["whis", "Tauri"]));
+    }
+
+    #[test]
+    fn fallback_chain_skips_primary_duplicates_and_unconfigured_providers() {
+        let mut settings = TranscriptionSettings {
+            provider: TranscriptionProvider::OpenAI,
+            fallback_providers: vec![
+                TranscriptionProvider::OpenAI,
+                TranscriptionProvider::Groq,
+                TranscriptionProvider::Mistral,
+                TranscriptionProvider::Groq,
+                TranscriptionProvider::Custom,
+                TranscriptionProvider::LocalWhisper,
+            ],
+            custom_endpoint: endpoint("http://localhost:8000/v1"),
+            ..Default::default()
+        };
+        settings
+            .api_keys
+            .insert("groq".to_string(), "gsk_test".to_string());
+        settings.local_models.whisper_path = Some("/models/ggml-small.bin".to_string());
+
+        let chain: Vec<_> = settings
+            .fallback_chain()
+            .into_iter()
+            .map(|f| (f.provider, f.api_key))
+            .collect();
+
+        // Mistral has no key; the custom endpoint needs none
+        assert_eq!(
+            chain,
+            vec![
+                (TranscriptionProvider::Groq, "gsk_test".to_string()),
+                (TranscriptionProvider::Custom, String::new()),
+                (
+                    TranscriptionProvider::LocalWhisper,
+                    "/models/ggml-small.bin".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn fallback_chain_skips_custom_without_url() {
+        let settings = TranscriptionSettings {
+            provider: TranscriptionProvider::OpenAI,
+            fallback_providers: vec![TranscriptionProvider::Custom],
+            ..Default::default()
+        };
+
+        assert!(settings.fallback_chain().is_empty());
+    }
+}
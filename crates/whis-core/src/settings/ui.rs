@@ -2,12 +2,14 @@
 //!
 //! This module contains settings for:
 //! - Audio recording configuration (microphone, VAD, chunking)
+//! - Continuous dictation (per-utterance output)
 //! - Output handling (clipboard backend, presets)
 //! - Desktop-specific features (floating bubble overlay)
 //!
 //! Note: Keyboard shortcuts are now in the `shortcuts` module.
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 #[cfg(feature = "clipboard")]
 use crate::clipboard::ClipboardMethod;
@@ -49,6 +51,27 @@ pub struct UiSettings {
     #[serde(default)]
     pub vad: VadSettings,
 
+    /// Noise suppression settings.
+    ///
+    /// When enabled, background noise is removed before VAD and
+    /// transcription (RNNoise).
+    #[serde(default)]
+    pub denoise: DenoiseSettings,
+
+    /// Auto-stop settings.
+    ///
+    /// When enabled, recordings end by themselves once the
+    /// speaker stops talking (uses VAD speech detection).
+    #[serde(default)]
+    pub auto_stop: AutoStopSettings,
+
+    /// Continuous dictation settings.
+    ///
+    /// When enabled, the record shortcut starts listening until it is
+    /// pressed again, and every utterance is output as soon as it's ready.
+    #[serde(default)]
+    pub continuous: ContinuousSettings,
+
     /// Currently active output preset name.
     ///
     /// Presets define post-processing transformations like
@@ -72,6 +95,13 @@ pub struct UiSettings {
     #[serde(default = "default_chunk_duration")]
     pub chunk_duration_secs: u64,
 
+    /// Maximum number of chunks transcribed at once by cloud providers.
+    ///
+    /// Halved automatically while the provider returns rate-limit errors.
+    /// 1 = strictly sequential. Valid range: 1-8
+    #[serde(default = "default_chunk_concurrency")]
+    pub chunk_concurrency: usize,
+
     /// Floating bubble overlay settings (desktop only).
     ///
     /// Shows a small floating indicator during recording.
@@ -129,6 +159,10 @@ fn default_chunk_duration() -> u64 {
     crate::configuration::DEFAULT_CHUNK_DURATION_SECS
 }
 
+fn default_chunk_concurrency() -> usize {
+    crate::configuration::DEFAULT_CHUNK_CONCURRENCY
+}
+
 /// Voice Activity Detection configuration.
 ///
 /// VAD automatically detects speech and skips silence,
@@ -143,6 +177,14 @@ pub struct VadSettings {
     #[serde(default)]
     pub enabled: bool,
 
+    /// Engine that detects speech.
+    ///
+    /// - `auto`: Silero when available, otherwise energy (default)
+    /// - `silero`: Neural model, most accurate (needs ONNX Runtime)
+    /// - `energy`: Built-in energy detector, works on every platform
+    #[serde(default)]
+    pub engine: crate::VadEngine,
+
     /// Speech probability threshold (0.0-1.0).
     ///
     /// - Lower (0.3): More sensitive, may include background noise
@@ -152,15 +194,199 @@ pub struct VadSettings {
     /// Adjust if VAD is cutting off speech or including too much silence.
     #[serde(default)]
     pub threshold: f32,
+
+    /// Frames (32ms each) kept from before speech is confirmed.
+    ///
+    /// Raise if the first syllables of fast speech are clipped.
+    /// Valid range: 0-60 frames
+    #[serde(default = "default_vad_prefill_frames")]
+    pub prefill_frames: usize,
+
+    /// Consecutive speech frames (32ms each) required to confirm speech.
+    ///
+    /// - Lower (1): Reacts immediately, clicks may count as speech
+    /// - Higher (4+): Ignores short noises, may miss short words
+    ///
+    /// Valid range: 1-10 frames
+    #[serde(default = "default_vad_onset_frames")]
+    pub onset_frames: usize,
+
+    /// Frames (32ms each) kept after speech stops.
+    ///
+    /// Raise if word endings or short pauses are cut.
+    /// Valid range: 0-60 frames
+    #[serde(default = "default_vad_hangover_frames")]
+    pub hangover_frames: usize,
+}
+
+fn default_vad_prefill_frames() -> usize {
+    crate::configuration::DEFAULT_VAD_PREFILL_FRAMES
+}
+
+fn default_vad_onset_frames() -> usize {
+    crate::configuration::DEFAULT_VAD_ONSET_FRAMES
+}
+
+fn default_vad_hangover_frames() -> usize {
+    crate::configuration::DEFAULT_VAD_HANGOVER_FRAMES
 }
 
 impl Default for VadSettings {
     fn default() -> Self {
         Self {
             enabled: crate::configuration::DEFAULT_VAD_ENABLED,
+            engine: crate::VadEngine::default(),
             threshold: crate::configuration::DEFAULT_VAD_THRESHOLD,
+            prefill_frames: crate::configuration::DEFAULT_VAD_PREFILL_FRAMES,
+            onset_frames: crate::configuration::DEFAULT_VAD_ONSET_FRAMES,
+            hangover_frames: crate::configuration::DEFAULT_VAD_HANGOVER_FRAMES,
+        }
+    }
+}
+
+impl VadSettings {
+    /// Recorder configuration for these settings
+    pub fn config(&self) -> crate::VadConfig {
+        crate::VadConfig {
+            enabled: self.enabled,
+            engine: self.engine,
+            threshold: self.threshold,
+            prefill_frames: self.prefill_frames,
+            onset_frames: self.onset_frames,
+            hangover_frames: self.hangover_frames,
         }
     }
+
+    /// These settings with the preset's VAD overrides applied
+    pub fn for_preset(&self, preset: Option<&crate::Preset>) -> VadSettings {
+        let mut settings = self.clone();
+        if let Some(vad) = preset.and_then(|p| p.vad.as_ref()) {
+            settings.threshold = vad.threshold.unwrap_or(settings.threshold);
+            settings.prefill_frames = vad.prefill_frames.unwrap_or(settings.prefill_frames);
+            settings.onset_frames = vad.onset_frames.unwrap_or(settings.onset_frames);
+            settings.hangover_frames = vad.hangover_frames.unwrap_or(settings.hangover_frames);
+        }
+        settings
+    }
+}
+
+/// Noise suppression configuration.
+///
+/// Runs RNNoise on the recording before VAD and chunking,
+/// which helps with fans, keyboards and street noise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DenoiseSettings {
+    /// Enable noise suppression.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Suppression strength (0.0-1.0).
+    ///
+    /// Blends the denoised audio with the original:
+    /// - Lower (0.5): Keeps some background, more natural voice
+    /// - Default (1.0): Full suppression
+    #[serde(default = "default_denoise_strength")]
+    pub strength: f32,
+}
+
+fn default_denoise_strength() -> f32 {
+    crate::configuration::DEFAULT_DENOISE_STRENGTH
+}
+
+impl Default for DenoiseSettings {
+    fn default() -> Self {
+        Self {
+            enabled: crate::configuration::DEFAULT_DENOISE_ENABLED,
+            strength: crate::configuration::DEFAULT_DENOISE_STRENGTH,
+        }
+    }
+}
+
+/// Auto-stop configuration.
+///
+/// Ends a recording after trailing silence once speech was detected,
+/// and optionally when nobody speaks at all.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoStopSettings {
+    /// Enable auto-stop.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Silence after speech that ends the recording (seconds).
+    ///
+    /// Valid range: 0.5-10 seconds
+    #[serde(default = "default_auto_stop_silence")]
+    pub silence_secs: f32,
+
+    /// Stop if no speech is detected within this time (seconds).
+    ///
+    /// - `null`: Wait for speech indefinitely (default)
+    #[serde(default)]
+    pub no_speech_timeout_secs: Option<f32>,
+}
+
+fn default_auto_stop_silence() -> f32 {
+    crate::configuration::DEFAULT_AUTO_STOP_SILENCE_SECS
+}
+
+impl Default for AutoStopSettings {
+    fn default() -> Self {
+        Self {
+            enabled: crate::configuration::DEFAULT_AUTO_STOP_ENABLED,
+            silence_secs: crate::configuration::DEFAULT_AUTO_STOP_SILENCE_SECS,
+            no_speech_timeout_secs: None,
+        }
+    }
+}
+
+impl AutoStopSettings {
+    /// Recorder configuration for these settings
+    pub fn config(&self) -> crate::AutoStopConfig {
+        crate::AutoStopConfig {
+            enabled: self.enabled,
+            silence: std::time::Duration::from_secs_f32(self.silence_secs.max(0.0)),
+            no_speech_timeout: self
+                .no_speech_timeout_secs
+                .filter(|secs| *secs > 0.0)
+                .map(std::time::Duration::from_secs_f32),
+        }
+    }
+}
+
+/// Continuous (hands-free) dictation configuration.
+///
+/// Listens until toggled off, splits the audio at pauses (VAD) and outputs
+/// each utterance on its own.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContinuousSettings {
+    /// Toggle continuous dictation instead of single recordings.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Also append each utterance to this file (one line per utterance).
+    ///
+    /// - `null`: Only use the output method (default)
+    #[serde(default)]
+    pub output_file: Option<PathBuf>,
+}
+
+impl ContinuousSettings {
+    /// Append an utterance to `output_file` (no-op if unset)
+    pub fn append(&self, text: &str) -> anyhow::Result<()> {
+        use anyhow::Context;
+        use std::io::Write;
+
+        let Some(path) = &self.output_file else {
+            return Ok(());
+        };
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        writeln!(file, "{}", text.trim())
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
 }
 
 /// Floating bubble overlay settings (experimental).
@@ -233,8 +459,12 @@ impl Default for UiSettings {
             clipboard_backend: ClipboardMethod::default(),
             microphone_device: None,
             vad: VadSettings::default(),
+            denoise: DenoiseSettings::default(),
+            auto_stop: AutoStopSettings::default(),
+            continuous: ContinuousSettings::default(),
             active_preset: None,
             chunk_duration_secs: crate::configuration::DEFAULT_CHUNK_DURATION_SECS,
+            chunk_concurrency: crate::configuration::DEFAULT_CHUNK_CONCURRENCY,
             bubble: BubbleSettings::default(),
             model_memory: ModelMemorySettings::default(),
             #[cfg(feature = "autotyping")]
@@ -0,0 +1,289 @@
+//! Checkpoints for resumable file transcription
+//!
+//! A checkpoint keeps the result of every chunk transcribed so far, so a long
+//! file that fails halfway (network drop, quota) resumes from the missing
+//! chunks instead of re-uploading and re-billing everything.
+//!
+//! Checkpoints are stored in the cache directory (`~/.cache/whis/checkpoints`
+//! on Linux), one file per audio SHA-256. A checkpoint is only reused for the
+//! same provider, model, fallback chain and options, and a chunk only when its
+//! boundaries match, so changing the chunk duration starts over. Finished
+//! transcriptions remove their checkpoint; abandoned ones are pruned after a
+//! week.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::audio::chunker::AudioChunk;
+use crate::config::TranscriptionProvider;
+use crate::provider::model_for;
+
+use super::transcribe::{ChunkTranscription, CloudTranscriptionOptions};
+
+/// Checkpoints older than this are deleted when a new one is opened
+const MAX_CHECKPOINT_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Saved chunk results for one audio file
+pub struct TranscriptionCheckpoint {
+    path: PathBuf,
+    data: CheckpointData,
+    /// (index, start sample, sample count) of the chunks being transcribed
+    boundaries: Vec<(usize, usize, usize)>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CheckpointData {
+    /// Provider and options the chunks were transcribed with
+    options: String,
+    chunks: Vec<CheckpointChunk>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CheckpointChunk {
+    start_sample: usize,
+    sample_count: usize,
+    transcription: ChunkTranscription,
+}
+
+impl TranscriptionCheckpoint {
+    /// Open (or start) the checkpoint for `samples` transcribed with `provider`
+    ///
+    /// A checkpoint left by a run with a different provider or options is
+    /// discarded.
+    pub fn open(
+        samples: &[f32],
+        provider: &TranscriptionProvider,
+        options: &CloudTranscriptionOptions,
+    ) -> Result<Self> {
+        let dir = dirs::cache_dir()
+            .context("No cache directory for transcription checkpoints")?
+            .join("whis")
+            .join("checkpoints");
+        fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        prune_stale(&dir);
+
+        let path = dir.join(format!("{}.json", hash_samples(samples)));
+        let fallbacks: Vec<String> = options
+            .fallbacks
+            .iter()
+            .map(|fallback| {
+                // Local fallbacks are identified by their model path
+                let model = if fallback.provider.is_local() {
+                    fallback.api_key.clone()
+                } else {
+                    model_for(&fallback.provider, fallback.model.as_deref())
+                };
+                format!("{}:{}", fallback.provider, model)
+            })
+            .collect();
+        let options = format!(
+            "{}:{}|{:?}|{}|{}|{:?}|{:?}",
+            provider,
+            model_for(provider, options.model.as_deref()),
+            options.language,
+            options.diarize,
+            options.translate,
+            options.vocabulary,
+            fallbacks
+        );
+
+        let data = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CheckpointData>(&content).ok())
+            .filter(|data| data.options == options)
+            .unwrap_or(CheckpointData {
+                options,
+                chunks: Vec::new(),
+            });
+
+        Ok(Self {
+            path,
+            data,
+            boundaries: Vec::new(),
+        })
+    }
+
+    /// Take saved results for `chunks` whose boundaries still match
+    ///
+    /// Also remembers the boundaries so `record` can save new results.
+    pub(super) fn take_completed(&mut self, chunks: &[AudioChunk]) -> Vec<ChunkTranscription> {
+        self.boundaries = chunks
+            .iter()
+            .map(|chunk| (chunk.index, chunk.start_sample, chunk.samples.len()))
+            .collect();
+
+        // Chunks cut differently than this time can't be merged; drop them
+        let boundaries = &self.boundaries;
+        self.data.chunks.retain(|saved| {
+            boundaries.contains(&(
+                saved.transcription.index,
+                saved.start_sample,
+                saved.sample_count,
+            ))
+        });
+
+        self.data
+            .chunks
+            .iter()
+            .map(|saved| saved.transcription.clone())
+            .collect()
+    }
+
+    /// Save a finished chunk
+    pub(super) fn record(&mut self, transcription: &ChunkTranscription) -> Result<()> {
+        let Some(&(_, start_sample, sample_count)) = self
+            .boundaries
+            .iter()
+            .find(|(index, _, _)| *index == transcription.index)
+        else {
+            return Ok(());
+        };
+
+        self.data.chunks.push(CheckpointChunk {
+            start_sample,
+            sample_count,
+            transcription: transcription.clone(),
+        });
+
+        let json = serde_json::to_string(&self.data)?;
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, json).with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Delete the checkpoint (the transcription is complete)
+    pub fn remove(self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// SHA-256 of the samples (hex)
+fn hash_samples(samples: &[f32]) -> String {
+    let mut hasher = Sha256::new();
+    let mut bytes = Vec::with_capacity(64 * 1024);
+    for block in samples.chunks(16 * 1024) {
+        bytes.clear();
+        bytes.extend(block.iter().flat_map(|sample| sample.to_le_bytes()));
+        hasher.update(&bytes);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Delete checkpoints nobody resumed
+fn prune_stale(dir: &std::path::Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let stale = entry
+            .metadata()
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > MAX_CHECKPOINT_AGE);
+        if stale {
+            crate::verbose!("Removing stale checkpoint {}", entry.path().display());
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::TranscriptionResult;
+
+    /// Chunk of silence starting at `start_secs`, `secs` long
+    fn audio(index: usize, start_secs: usize, secs: usize) -> AudioChunk {
+        AudioChunk {
+            index,
+            samples: vec![0.0; secs * 16_000],
+            has_leading_overlap: index > 0,
+            start_sample: start_secs * 16_000,
+            encoded: None,
+        }
+    }
+
+    fn transcribed(chunk: &AudioChunk) -> ChunkTranscription {
+        ChunkTranscription::new(
+            chunk,
+            TranscriptionResult::from_text(format!("chunk {}", chunk.index)),
+            &TranscriptionProvider::OpenAI,
+        )
+    }
+
+    fn checkpoint_at(path: &std::path::Path) -> TranscriptionCheckpoint {
+        let data = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        TranscriptionCheckpoint {
+            path: path.to_path_buf(),
+            data,
+            boundaries: Vec::new(),
+        }
+    }
+
+    fn indexes(transcriptions: &[ChunkTranscription]) -> Vec<usize> {
+        transcriptions.iter().map(|t| t.index).collect()
+    }
+
+    #[test]
+    fn resumes_chunks_saved_by_an_earlier_run() {
+        let path =
+            std::env::temp_dir().join(format!("whis-checkpoint-{}.json", std::process::id()));
+        fs::remove_file(&path).ok();
+        let chunks = [audio(0, 0, 10), audio(1, 9, 10), audio(2, 18, 10)];
+
+        let mut first = checkpoint_at(&path);
+        assert!(first.take_completed(&chunks).is_empty());
+        first.record(&transcribed(&chunks[0])).unwrap();
+        first.record(&transcribed(&chunks[2])).unwrap();
+
+        let mut second = checkpoint_at(&path);
+        let resumed = second.take_completed(&chunks);
+        fs::remove_file(&path).ok();
+        assert_eq!(indexes(&resumed), [0, 2]);
+    }
+
+    #[test]
+    fn drops_chunks_cut_at_different_boundaries() {
+        let path = std::env::temp_dir().join(format!(
+            "whis-checkpoint-{}-boundaries.json",
+            std::process::id()
+        ));
+        fs::remove_file(&path).ok();
+        let before = [audio(0, 0, 10), audio(1, 9, 10)];
+        let mut checkpoint = checkpoint_at(&path);
+        checkpoint.take_completed(&before);
+        checkpoint.record(&transcribed(&before[0])).unwrap();
+        checkpoint.record(&transcribed(&before[1])).unwrap();
+
+        // Longer second chunk (chunk duration changed): only chunk 0 still fits
+        let after = [audio(0, 0, 10), audio(1, 9, 12)];
+        let mut checkpoint = checkpoint_at(&path);
+        assert_eq!(indexes(&checkpoint.take_completed(&after)), [0]);
+
+        // New results are saved with the new boundaries; unknown chunks are ignored
+        checkpoint.record(&transcribed(&after[1])).unwrap();
+        checkpoint.record(&transcribed(&audio(5, 40, 10))).unwrap();
+        let saved: Vec<(usize, usize)> = checkpoint
+            .data
+            .chunks
+            .iter()
+            .map(|chunk| (chunk.start_sample, chunk.sample_count))
+            .collect();
+        assert_eq!(saved, [(0, 160_000), (144_000, 192_000)]);
+
+        let mut reopened = checkpoint_at(&path);
+        let resumed = reopened.take_completed(&after);
+        fs::remove_file(&path).ok();
+        assert_eq!(indexes(&resumed), [0, 1]);
+    }
+}
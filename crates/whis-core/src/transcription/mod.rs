@@ -2,16 +2,20 @@
 //!
 //! This module contains:
 //! - Progressive transcription functions (cloud and local)
+//! - Per-utterance transcription for continuous dictation
+//! - Checkpoints for resuming interrupted file transcriptions
 //! - Ollama integration for local LLM
 //! - Post-processing with LLM cleanup
 //! - Connection warmup utilities
 
+mod checkpoint;
 mod ollama;
 mod ollama_manager;
 mod post_processing;
 mod transcribe;
 mod warmup;
 
+pub use checkpoint::TranscriptionCheckpoint;
 pub use ollama::{
     DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, OLLAMA_MODEL_OPTIONS, OllamaModel,
     ensure_ollama_ready, ensure_ollama_running, has_model, is_ollama_installed, is_ollama_running,
@@ -19,10 +23,14 @@ pub use ollama::{
 };
 pub use ollama_manager::{clear_warmup_cache, preload_ollama};
 pub use post_processing::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
-    resolve_post_processor_config,
+    DEFAULT_POST_PROCESSING_PROMPT, LANGUAGE_PLACEHOLDER, PostProcessConfig, PostProcessor,
+    TRANSLATION_PROMPT, fill_language_placeholder, post_process, resolve_post_processor_config,
+    translate_transcript,
 };
-pub use transcribe::progressive_transcribe_cloud;
 #[cfg(feature = "local-transcription")]
 pub use transcribe::progressive_transcribe_local;
+pub use transcribe::{
+    CloudTranscriptionOptions, FallbackProvider, progressive_transcribe_cloud,
+    transcribe_chunks_resumable, transcribe_utterances,
+};
 pub use warmup::{WarmupConfig, warmup_configured};
@@ -24,7 +24,7 @@
 //! ).await?;
 //! ```
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -39,6 +39,27 @@ Remove filler words (um, uh, like, you know). \
 Fix grammar and punctuation. Keep technical terms intact. \
 Output only the cleaned text, no explanations.";
 
+/// Prompt used when translating transcripts to English with an LLM
+pub const TRANSLATION_PROMPT: &str = "Translate this voice transcript into English. \
+Keep names, technical terms and formatting intact. \
+If it is already in English, return it unchanged. \
+Output only the translated text, no explanations.";
+
+/// Placeholder in post-processing prompts replaced by the detected language
+pub const LANGUAGE_PLACEHOLDER: &str = "{language}";
+
+/// Fill [`LANGUAGE_PLACEHOLDER`] in a prompt with the spoken language.
+///
+/// Lets presets react to the language, e.g. "Reply in {language}". Uses the
+/// ISO 639-1 code from `TranscriptionResult::detected_language`, or "the
+/// original language" when the provider didn't report one.
+pub fn fill_language_placeholder(prompt: &str, language: Option<&str>) -> String {
+    prompt.replace(
+        LANGUAGE_PLACEHOLDER,
+        language.unwrap_or("the original language"),
+    )
+}
+
 /// Available post-processing providers (LLM for transcript cleanup)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -367,3 +388,30 @@ pub fn resolve_post_processor_config(
         PostProcessor::None => Err(anyhow!("Post-processing not configured. Run: whis setup")),
     }
 }
+
+/// Translate a transcript to English with the configured post-processor.
+///
+/// Fallback for providers without native translation (see
+/// `TranscriptionProvider::supports_translation`). Uses the same processor, API key
+/// and model as post-processing (including preset overrides), with [`TRANSLATION_PROMPT`].
+pub async fn translate_transcript(
+    text: &str,
+    preset: &Option<Preset>,
+    settings: &Settings,
+) -> Result<String> {
+    if text.trim().is_empty() {
+        return Ok(text.to_string());
+    }
+
+    let (processor, api_key, model, _prompt) = resolve_post_processor_config(preset, settings)
+        .context("Translation with this provider needs a post-processor")?;
+
+    post_process(
+        text,
+        &processor,
+        &api_key,
+        TRANSLATION_PROMPT,
+        model.as_deref(),
+    )
+    .await
+}
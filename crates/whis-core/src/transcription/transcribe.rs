@@ -1,75 +1,325 @@
 //! Progressive audio transcription using provider registry.
 //!
 //! All audio inputs (microphone, file, stdin) use progressive transcription:
-//! - Cloud: `progressive_transcribe_cloud()` - sequential processing
+//! - Cloud: `progressive_transcribe_cloud()` - bounded-parallel processing, with an
+//!   optional fallback chain tried per chunk when the primary provider fails
 //! - Local: `progressive_transcribe_local()` - sequential with shared model cache
+//! - Files: `transcribe_chunks_resumable()` - cloud transcription of known chunks
+//!   that resumes from a checkpoint after a failure
+//! - Continuous dictation: `transcribe_utterances()` - one result per utterance,
+//!   delivered as soon as it's ready
 //!
 //! Supports overlap merging for seamless chunk boundaries.
 
 use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use futures_util::stream::FuturesUnordered;
+use serde::{Deserialize, Serialize};
 
+use crate::audio::{UploadFormat, create_encoder_for, upload_format_for};
 use crate::config::TranscriptionProvider;
 use crate::http::get_http_client;
-use crate::provider::{TranscriptionRequest, registry};
+use crate::provider::{
+    ChunkSource, TranscriptionRequest, TranscriptionResult, TranscriptionWord, registry,
+    weighted_confidence,
+};
+use crate::settings::CustomEndpointConfig;
+
+use super::checkpoint::TranscriptionCheckpoint;
 
 /// Maximum words to search for overlap between chunks
 const MAX_OVERLAP_WORDS: usize = 15;
 
+/// Largest time difference between the two transcriptions of an overlap word
+const SPEAKER_MATCH_SECS: f64 = 0.5;
+
 /// Result of transcribing a single chunk
-struct ChunkTranscription {
-    index: usize,
-    text: String,
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct ChunkTranscription {
+    pub(super) index: usize,
+    result: TranscriptionResult,
     has_leading_overlap: bool,
+    /// Chunk start within the recording (seconds, including leading overlap)
+    start_secs: f64,
+    /// Chunk end within the recording (seconds)
+    end_secs: f64,
+}
+
+impl ChunkTranscription {
+    pub(super) fn new(
+        chunk: &ProgressiveChunk,
+        mut result: TranscriptionResult,
+        provider: &TranscriptionProvider,
+    ) -> Self {
+        // Provider timings are relative to the chunk, make them absolute
+        result.shift(chunk.start_secs());
+        result.chunks = vec![ChunkSource {
+            index: chunk.index,
+            start: chunk.start_secs(),
+            end: chunk.start_secs() + chunk.duration_secs(),
+            provider: provider.clone(),
+        }];
+        Self {
+            index: chunk.index,
+            result,
+            has_leading_overlap: chunk.has_leading_overlap,
+            start_secs: chunk.start_secs(),
+            end_secs: chunk.start_secs() + chunk.duration_secs(),
+        }
+    }
+}
+
+/// Language and confidence reported for one chunk
+struct ChunkReport {
+    duration_secs: f64,
+    language: Option<String>,
+    language_confidence: Option<f64>,
+    confidence: Option<f64>,
 }
 
 /// Merge transcription results, handling overlaps
-fn merge_transcriptions(transcriptions: Vec<ChunkTranscription>) -> String {
+///
+/// Text is deduplicated by word matching. Timings are cut at the middle of
+/// each overlap: the earlier chunk keeps everything before the cut, the later
+/// chunk everything after it. The detected language is the one reported for
+/// most of the audio; confidences are averaged weighted by chunk duration.
+fn merge_transcriptions(transcriptions: Vec<ChunkTranscription>) -> TranscriptionResult {
     if transcriptions.is_empty() {
-        return String::new();
+        return TranscriptionResult::default();
     }
 
     if transcriptions.len() == 1 {
-        return transcriptions.into_iter().next().unwrap().text;
+        return transcriptions.into_iter().next().unwrap().result;
     }
 
-    let mut merged = String::new();
+    let mut merged = TranscriptionResult {
+        translated: true,
+        ..Default::default()
+    };
+    let mut previous_end: Option<f64> = None;
+    let mut reports = Vec::new();
 
     for (i, transcription) in transcriptions.into_iter().enumerate() {
-        let text = transcription.text.trim();
+        let ChunkTranscription {
+            index,
+            mut result,
+            has_leading_overlap,
+            start_secs,
+            end_secs,
+        } = transcription;
+
+        // Timings: cut at the middle of the overlap shared with the previous chunk
+        let overlap_end = previous_end.filter(|_| has_leading_overlap);
+        if let Some(overlap_end) = overlap_end {
+            reconcile_speakers(&merged, &mut result, start_secs, overlap_end);
+        }
+        let cut = overlap_end.map(|prev_end| (start_secs + prev_end) / 2.0);
+        let text = result.text.trim();
+        merge_timed(&mut merged.segments, result.segments, cut, |s| {
+            (s.start, s.end)
+        });
+        merge_timed(&mut merged.words, result.words, cut, |w| (w.start, w.end));
+        merged.chunks.extend(result.chunks);
+        merged.rate_limited += result.rate_limited;
+        // Translated only if every chunk was (fallbacks may lack translation)
+        merged.translated &= result.translated;
+        reports.push(ChunkReport {
+            duration_secs: end_secs - start_secs,
+            language: result.detected_language,
+            language_confidence: result.language_confidence,
+            confidence: result.confidence,
+        });
+        previous_end = Some(end_secs);
 
+        let merged_text = &mut merged.text;
         if i == 0 {
             // First chunk - use as-is
-            merged.push_str(text);
-        } else if transcription.has_leading_overlap {
+            merged_text.push_str(text);
+        } else if has_leading_overlap {
             // This chunk has overlap - try to find and remove duplicate words
-            let cleaned_text = remove_overlap(&merged, text);
+            let cleaned_text = remove_overlap(merged_text, text);
 
             // Skip completely deduplicated chunks to avoid extra whitespace
             if cleaned_text.trim().is_empty() {
                 crate::verbose!(
                     "Chunk {} completely deduplicated after overlap removal",
-                    transcription.index
+                    index
                 );
                 continue;
             }
 
-            if !merged.ends_with(' ') && !cleaned_text.is_empty() && !cleaned_text.starts_with(' ')
+            if !merged_text.ends_with(' ')
+                && !cleaned_text.is_empty()
+                && !cleaned_text.starts_with(' ')
             {
-                merged.push(' ');
+                merged_text.push(' ');
             }
-            merged.push_str(&cleaned_text);
+            merged_text.push_str(&cleaned_text);
         } else {
             // No overlap - just append with space
-            if !merged.ends_with(' ') && !text.is_empty() && !text.starts_with(' ') {
-                merged.push(' ');
+            if !merged_text.ends_with(' ') && !text.is_empty() && !text.starts_with(' ') {
+                merged_text.push(' ');
             }
-            merged.push_str(text);
+            merged_text.push_str(text);
         }
     }
 
+    merged.confidence = weighted_confidence(
+        reports
+            .iter()
+            .map(|report| (report.duration_secs, report.confidence)),
+    );
+    merged.detected_language = dominant_language(&reports);
+    merged.language_confidence = weighted_confidence(
+        reports
+            .iter()
+            .filter(|report| report.language == merged.detected_language)
+            .map(|report| (report.duration_secs, report.language_confidence)),
+    );
+
     merged
 }
 
+/// Language reported for the largest share of audio across chunks
+fn dominant_language(reports: &[ChunkReport]) -> Option<String> {
+    let mut totals: Vec<(&str, f64)> = Vec::new();
+    for report in reports {
+        let Some(language) = report.language.as_deref() else {
+            continue;
+        };
+        match totals.iter_mut().find(|(l, _)| *l == language) {
+            Some((_, secs)) => *secs += report.duration_secs,
+            None => totals.push((language, report.duration_secs)),
+        }
+    }
+
+    // First reported language wins ties
+    totals
+        .into_iter()
+        .reduce(|best, next| if next.1 > best.1 { next } else { best })
+        .map(|(language, _)| language.to_string())
+}
+
+/// Renumber the speakers of a chunk to match the transcript merged so far
+///
+/// Providers number speakers per request, so "Speaker 1" of one chunk may be
+/// "Speaker 2" of the next. Words spoken in the overlap shared with the
+/// previous chunk appear in both: each speaker of the new chunk takes the
+/// label the earlier transcript gave most of those words. Speakers not heard
+/// in the overlap get labels not used so far.
+fn reconcile_speakers(
+    merged: &TranscriptionResult,
+    result: &mut TranscriptionResult,
+    overlap_start: f64,
+    overlap_end: f64,
+) {
+    if !result.has_speakers() || !merged.has_speakers() {
+        return;
+    }
+
+    let in_overlap = |w: &&TranscriptionWord| {
+        let midpoint = (w.start + w.end) / 2.0;
+        w.speaker.is_some() && (overlap_start..=overlap_end).contains(&midpoint)
+    };
+    let earlier: Vec<&TranscriptionWord> = merged.words.iter().filter(in_overlap).collect();
+
+    // Count how often each (new, earlier) speaker pair said the same word
+    let mut votes: Vec<((u32, u32), usize)> = Vec::new();
+    for word in result.words.iter().filter(in_overlap) {
+        let midpoint = (word.start + word.end) / 2.0;
+        let same_word = earlier
+            .iter()
+            .filter(|e| normalize_word(&e.word) == normalize_word(&word.word))
+            .map(|e| (e, ((e.start + e.end) / 2.0 - midpoint).abs()))
+            .filter(|(_, distance)| *distance <= SPEAKER_MATCH_SECS)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        if let (Some(new), Some((same, _))) = (word.speaker, same_word)
+            && let Some(old) = same.speaker
+        {
+            match votes.iter_mut().find(|(pair, _)| *pair == (new, old)) {
+                Some((_, count)) => *count += 1,
+                None => votes.push(((new, old), 1)),
+            }
+        }
+    }
+
+    // Strongest pairs first; each speaker is matched at most once
+    votes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut mapping: Vec<(u32, u32)> = Vec::new();
+    for ((new, old), _) in votes {
+        if !mapping.iter().any(|(n, o)| *n == new || *o == old) {
+            mapping.push((new, old));
+        }
+    }
+
+    let used = merged
+        .words
+        .iter()
+        .filter_map(|w| w.speaker)
+        .chain(merged.segments.iter().filter_map(|s| s.speaker))
+        .chain(mapping.iter().map(|(_, old)| *old));
+    let mut next = used.max().map_or(0, |max| max + 1);
+    let mut speakers: Vec<u32> = result
+        .words
+        .iter()
+        .filter_map(|w| w.speaker)
+        .chain(result.segments.iter().filter_map(|s| s.speaker))
+        .collect();
+    speakers.sort_unstable();
+    speakers.dedup();
+    for speaker in speakers {
+        if !mapping.iter().any(|(new, _)| *new == speaker) {
+            mapping.push((speaker, next));
+            next += 1;
+        }
+    }
+
+    let relabel = |speaker: &mut Option<u32>| {
+        if let Some(id) = speaker
+            && let Some((_, old)) = mapping.iter().find(|(new, _)| *new == *id)
+        {
+            *id = *old;
+        }
+    };
+    result
+        .words
+        .iter_mut()
+        .for_each(|w| relabel(&mut w.speaker));
+    result
+        .segments
+        .iter_mut()
+        .for_each(|s| relabel(&mut s.speaker));
+}
+
+/// Word text for matching across chunks (case and punctuation ignored)
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Append timed items from a new chunk, dropping duplicates around the overlap cut
+fn merge_timed<T>(
+    merged: &mut Vec<T>,
+    new: Vec<T>,
+    cut: Option<f64>,
+    span: impl Fn(&T) -> (f64, f64),
+) {
+    let midpoint = |item: &T| {
+        let (start, end) = span(item);
+        (start + end) / 2.0
+    };
+
+    match cut {
+        Some(cut) => {
+            merged.retain(|item| midpoint(item) < cut);
+            merged.extend(new.into_iter().filter(|item| midpoint(item) >= cut));
+        }
+        None => merged.extend(new),
+    }
+}
+
 /// Remove overlapping text from the beginning of new_text that matches end of existing_text
 fn remove_overlap(existing: &str, new_text: &str) -> String {
     let existing_words: Vec<&str> = existing.split_whitespace().collect();
@@ -116,126 +366,782 @@ fn remove_overlap(existing: &str, new_text: &str) -> String {
 
 use crate::audio::chunker::AudioChunk as ProgressiveChunk;
 
+/// A provider to try when the primary fails on a chunk
+#[derive(Debug, Clone, PartialEq)]
+pub struct FallbackProvider {
+    pub provider: TranscriptionProvider,
+    /// API key (model path for local providers, may be empty for custom endpoints)
+    pub api_key: String,
+    /// Model override for cloud providers (None = the provider's default model)
+    pub model: Option<String>,
+}
+
+/// Options applied to every chunk of a progressive cloud transcription
+#[derive(Debug, Clone, Default)]
+pub struct CloudTranscriptionOptions {
+    /// Optional language hint
+    pub language: Option<String>,
+    /// Model override for the primary provider (None = its default model);
+    /// fallbacks carry their own
+    pub model: Option<String>,
+    /// Endpoint used when the primary or a fallback is the custom provider
+    pub custom_endpoint: Option<CustomEndpointConfig>,
+    /// Request speaker labels (matched across chunks by the words in their overlap)
+    pub diarize: bool,
+    /// Terms to bias recognition towards (product names, jargon)
+    pub vocabulary: Vec<String>,
+    /// Providers tried in order when a chunk fails with the primary provider
+    pub fallbacks: Vec<FallbackProvider>,
+    /// Maximum chunks transcribed at once (0 or 1 = sequential)
+    pub concurrency: usize,
+    /// Translate to English with providers that support it natively
+    /// (`TranscriptionResult::translated` tells whether it happened)
+    pub translate: bool,
+    /// Preferred upload format (None = pick per provider, see `upload_format_for`)
+    pub upload_format: Option<UploadFormat>,
+}
+
 /// Progressive transcription for cloud providers
 ///
 /// Transcribes audio chunks DURING recording (true progressive). As each 90-second
-/// chunk is produced, it's immediately sent to the API for transcription sequentially.
-/// Results are collected and merged when recording ends.
+/// chunk is produced, it's immediately sent to the API for transcription.
+/// Up to `options.concurrency` chunks are in flight at once, so a backlog of chunks
+/// (long recordings, files) doesn't serialize upload and inference time. Results
+/// are collected, put back in chunk order, and merged when recording ends.
+/// Segment and word timings are shifted by each chunk's offset, so they are absolute.
+///
+/// If the primary provider fails on a chunk (after its own retries), the chunk is
+/// retried with each of `options.fallbacks` in order, so a provider outage or a
+/// dropped network doesn't lose the whole dictation. `TranscriptionResult::chunks`
+/// records which provider produced each chunk.
 ///
 /// # Arguments
 /// * `provider` - The transcription provider to use
 /// * `api_key` - API key for the provider
-/// * `language` - Optional language hint
+/// * `options` - Language, diarization, vocabulary, fallbacks, and concurrency
 /// * `chunk_rx` - Channel receiving audio chunks during recording
 /// * `progress_callback` - Optional progress reporting
 pub async fn progressive_transcribe_cloud(
     provider: &TranscriptionProvider,
     api_key: &str,
-    language: Option<&str>,
-    mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    options: CloudTranscriptionOptions,
+    chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
+) -> Result<TranscriptionResult> {
+    let mut completed = 0;
+    let mut transcriptions = transcribe_chunk_stream(provider, api_key, &options, chunk_rx, |_| {
+        completed += 1;
+        // Total is 0 since we don't know how many more chunks will arrive
+        if let Some(ref callback) = progress_callback {
+            callback(completed, 0);
+        }
+    })
+    .await?;
+
+    // Chunks may finish out of order when transcribed in parallel
+    transcriptions.sort_by_key(|t| t.index);
+    Ok(merge_transcriptions(transcriptions))
+}
+
+/// Transcribe the chunks of fully available audio (e.g. a file), resuming
+/// from a checkpoint
+///
+/// Chunks a previous, interrupted run saved in `checkpoint` are reused and
+/// only the missing ones are sent to the provider. Every finished chunk is
+/// saved right away; the checkpoint is removed once all chunks are merged.
+/// Without a checkpoint this is `progressive_transcribe_cloud` with a known
+/// total.
+///
+/// `progress_callback` receives (completed, total) chunks, counting resumed
+/// chunks as completed. It is called once before transcription starts.
+pub async fn transcribe_chunks_resumable(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    options: CloudTranscriptionOptions,
+    chunks: Vec<ProgressiveChunk>,
+    mut checkpoint: Option<TranscriptionCheckpoint>,
     progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
-) -> Result<String> {
+) -> Result<TranscriptionResult> {
+    let total = chunks.len();
+    let mut transcriptions = match &mut checkpoint {
+        Some(checkpoint) => checkpoint.take_completed(&chunks),
+        None => Vec::new(),
+    };
+    if !transcriptions.is_empty() {
+        crate::verbose!(
+            "Resuming from checkpoint: {} of {} chunks already transcribed",
+            transcriptions.len(),
+            total
+        );
+    }
+
+    let (chunk_tx, chunk_rx) = tokio::sync::mpsc::unbounded_channel();
+    for chunk in chunks {
+        if !transcriptions.iter().any(|t| t.index == chunk.index) {
+            let _ = chunk_tx.send(chunk);
+        }
+    }
+    drop(chunk_tx);
+
+    let mut completed = transcriptions.len();
+    if let Some(ref callback) = progress_callback {
+        callback(completed, total);
+    }
+
+    let transcribed = transcribe_chunk_stream(provider, api_key, &options, chunk_rx, |t| {
+        // A checkpoint that can't be saved only costs the ability to resume
+        if let Some(checkpoint) = &mut checkpoint
+            && let Err(e) = checkpoint.record(t)
+        {
+            crate::verbose!("Failed to save transcription checkpoint: {:#}", e);
+        }
+        completed += 1;
+        if let Some(ref callback) = progress_callback {
+            callback(completed, total);
+        }
+    })
+    .await?;
+
+    transcriptions.extend(transcribed);
+    transcriptions.sort_by_key(|t| t.index);
+    let merged = merge_transcriptions(transcriptions);
+
+    if let Some(checkpoint) = checkpoint {
+        checkpoint.remove();
+    }
+    Ok(merged)
+}
+
+/// Transcribe chunks from `chunk_rx` with bounded parallelism
+///
+/// `on_chunk_done` runs for every successful chunk as it finishes. When a
+/// chunk fails, no new chunks are started but the ones already in flight
+/// are finished (their uploads are paid for), then the first error is
+/// returned. Results come back in completion order.
+async fn transcribe_chunk_stream(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    options: &CloudTranscriptionOptions,
+    mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    mut on_chunk_done: impl FnMut(&ChunkTranscription),
+) -> Result<Vec<ChunkTranscription>> {
     let client = get_http_client()?;
-    let provider_impl = registry().get_by_kind(provider)?;
+    let primary = FallbackProvider {
+        provider: provider.clone(),
+        api_key: api_key.to_string(),
+        model: options.model.clone(),
+    };
+    let mut limit = ConcurrencyLimit::new(options.concurrency);
+    let mut in_flight = FuturesUnordered::new();
     let mut transcriptions = Vec::new();
-    let mut chunk_count = 0;
+    let mut channel_open = true;
+    let mut failure = None;
 
-    // Process chunks sequentially as they arrive (true progressive)
-    while let Some(chunk) = chunk_rx.recv().await {
-        chunk_count += 1;
-        let chunk_index = chunk.index;
-        let has_leading_overlap = chunk.has_leading_overlap;
-
-        // Convert samples to MP3
-        let mp3_data =
-            samples_to_mp3(&chunk.samples).context("Failed to encode audio chunk to MP3")?;
-
-        let request = TranscriptionRequest {
-            audio_data: mp3_data,
-            language: language.map(|s| s.to_string()),
-            filename: format!("audio_chunk_{chunk_index}.mp3"),
-            mime_type: "audio/mpeg".to_string(),
-            progress: None,
-        };
+    loop {
+        let can_start = channel_open && failure.is_none() && in_flight.len() < limit.current();
+        if !can_start && in_flight.is_empty() {
+            break;
+        }
 
-        let result = provider_impl
-            .transcribe_async(client, api_key, request)
-            .await
-            .with_context(|| format!("Failed to transcribe chunk {chunk_index}"))?;
+        tokio::select! {
+            // Start the next chunk as soon as there is a free slot
+            chunk = chunk_rx.recv(), if can_start => match chunk {
+                Some(chunk) => in_flight.push(transcribe_with_fallbacks(
+                    client, &primary, chunk, options,
+                )),
+                None => channel_open = false,
+            },
 
-        transcriptions.push(ChunkTranscription {
-            index: chunk_index,
-            text: result.text,
-            has_leading_overlap,
-        });
+            Some(transcription) = in_flight.next(), if !in_flight.is_empty() => {
+                match transcription {
+                    Ok(transcription) => {
+                        on_chunk_done(&transcription);
+                        limit.on_chunk_done(transcription.result.rate_limited);
+                        transcriptions.push(transcription);
+                    }
+                    Err(e) => {
+                        failure.get_or_insert(e);
+                    }
+                }
+            }
+        }
+    }
 
-        // Progress reporting (total unknown until channel closes)
-        if let Some(ref callback) = progress_callback {
-            callback(chunk_count, 0); // Total is 0 since we don't know how many more chunks will arrive
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(transcriptions),
+    }
+}
+
+/// Adaptive limit on chunks in flight
+///
+/// Halves when a chunk was rate limited, then grows back by one after each
+/// run of `current` chunks that completes without rate limits.
+struct ConcurrencyLimit {
+    max: usize,
+    current: usize,
+    clean_completions: usize,
+}
+
+impl ConcurrencyLimit {
+    fn new(max: usize) -> Self {
+        let max = max.max(1);
+        Self {
+            max,
+            current: max,
+            clean_completions: 0,
         }
     }
 
-    // Results are already in correct order (sequential processing, no sorting needed)
-    Ok(merge_transcriptions(transcriptions))
+    fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Adjust the limit after a chunk completes with `rate_limited` retried 429s
+    fn on_chunk_done(&mut self, rate_limited: u32) {
+        if rate_limited > 0 {
+            self.clean_completions = 0;
+            let lowered = (self.current / 2).max(1);
+            if lowered < self.current {
+                crate::verbose!(
+                    "Rate limited, lowering chunk concurrency {} -> {}",
+                    self.current,
+                    lowered
+                );
+                self.current = lowered;
+            }
+        } else if self.current < self.max {
+            self.clean_completions += 1;
+            if self.clean_completions >= self.current {
+                self.clean_completions = 0;
+                self.current += 1;
+                crate::verbose!("Raising chunk concurrency to {}", self.current);
+            }
+        }
+    }
+}
+
+/// Transcribe one chunk with the primary provider, then each fallback in order
+async fn transcribe_with_fallbacks(
+    client: &reqwest::Client,
+    primary: &FallbackProvider,
+    mut chunk: ProgressiveChunk,
+    options: &CloudTranscriptionOptions,
+) -> Result<ChunkTranscription> {
+    let chunk_index = chunk.index;
+    // Streamed chunks arrive already encoded
+    let mut encoded = chunk.encoded.take();
+    let mut last_error = None;
+
+    for candidate in std::iter::once(primary).chain(&options.fallbacks) {
+        match transcribe_chunk(client, candidate, &chunk, &mut encoded, options).await {
+            Ok(result) => {
+                if candidate.provider != primary.provider {
+                    crate::verbose!(
+                        "Chunk {} transcribed by fallback provider {}",
+                        chunk_index,
+                        candidate.provider
+                    );
+                }
+                return Ok(ChunkTranscription::new(&chunk, result, &candidate.provider));
+            }
+            Err(e) => {
+                crate::verbose!(
+                    "Chunk {} failed with {}: {:#}",
+                    chunk_index,
+                    candidate.provider,
+                    e
+                );
+                last_error = Some(e);
+            }
+        }
+    }
+
+    let error = last_error.unwrap_or_else(|| anyhow::anyhow!("No provider to try"));
+    Err(error).with_context(|| {
+        if options.fallbacks.is_empty() {
+            format!("Failed to transcribe chunk {chunk_index}")
+        } else {
+            format!("Failed to transcribe chunk {chunk_index} with all fallback providers")
+        }
+    })
+}
+
+/// Transcribe one chunk with a single provider
+///
+/// Cloud providers get the chunk in their upload format (encoded on first use
+/// and reused across fallbacks that take the same format); local providers
+/// decode the raw samples directly.
+async fn transcribe_chunk(
+    client: &reqwest::Client,
+    target: &FallbackProvider,
+    chunk: &ProgressiveChunk,
+    encoded: &mut Option<(UploadFormat, Vec<u8>)>,
+    options: &CloudTranscriptionOptions,
+) -> Result<TranscriptionResult> {
+    if target.provider.is_local() {
+        return transcribe_chunk_local(target, chunk, options).await;
+    }
+
+    let format = upload_format_for(&target.provider, options.upload_format);
+    let audio_data = match encoded {
+        Some((cached, data)) if *cached == format => data.clone(),
+        _ => {
+            let data = encode_samples(&chunk.samples, format)
+                .with_context(|| format!("Failed to encode audio chunk to {format}"))?;
+            encoded.insert((format, data)).1.clone()
+        }
+    };
+
+    let request = TranscriptionRequest {
+        audio_data,
+        language: options.language.clone(),
+        filename: format!("audio_chunk_{}.{}", chunk.index, format.extension()),
+        mime_type: format.mime_type().to_string(),
+        diarize: options.diarize,
+        vocabulary: options.vocabulary.clone(),
+        translate: options.translate && target.provider.supports_translation(),
+        model: target.model.clone(),
+        custom_endpoint: options.custom_endpoint.clone(),
+        progress: None,
+    };
+
+    registry()
+        .get_by_kind(&target.provider)?
+        .transcribe_async(client, &target.api_key, request)
+        .await
+}
+
+/// Transcribe one chunk with a local model (`api_key` is the model path)
+#[cfg(feature = "local-transcription")]
+async fn transcribe_chunk_local(
+    target: &FallbackProvider,
+    chunk: &ProgressiveChunk,
+    options: &CloudTranscriptionOptions,
+) -> Result<TranscriptionResult> {
+    let provider = target.provider.clone();
+    let model_path = target.api_key.clone();
+    let samples = chunk.samples.clone();
+    let language = options.language.clone();
+    let vocabulary = options.vocabulary.clone();
+    let translate = options.translate;
+
+    // Run transcription in blocking task (CPU-bound work)
+    tokio::task::spawn_blocking(move || match provider {
+        TranscriptionProvider::LocalParakeet => {
+            crate::provider::transcribe_raw_parakeet(&model_path, samples)
+        }
+        _ => crate::provider::transcribe_raw(
+            &model_path,
+            &samples,
+            language.as_deref(),
+            &vocabulary,
+            translate,
+        ),
+    })
+    .await
+    .context("Transcription task panicked")?
+}
+
+#[cfg(not(feature = "local-transcription"))]
+async fn transcribe_chunk_local(
+    target: &FallbackProvider,
+    _chunk: &ProgressiveChunk,
+    _options: &CloudTranscriptionOptions,
+) -> Result<TranscriptionResult> {
+    anyhow::bail!(
+        "Provider '{}' requires the 'local-transcription' feature (not enabled in this build)",
+        target.provider
+    )
 }
 
 /// Progressive transcription for local providers (Whisper + Parakeet)
 ///
 /// Transcribes audio chunks DURING recording (true progressive). As each 90-second
-/// chunk is produced, it's immediately transcribed using the shared cached model
-/// (sequential processing). The model is loaded once and reused to minimize memory
-/// usage (constant 2GB, compared to 6GB with the previous parallel worker architecture).
+/// chunk is produced, it's immediately transcribed by the engine matching `provider`,
+/// using the shared cached model (sequential processing). The model is loaded once
+/// and reused for every chunk; when the recording ends it is unloaded or kept
+/// according to the keep-loaded setting, the same way for both engines.
 ///
 /// # Arguments
-/// * `model_path` - Path to local model directory
+/// * `provider` - `LocalWhisper` or `LocalParakeet`
+/// * `model_path` - Path to the local model (Whisper file or Parakeet directory)
+/// * `language` - Optional language hint (Whisper only, Parakeet detects it)
+/// * `vocabulary` - Terms to bias recognition towards (Whisper only)
+/// * `translate` - Translate to English (Whisper only, see `TranscriptionResult::translated`)
 /// * `chunk_rx` - Channel receiving audio chunks during recording
 /// * `progress_callback` - Optional progress reporting
 #[cfg(feature = "local-transcription")]
 pub async fn progressive_transcribe_local(
+    provider: &TranscriptionProvider,
     model_path: &str,
+    language: Option<&str>,
+    vocabulary: &[String],
+    translate: bool,
     mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
     progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
-) -> Result<String> {
+) -> Result<TranscriptionResult> {
+    if !provider.is_local() {
+        anyhow::bail!("Provider '{}' is not a local provider", provider);
+    }
+
     let mut transcriptions = Vec::new();
     let mut chunk_count = 0;
 
     // Process chunks sequentially as they arrive (true progressive)
-    while let Some(chunk) = chunk_rx.recv().await {
-        chunk_count += 1;
-        let chunk_index = chunk.index;
-        let has_leading_overlap = chunk.has_leading_overlap;
-        let samples = chunk.samples;
-        let model_path_owned = model_path.to_string();
-
-        // Run transcription in blocking task (CPU-bound work)
-        let result = tokio::task::spawn_blocking(move || {
-            crate::provider::transcribe_raw_parakeet(&model_path_owned, samples)
-        })
-        .await
-        .context("Transcription task panicked")?
-        .context("Transcription failed")?;
+    let outcome: Result<()> = async {
+        while let Some(chunk) = chunk_rx.recv().await {
+            chunk_count += 1;
+            let samples = chunk.samples.clone();
+            let engine = provider.clone();
+            let model_path_owned = model_path.to_string();
+            let language = language.map(str::to_string);
+            let vocabulary = vocabulary.to_vec();
 
-        transcriptions.push(ChunkTranscription {
-            index: chunk_index,
-            text: result.text,
-            has_leading_overlap,
-        });
+            // Run transcription in blocking task (CPU-bound work)
+            let result = tokio::task::spawn_blocking(move || {
+                crate::provider::transcribe_local_window(
+                    &engine,
+                    &model_path_owned,
+                    samples,
+                    language.as_deref(),
+                    &vocabulary,
+                    translate,
+                )
+            })
+            .await
+            .context("Transcription task panicked")?
+            .with_context(|| format!("Failed to transcribe chunk {}", chunk.index))?;
 
-        // Progress reporting (total unknown until channel closes)
-        if let Some(ref callback) = progress_callback {
-            callback(chunk_count, 0); // Total is 0 since we don't know how many more chunks will arrive
+            transcriptions.push(ChunkTranscription::new(&chunk, result, provider));
+
+            // Progress reporting (total unknown until channel closes)
+            if let Some(ref callback) = progress_callback {
+                callback(chunk_count, 0); // Total is 0 since we don't know how many more chunks will arrive
+            }
         }
+        Ok(())
     }
+    .await;
+
+    // The engine stayed loaded across chunks; apply keep-loaded once, even on error
+    crate::provider::release_local_engine(provider);
+    outcome?;
 
     // Results are already in correct order (sequential processing, no sorting needed)
     Ok(merge_transcriptions(transcriptions))
 }
 
-/// Convert f32 samples to MP3 bytes
-fn samples_to_mp3(samples: &[f32]) -> Result<Vec<u8>> {
-    use crate::audio::create_encoder;
-    let encoder = create_encoder();
-    encoder
-        .encode_samples(samples, crate::resample::WHISPER_SAMPLE_RATE)
-        .context("Failed to encode audio to MP3")
+/// Transcribe each utterance on its own (continuous dictation)
+///
+/// Chunks from `chunk_rx` (see `ProgressiveChunker::with_utterances`) are
+/// transcribed one at a time, in order, and each result is sent to
+/// `result_tx` as soon as it's ready instead of being merged. A failed
+/// utterance is reported on `result_tx` and doesn't end the session.
+/// Cloud providers try `options.fallbacks` per utterance; local models stay
+/// loaded for the whole session and keep-loaded is applied when it ends.
+///
+/// Returns when `chunk_rx` closes (the recording stopped) or nobody is
+/// receiving results anymore.
+pub async fn transcribe_utterances(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    options: CloudTranscriptionOptions,
+    mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    result_tx: tokio::sync::mpsc::UnboundedSender<Result<TranscriptionResult>>,
+) -> Result<()> {
+    let client = get_http_client()?;
+    let primary = FallbackProvider {
+        provider: provider.clone(),
+        api_key: api_key.to_string(),
+        model: options.model.clone(),
+    };
+
+    while let Some(chunk) = chunk_rx.recv().await {
+        let index = chunk.index;
+        let result = if provider.is_local() {
+            transcribe_utterance_local(provider, api_key, chunk, &options).await
+        } else {
+            transcribe_with_fallbacks(client, &primary, chunk, &options)
+                .await
+                .map(|transcription| transcription.result)
+        }
+        .with_context(|| format!("Failed to transcribe utterance {}", index + 1));
+
+        if result_tx.send(result).is_err() {
+            break;
+        }
+    }
+
+    #[cfg(feature = "local-transcription")]
+    crate::provider::release_local_engine(provider);
+
+    Ok(())
+}
+
+/// Transcribe one utterance with the session's local model
+#[cfg(feature = "local-transcription")]
+async fn transcribe_utterance_local(
+    provider: &TranscriptionProvider,
+    model_path: &str,
+    chunk: ProgressiveChunk,
+    options: &CloudTranscriptionOptions,
+) -> Result<TranscriptionResult> {
+    let engine = provider.clone();
+    let model_path = model_path.to_string();
+    let language = options.language.clone();
+    let vocabulary = options.vocabulary.clone();
+    let translate = options.translate;
+
+    // Run transcription in blocking task (CPU-bound work)
+    tokio::task::spawn_blocking(move || {
+        crate::provider::transcribe_local_window(
+            &engine,
+            &model_path,
+            chunk.samples,
+            language.as_deref(),
+            &vocabulary,
+            translate,
+        )
+    })
+    .await
+    .context("Transcription task panicked")?
+}
+
+#[cfg(not(feature = "local-transcription"))]
+async fn transcribe_utterance_local(
+    provider: &TranscriptionProvider,
+    _model_path: &str,
+    _chunk: ProgressiveChunk,
+    _options: &CloudTranscriptionOptions,
+) -> Result<TranscriptionResult> {
+    anyhow::bail!(
+        "Provider '{}' requires the 'local-transcription' feature (not enabled in this build)",
+        provider
+    )
+}
+
+/// Encode 16kHz f32 samples for upload
+fn encode_samples(samples: &[f32], format: UploadFormat) -> Result<Vec<u8>> {
+    create_encoder_for(format)?.encode_samples(samples, crate::resample::WHISPER_SAMPLE_RATE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::{TranscriptionSegment, TranscriptionWord};
+
+    fn word(word: &str, start: f64, end: f64) -> TranscriptionWord {
+        TranscriptionWord {
+            start,
+            end,
+            word: word.to_string(),
+            speaker: None,
+            confidence: None,
+        }
+    }
+
+    fn chunk(
+        index: usize,
+        start_secs: f64,
+        end_secs: f64,
+        text: &str,
+        words: Vec<TranscriptionWord>,
+    ) -> ChunkTranscription {
+        ChunkTranscription {
+            index,
+            result: TranscriptionResult {
+                text: text.to_string(),
+                segments: Vec::new(),
+                words,
+                ..Default::default()
+            },
+            has_leading_overlap: index > 0,
+            start_secs,
+            end_secs,
+        }
+    }
+
+    #[test]
+    fn test_concurrency_limit_backs_off_and_recovers() {
+        let mut limit = ConcurrencyLimit::new(4);
+
+        limit.on_chunk_done(1);
+        assert_eq!(limit.current(), 2);
+        limit.on_chunk_done(2);
+        assert_eq!(limit.current(), 1);
+        limit.on_chunk_done(1);
+        assert_eq!(limit.current(), 1);
+
+        // One clean run of `current` chunks raises the limit by one
+        limit.on_chunk_done(0);
+        assert_eq!(limit.current(), 2);
+        limit.on_chunk_done(0);
+        limit.on_chunk_done(0);
+        assert_eq!(limit.current(), 3);
+    }
+
+    #[test]
+    fn test_merge_keeps_absolute_word_times_across_overlap() {
+        // Chunk 1 starts at 8s (2s overlap with chunk 0, which ends at 10s)
+        let merged = merge_transcriptions(vec![
+            chunk(
+                0,
+                0.0,
+                10.0,
+                "hello there general",
+                vec![
+                    word("hello", 1.0, 1.5),
+                    word("there", 8.2, 8.6),
+                    word("general", 9.5, 9.9),
+                ],
+            ),
+            chunk(
+                1,
+                8.0,
+                15.0,
+                "there general kenobi",
+                vec![
+                    word("there", 8.2, 8.6),
+                    word("general", 9.4, 9.9),
+                    word("kenobi", 11.0, 11.5),
+                ],
+            ),
+        ]);
+
+        assert_eq!(merged.text, "hello there general kenobi");
+        let words: Vec<_> = merged
+            .words
+            .iter()
+            .map(|w| (w.word.as_str(), w.start))
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                ("hello", 1.0),
+                ("there", 8.2),
+                ("general", 9.4),
+                ("kenobi", 11.0)
+            ]
+        );
+    }
+
+    fn spoken(word: &str, start: f64, end: f64, speaker: u32) -> TranscriptionWord {
+        TranscriptionWord {
+            speaker: Some(speaker),
+            ..self::word(word, start, end)
+        }
+    }
+
+    #[test]
+    fn test_merge_matches_speakers_across_overlap() {
+        // Chunk 1 numbers the speakers the other way round
+        let first = chunk(
+            0,
+            0.0,
+            10.0,
+            "hi there yes sure",
+            vec![
+                spoken("hi", 1.0, 1.3, 0),
+                spoken("there", 1.4, 1.8, 0),
+                spoken("yes", 8.3, 8.6, 1),
+                spoken("sure", 9.2, 9.6, 1),
+            ],
+        );
+        let mut second = chunk(
+            1,
+            8.0,
+            15.0,
+            "yes sure bye",
+            vec![
+                spoken("Yes,", 8.35, 8.6, 0),
+                spoken("sure", 9.2, 9.5, 0),
+                spoken("bye", 12.0, 12.4, 1),
+            ],
+        );
+        second.result.segments = vec![TranscriptionSegment {
+            start: 9.2,
+            end: 9.5,
+            text: "sure".to_string(),
+            speaker: Some(0),
+            confidence: None,
+        }];
+
+        let merged = merge_transcriptions(vec![first, second]);
+
+        let speakers: Vec<_> = merged
+            .words
+            .iter()
+            .map(|w| (w.word.as_str(), w.speaker))
+            .collect();
+        assert_eq!(
+            speakers,
+            vec![
+                ("hi", Some(0)),
+                ("there", Some(0)),
+                ("yes", Some(1)),
+                ("sure", Some(1)),
+                // Not heard in the overlap, so it gets a new label
+                ("bye", Some(2)),
+            ]
+        );
+        assert_eq!(merged.segments[0].speaker, Some(1));
+    }
+
+    #[test]
+    fn test_chunks_record_the_provider_that_transcribed_them() {
+        let audio = |index: usize, start_secs: usize, secs: usize| ProgressiveChunk {
+            index,
+            samples: vec![0.0; secs * 16_000],
+            has_leading_overlap: index > 0,
+            start_sample: start_secs * 16_000,
+            encoded: None,
+        };
+        let first = ChunkTranscription::new(
+            &audio(0, 0, 10),
+            TranscriptionResult::from_text("one"),
+            &TranscriptionProvider::OpenAI,
+        );
+        let second = ChunkTranscription::new(
+            &audio(1, 8, 7),
+            TranscriptionResult::from_text("two"),
+            &TranscriptionProvider::Groq,
+        );
+
+        let merged = merge_transcriptions(vec![first, second]);
+
+        assert_eq!(
+            merged.chunks,
+            vec![
+                ChunkSource {
+                    index: 0,
+                    start: 0.0,
+                    end: 10.0,
+                    provider: TranscriptionProvider::OpenAI,
+                },
+                ChunkSource {
+                    index: 1,
+                    start: 8.0,
+                    end: 15.0,
+                    provider: TranscriptionProvider::Groq,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_picks_language_spoken_longest() {
+        let mut first = chunk(0, 0.0, 10.0, "hallo", Vec::new());
+        first.result.detected_language = Some("de".to_string());
+        first.result.confidence = Some(0.5);
+        let mut second = chunk(1, 8.0, 38.0, "hello there", Vec::new());
+        second.result.detected_language = Some("en".to_string());
+        second.result.confidence = Some(0.9);
+
+        let merged = merge_transcriptions(vec![first, second]);
+
+        assert_eq!(merged.detected_language.as_deref(), Some("en"));
+        let confidence = merged.confidence.unwrap();
+        assert!((confidence - 0.8).abs() < 1e-9);
+    }
 }
@@ -6,7 +6,7 @@
 use super::save_settings_to_store;
 use crate::state::AppState;
 use tauri::{AppHandle, State};
-use whis_core::preset::{Preset, PresetSource};
+use whis_core::preset::{Preset, PresetSource, PresetVad};
 
 /// Preset info for the UI
 #[derive(serde::Serialize)]
@@ -24,6 +24,9 @@ pub struct PresetDetails {
     pub prompt: String,
     pub post_processor: Option<String>,
     pub model: Option<String>,
+    pub vocabulary: Vec<String>,
+    pub translate: bool,
+    pub vad: Option<PresetVad>,
     pub is_builtin: bool,
 }
 
@@ -35,6 +38,12 @@ pub struct CreatePresetInput {
     pub prompt: String,
     pub post_processor: Option<String>,
     pub model: Option<String>,
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+    #[serde(default)]
+    pub translate: bool,
+    #[serde(default)]
+    pub vad: Option<PresetVad>,
 }
 
 /// Input for updating an existing preset
@@ -44,6 +53,12 @@ pub struct UpdatePresetInput {
     pub prompt: String,
     pub post_processor: Option<String>,
     pub model: Option<String>,
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+    #[serde(default)]
+    pub translate: bool,
+    #[serde(default)]
+    pub vad: Option<PresetVad>,
 }
 
 /// List all available presets (built-in + user)
@@ -116,6 +131,10 @@ pub async fn set_active_preset(
         settings.clone()
     };
     save_settings_to_store(&app, &settings_clone)?;
+
+    // Preset vocabulary and translation are part of the cached transcription config
+    *state.transcription_config.lock().unwrap() = None;
+
     Ok(())
 }
 
@@ -130,6 +149,9 @@ pub fn get_preset_details(name: String) -> Result<PresetDetails, String> {
         prompt: preset.prompt,
         post_processor: preset.post_processor,
         model: preset.model,
+        vocabulary: preset.vocabulary,
+        translate: preset.translate,
+        vad: preset.vad,
         is_builtin: source == PresetSource::BuiltIn,
     })
 }
@@ -152,6 +174,9 @@ pub fn create_preset(input: CreatePresetInput) -> Result<PresetInfo, String> {
         prompt: input.prompt,
         post_processor: input.post_processor,
         model: input.model,
+        vocabulary: input.vocabulary,
+        translate: input.translate,
+        vad: input.vad,
     };
 
     preset.save()?;
@@ -165,7 +190,11 @@ pub fn create_preset(input: CreatePresetInput) -> Result<PresetInfo, String> {
 
 /// Update an existing user preset
 #[tauri::command]
-pub fn update_preset(name: String, input: UpdatePresetInput) -> Result<PresetInfo, String> {
+pub fn update_preset(
+    name: String,
+    input: UpdatePresetInput,
+    state: State<'_, AppState>,
+) -> Result<PresetInfo, String> {
     // Check it's not a built-in
     if Preset::is_builtin(&name) {
         return Err(format!("Cannot edit built-in preset '{}'", name));
@@ -179,10 +208,16 @@ pub fn update_preset(name: String, input: UpdatePresetInput) -> Result<PresetInf
     preset.prompt = input.prompt;
     preset.post_processor = input.post_processor;
     preset.model = input.model;
+    preset.vocabulary = input.vocabulary;
+    preset.translate = input.translate;
+    preset.vad = input.vad;
 
     // Save
     preset.save()?;
 
+    // Preset vocabulary and translation are part of the cached transcription config
+    *state.transcription_config.lock().unwrap() = None;
+
     Ok(PresetInfo {
         name,
         description: input.description,
@@ -46,11 +46,20 @@ pub async fn save_settings(
         (
             current.transcription.provider != settings.transcription.provider
                 || current.transcription.api_keys != settings.transcription.api_keys
+                || current.transcription.models != settings.transcription.models
                 || current.transcription.language != settings.transcription.language
                 || current.transcription.local_models.whisper_path
                     != settings.transcription.local_models.whisper_path
                 || current.transcription.local_models.parakeet_path
-                    != settings.transcription.local_models.parakeet_path,
+                    != settings.transcription.local_models.parakeet_path
+                || current.transcription.local_models.streaming
+                    != settings.transcription.local_models.streaming
+                || current.transcription.custom_endpoint != settings.transcription.custom_endpoint
+                || current.transcription.vocabulary != settings.transcription.vocabulary
+                || current.transcription.fallback_providers
+                    != settings.transcription.fallback_providers
+                || current.transcription.translate != settings.transcription.translate
+                || current.transcription.upload_format != settings.transcription.upload_format,
             current.shortcuts.desktop_key != settings.shortcuts.desktop_key,
         )
     };
@@ -89,6 +98,7 @@ pub async fn check_config_readiness(
     whisper_model_path: Option<String>,
     parakeet_model_path: Option<String>,
     ollama_url: Option<String>,
+    custom_url: Option<String>,
 ) -> ConfigReadiness {
     // Check transcription readiness
     let (transcription_ready, transcription_error) = match provider.as_str() {
@@ -105,6 +115,14 @@ pub async fn check_config_readiness(
             ),
             None => (false, Some("Parakeet model not configured".to_string())),
         },
+        // API key is optional for custom endpoints, only the URL is required
+        "custom" => match custom_url.as_deref().map(str::trim) {
+            Some(url) if !url.is_empty() => (true, None),
+            _ => (
+                false,
+                Some("Custom endpoint URL not configured".to_string()),
+            ),
+        },
         provider => {
             // Normalize provider for API key lookup (realtime variants share keys)
             let key_provider = match provider {
@@ -188,6 +206,13 @@ pub fn get_defaults() -> serde_json::Value {
         "desktop_key": DEFAULT_SHORTCUT,
         "vad_enabled": DEFAULT_VAD_ENABLED,
         "vad_threshold": DEFAULT_VAD_THRESHOLD,
+        "vad_prefill_frames": DEFAULT_VAD_PREFILL_FRAMES,
+        "vad_onset_frames": DEFAULT_VAD_ONSET_FRAMES,
+        "vad_hangover_frames": DEFAULT_VAD_HANGOVER_FRAMES,
+        "denoise_enabled": DEFAULT_DENOISE_ENABLED,
+        "denoise_strength": DEFAULT_DENOISE_STRENGTH,
+        "auto_stop_enabled": DEFAULT_AUTO_STOP_ENABLED,
+        "auto_stop_silence_secs": DEFAULT_AUTO_STOP_SILENCE_SECS,
     })
 }
 
@@ -196,20 +221,59 @@ pub fn get_defaults() -> serde_json::Value {
 pub struct CloudProviderOption {
     pub value: String,
     pub label: String,
+    /// Model choices (None for the custom endpoint, which has its own model field)
+    pub models: Option<ProviderModels>,
+    /// Model choices for the realtime variant, if the provider has one
+    pub realtime_models: Option<ProviderModels>,
+}
+
+/// Model choices for one provider
+#[derive(serde::Serialize)]
+pub struct ProviderModels {
+    /// Provider key in `transcription.models`
+    pub provider: String,
+    /// Model used when no override is set
+    pub default_model: String,
+    /// Known models (any other name can be entered)
+    pub known: Vec<String>,
+}
+
+impl ProviderModels {
+    fn for_provider(provider: &whis_core::TranscriptionProvider) -> Option<Self> {
+        Some(Self {
+            provider: provider.as_str().to_string(),
+            default_model: provider.default_model()?.to_string(),
+            known: provider
+                .known_models()
+                .iter()
+                .map(|m| m.to_string())
+                .collect(),
+        })
+    }
 }
 
 /// Get cloud providers in recommended order
 ///
 /// Returns cloud providers (excluding local and realtime variants) in the
-/// order defined by TranscriptionProvider::all() for consistent UI display.
+/// order defined by TranscriptionProvider::all() for consistent UI display,
+/// with the known models of each provider and its realtime variant.
 #[tauri::command]
 pub fn get_cloud_providers() -> Vec<CloudProviderOption> {
     use whis_core::TranscriptionProvider;
 
     TranscriptionProvider::cloud_providers()
-        .map(|p| CloudProviderOption {
-            value: p.as_str().to_string(),
-            label: p.display_name().to_string(),
+        .map(|p| {
+            let realtime = match p {
+                TranscriptionProvider::OpenAI => Some(TranscriptionProvider::OpenAIRealtime),
+                TranscriptionProvider::Deepgram => Some(TranscriptionProvider::DeepgramRealtime),
+                _ => None,
+            };
+            CloudProviderOption {
+                value: p.as_str().to_string(),
+                label: p.display_name().to_string(),
+                models: ProviderModels::for_provider(p),
+                realtime_models: realtime.as_ref().and_then(ProviderModels::for_provider),
+            }
         })
         .collect()
 }
@@ -3,10 +3,10 @@
 //! Handles loading and validation of transcription configuration from settings.
 
 use crate::state::{AppState, TranscriptionConfig};
-use whis_core::TranscriptionProvider;
+use whis_core::{Preset, TranscriptionProvider};
 
 /// Load transcription configuration from settings
-/// Returns error if required API key or model path is missing
+/// Returns error if required API key, endpoint URL, or model path is missing
 pub fn load_transcription_config(state: &AppState) -> Result<TranscriptionConfig, String> {
     let settings = state.settings.lock().unwrap();
     let provider = settings.transcription.provider.clone();
@@ -21,6 +21,21 @@ pub fn load_transcription_config(state: &AppState) -> Result<TranscriptionConfig
             .transcription
             .parakeet_model_path()
             .ok_or_else(|| "Parakeet model not configured. Add it in Settings.".to_string())?,
+        TranscriptionProvider::Custom => {
+            if settings
+                .transcription
+                .custom_endpoint
+                .transcription_url()
+                .is_none()
+            {
+                return Err("Custom endpoint URL not configured. Add it in Settings.".to_string());
+            }
+            // API key is optional for self-hosted endpoints
+            settings
+                .transcription
+                .api_key_from_settings()
+                .unwrap_or_default()
+        }
         _ => settings
             .transcription
             .api_key_from_settings()
@@ -29,9 +44,39 @@ pub fn load_transcription_config(state: &AppState) -> Result<TranscriptionConfig
 
     let language = settings.transcription.language.clone();
 
+    // Global vocabulary merged with the active preset's terms
+    let preset = settings
+        .ui
+        .active_preset
+        .as_deref()
+        .and_then(|name| Preset::load(name).ok())
+        .map(|(preset, _)| preset);
+    let vocabulary = settings.transcription.vocabulary_for(preset.as_ref());
+    let translate = settings.transcription.translate_for(preset.as_ref());
+
+    // Model override for the active provider (fallbacks carry their own)
+    let model = settings
+        .transcription
+        .model_override(&provider)
+        .map(str::to_string);
+
+    // Fallbacks that can't be used (missing key, URL, or model) are skipped
+    let fallbacks = settings.transcription.fallback_chain();
+    let uses_custom = provider == TranscriptionProvider::Custom
+        || fallbacks
+            .iter()
+            .any(|f| f.provider == TranscriptionProvider::Custom);
+    let custom_endpoint = uses_custom.then(|| settings.transcription.custom_endpoint.clone());
+
     Ok(TranscriptionConfig {
         provider,
         api_key,
         language,
+        vocabulary,
+        model,
+        fallbacks,
+        custom_endpoint,
+        translate,
+        local_streaming: settings.transcription.local_models.streaming,
     })
 }
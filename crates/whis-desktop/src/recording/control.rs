@@ -4,12 +4,17 @@
 
 use super::config::load_transcription_config;
 use crate::state::{AppState, RecordingState};
-use tauri::AppHandle;
-use tokio::sync::{mpsc, oneshot};
+#[cfg(feature = "realtime")]
+use tauri::Emitter;
+use tauri::{AppHandle, Manager};
+use tokio::sync::{mpsc, oneshot, watch};
+#[cfg(feature = "realtime")]
+use whis_core::TranscriptEvent;
 #[cfg(feature = "local-transcription")]
 use whis_core::progressive_transcribe_local;
 use whis_core::{
-    AudioRecorder, ChunkerConfig, PostProcessor, ProgressiveChunker, TranscriptionProvider, info,
+    AudioRecorder, AutoStopReason, ChunkerConfig, CloudTranscriptionOptions, PostProcessor, Preset,
+    ProgressiveChunker, TranscriptionProvider, TranscriptionResult, VadConfig, info,
     progressive_transcribe_cloud,
 };
 
@@ -19,15 +24,27 @@ use whis_core::{
 /// - Progressive audio chunking (90s target, VAD-aware) for non-realtime providers
 /// - WebSocket streaming for realtime providers (deepgram-realtime, openai-realtime)
 /// - Transcription during recording (parallel for cloud providers, sequential for local providers)
+/// - Per-utterance transcription and output in continuous dictation mode
+///   (realtime providers send each utterance as a batch request to their base API)
 ///
 /// The transcription result will be available via the oneshot channel
 /// stored in AppState when recording completes.
-pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), String> {
+pub fn start_recording_sync(app: &AppHandle, state: &AppState) -> Result<(), String> {
     // Cancel any pending idle model unload (user is recording again)
     state.cancel_idle_unload();
 
     // Load transcription config if not already loaded
-    let (provider, api_key, language) = {
+    let (
+        provider,
+        api_key,
+        language,
+        model,
+        vocabulary,
+        fallbacks,
+        custom_endpoint,
+        translate,
+        local_streaming,
+    ) = {
         let mut config_guard = state.transcription_config.lock().unwrap();
         if config_guard.is_none() {
             *config_guard = Some(load_transcription_config(state)?);
@@ -37,18 +54,39 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
             config.provider.clone(),
             config.api_key.clone(),
             config.language.clone(),
+            config.model.clone(),
+            config.vocabulary.clone(),
+            config.fallbacks.clone(),
+            config.custom_endpoint.clone(),
+            config.translate,
+            config.local_streaming,
         )
     };
 
     // Check if this is a realtime provider (for branching later)
-    let is_realtime = whis_core::is_realtime_provider(&provider);
+    let is_realtime = whis_core::is_realtime_provider(&provider, local_streaming);
 
     // Extract all needed settings values in a single lock acquisition
     let settings = state.settings.lock().unwrap();
-    let vad_enabled = settings.ui.vad.enabled && !is_realtime;
-    let vad_threshold = settings.ui.vad.threshold;
+    // Continuous dictation splits utterances with VAD, so it takes precedence
+    // over streaming (realtime providers transcribe utterances via batch requests)
+    let continuous = settings.ui.continuous.enabled;
+    // The active preset can override VAD tuning
+    let preset = settings
+        .ui
+        .active_preset
+        .as_deref()
+        .and_then(|name| Preset::load(name).ok())
+        .map(|(preset, _)| preset);
+    let vad = settings.ui.vad.for_preset(preset.as_ref()).config();
+    let vad_enabled = continuous || (vad.enabled && !is_realtime);
+    let denoise_enabled = settings.ui.denoise.enabled;
+    let denoise_strength = settings.ui.denoise.strength;
+    let auto_stop = settings.ui.auto_stop.config();
     let device_name = settings.ui.microphone_device.clone();
     let chunk_duration = settings.ui.chunk_duration_secs;
+    let chunk_concurrency = settings.ui.chunk_concurrency;
+    let upload_format = settings.transcription.upload_format;
     #[cfg(feature = "local-transcription")]
     let keep_loaded = settings.ui.model_memory.keep_model_loaded;
     #[cfg(feature = "local-transcription")]
@@ -65,13 +103,30 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
     // Create recorder and start streaming
     let mut recorder = AudioRecorder::new().map_err(|e| e.to_string())?;
-    recorder.set_vad(vad_enabled, vad_threshold);
+    recorder.set_vad_config(VadConfig {
+        enabled: vad_enabled,
+        ..vad
+    });
+    recorder.set_denoise(denoise_enabled, denoise_strength);
+    if !continuous {
+        recorder.set_auto_stop(auto_stop);
+    }
 
     // Start streaming recording
     let mut audio_rx_bounded = recorder
         .start_recording_streaming_with_device(device_name.as_deref())
         .map_err(|e| e.to_string())?;
 
+    // Drive the level meters in the window and bubble
+    if let Some(level_rx) = recorder.level_receiver() {
+        super::level::spawn_level_events(app, level_rx);
+    }
+
+    // Stop by itself once the speaker goes quiet
+    if let Some(auto_stop_rx) = recorder.auto_stop_receiver() {
+        spawn_auto_stop(app, auto_stop_rx);
+    }
+
     // Create unbounded channel adapter (used by both realtime and chunked paths)
     let (audio_tx_unbounded, audio_rx_unbounded) = mpsc::unbounded_channel();
     tauri::async_runtime::spawn(async move {
@@ -109,18 +164,120 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
     // Warm HTTP client for cloud providers to reduce first-request latency
     let _ = whis_core::warmup_http_client();
 
-    // Branch based on provider type: realtime streaming vs chunked progressive
-    if is_realtime {
+    // Branch on mode: continuous dictation, realtime streaming or chunked progressive
+    if continuous {
+        // CONTINUOUS PATH: one chunk per utterance, each output when transcribed
+        let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
+
+        let mut chunker =
+            ProgressiveChunker::new(ChunkerConfig::utterances(), chunk_tx).with_utterances();
+        if !provider.is_local() {
+            chunker = chunker.with_streaming_encoder(whis_core::audio::upload_format_for(
+                &provider,
+                upload_format,
+            ));
+        }
+        if is_realtime {
+            info!(
+                "Continuous dictation with {}: utterances are sent as batch requests",
+                provider.display_name()
+            );
+        }
+
+        // A failed chunker is reported like a failed utterance and ends the session
+        let (utterance_tx, mut utterance_rx) = mpsc::unbounded_channel();
+        let vad_state_rx = recorder.vad_state_receiver();
+        let chunker_tx = utterance_tx.clone();
+        let chunker_app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = chunker
+                .consume_stream(audio_rx_unbounded, vad_state_rx)
+                .await
+            {
+                let _ = chunker_tx.send(Err(anyhow::anyhow!("Audio processing failed: {e}")));
+                let state = *chunker_app.state::<AppState>().state.lock().unwrap();
+                if state == RecordingState::Recording {
+                    super::toggle_recording(&chunker_app);
+                }
+            }
+        });
+
+        let options = CloudTranscriptionOptions {
+            language,
+            model,
+            custom_endpoint,
+            diarize: false,
+            vocabulary,
+            fallbacks,
+            concurrency: 1,
+            translate,
+            upload_format,
+        };
+
+        // Transcribe utterances in order
+        tauri::async_runtime::spawn(async move {
+            let result = whis_core::transcribe_utterances(
+                &provider,
+                &api_key,
+                options,
+                chunk_rx,
+                utterance_tx.clone(),
+            )
+            .await;
+            if let Err(e) = result {
+                let _ = utterance_tx.send(Err(e));
+            }
+        });
+
+        // Output each utterance as soon as it's transcribed
+        let (done_tx, done_rx) = oneshot::channel();
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            while let Some(result) = utterance_rx.recv().await {
+                let result = result.map_err(|e| format!("{e:#}"));
+                super::pipeline::deliver_utterance(&app_handle, result).await;
+            }
+            super::pipeline::finish_continuous(&app_handle);
+            let _ = done_tx.send(());
+        });
+        *state.continuous_done.lock().unwrap() = Some(done_rx);
+
+        info!("Recording started (continuous dictation)");
+    } else if is_realtime {
         // REALTIME PATH: Stream audio directly to WebSocket (no chunking)
         #[cfg(feature = "realtime")]
         {
-            let realtime_backend =
-                whis_core::get_realtime_backend(&provider).map_err(|e| e.to_string())?;
+            let realtime_backend = whis_core::get_realtime_backend(
+                &provider,
+                model,
+                &vocabulary,
+                translate,
+                local_streaming,
+            )
+            .map_err(|e| e.to_string())?;
+
+            // Forward partial/final segments to the main window and bubble
+            let (events_tx, mut events_rx) = mpsc::unbounded_channel::<TranscriptEvent>();
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                while let Some(event) = events_rx.recv().await {
+                    let _ = app_handle.emit("live-transcript", &event);
+                }
+            });
 
             tauri::async_runtime::spawn(async move {
                 let result = realtime_backend
-                    .transcribe_stream(&api_key, audio_rx_unbounded, language)
+                    .transcribe_stream_with_events(
+                        &api_key,
+                        audio_rx_unbounded,
+                        language,
+                        Some(events_tx),
+                    )
                     .await
+                    .map(|text| TranscriptionResult {
+                        translated: realtime_backend.translates(),
+                        ..TranscriptionResult::from_text(text)
+                    })
                     .map_err(|e| e.to_string());
                 let _ = result_tx.send(result);
             });
@@ -130,6 +287,7 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
         #[cfg(not(feature = "realtime"))]
         {
+            let _ = app;
             return Err(format!(
                 "Provider '{}' requires the 'realtime' feature (not enabled in this build)",
                 provider.as_str()
@@ -147,47 +305,65 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
             vad_aware: vad_enabled,
         };
 
-        // Spawn chunker task
+        // Spawn chunker task (cloud chunks are encoded while they're recorded)
         let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
-        tauri::async_runtime::spawn(async move {
-            let _ = chunker.consume_stream(audio_rx_unbounded, None).await;
+        if !provider.is_local() {
+            chunker = chunker.with_streaming_encoder(whis_core::audio::upload_format_for(
+                &provider,
+                upload_format,
+            ));
+        }
+        let chunker_task = tauri::async_runtime::spawn(async move {
+            chunker.consume_stream(audio_rx_unbounded, None).await
         });
 
+        // Fallback providers are tried per chunk if the primary fails
+        let options = CloudTranscriptionOptions {
+            language,
+            model,
+            custom_endpoint,
+            diarize: false,
+            vocabulary,
+            fallbacks,
+            concurrency: chunk_concurrency,
+            translate,
+            upload_format,
+        };
+
         // Spawn transcription task
         tauri::async_runtime::spawn(async move {
-            let result: Result<String, String> = {
+            let result: Result<TranscriptionResult, String> = {
                 #[cfg(feature = "local-transcription")]
-                if provider == TranscriptionProvider::LocalParakeet {
-                    match parakeet_model_path {
-                        Some(model_path) => {
-                            progressive_transcribe_local(&model_path, chunk_rx, None)
-                                .await
-                                .map_err(|e| e.to_string())
-                        }
-                        None => Err("Parakeet model path not configured".to_string()),
-                    }
-                } else {
-                    progressive_transcribe_cloud(
+                if provider.is_local() {
+                    // api_key holds the model path for local providers
+                    progressive_transcribe_local(
                         &provider,
                         &api_key,
-                        language.as_deref(),
+                        options.language.as_deref(),
+                        &options.vocabulary,
+                        options.translate,
                         chunk_rx,
                         None,
                     )
                     .await
                     .map_err(|e| e.to_string())
+                } else {
+                    progressive_transcribe_cloud(&provider, &api_key, options, chunk_rx, None)
+                        .await
+                        .map_err(|e| e.to_string())
                 }
 
                 #[cfg(not(feature = "local-transcription"))]
-                progressive_transcribe_cloud(
-                    &provider,
-                    &api_key,
-                    language.as_deref(),
-                    chunk_rx,
-                    None,
-                )
-                .await
-                .map_err(|e| e.to_string())
+                progressive_transcribe_cloud(&provider, &api_key, options, chunk_rx, None)
+                    .await
+                    .map_err(|e| e.to_string())
+            };
+
+            // A failed chunker ends the chunk stream early; don't pass the
+            // partial transcript off as complete
+            let result = match (result, chunker_task.await) {
+                (Ok(_), Ok(Err(e))) => Err(format!("Audio processing failed: {e}")),
+                (result, _) => result,
             };
 
             let _ = result_tx.send(result);
@@ -203,3 +379,27 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
     Ok(())
 }
+
+/// Stop the recording when the recorder reports end of speech
+///
+/// Does nothing if the recording was already stopped by hand (the receiver
+/// closes when the recorder is dropped).
+fn spawn_auto_stop(app: &AppHandle, mut auto_stop_rx: watch::Receiver<Option<AutoStopReason>>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let reason = match auto_stop_rx.wait_for(Option::is_some).await {
+            Ok(reason) => *reason,
+            Err(_) => return,
+        };
+        let state = *app.state::<AppState>().state.lock().unwrap();
+        if state != RecordingState::Recording {
+            return;
+        }
+
+        match reason {
+            Some(AutoStopReason::NoSpeech) => info!("Auto-stop: no speech detected"),
+            _ => info!("Auto-stop: end of speech"),
+        }
+        super::toggle_recording(app);
+    });
+}
@@ -0,0 +1,40 @@
+//! Input Level Events
+//!
+//! Forwards the recorder's live input level to the main window and bubble
+//! (`audio-level`) and warns when the microphone stays silent
+//! (`microphone-silent` event and tray tooltip).
+
+use tauri::{AppHandle, Emitter};
+use tokio::sync::watch;
+use whis_core::{AudioLevel, warn};
+
+use crate::tray;
+
+/// Minimum time between `audio-level` events (~15 updates per second)
+const EMIT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(66);
+
+/// Emit level events until the recording stops (the receiver closes)
+pub fn spawn_level_events(app: &AppHandle, mut level_rx: watch::Receiver<AudioLevel>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut warned = false;
+
+        while level_rx.changed().await.is_ok() {
+            let level = *level_rx.borrow_and_update();
+            let _ = app.emit("audio-level", level);
+
+            // Warn once per recording
+            if !warned && level.mic_appears_silent() {
+                warned = true;
+                warn!(
+                    "Microphone appears silent ({:.0}s without input)",
+                    level.silent_secs
+                );
+                let _ = app.emit("microphone-silent", level.silent_secs);
+                tray::menu::show_silent_mic_warning(&app);
+            }
+
+            tokio::time::sleep(EMIT_INTERVAL).await;
+        }
+    });
+}
@@ -11,12 +11,14 @@
 //! recording/
 //! ├── config.rs      - Configuration loading from settings
 //! ├── control.rs     - Start/stop recording logic
+//! ├── level.rs       - Input level events and silent-mic warning
 //! ├── pipeline.rs    - Transcription pipeline orchestration
 //! └── mod.rs         - Public API (toggle, start, stop)
 //! ```
 
 pub mod config;
 pub mod control;
+pub mod level;
 pub mod pipeline;
 
 // Re-export public APIs
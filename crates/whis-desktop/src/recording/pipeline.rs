@@ -6,53 +6,111 @@
 //! 3. Post-process transcription (optional)
 //! 4. Copy to clipboard
 //! 5. Emit completion event
+//!
+//! Continuous dictation runs steps 3-5 for every utterance as it is
+//! transcribed (`deliver_utterance`).
 
 use crate::state::{AppState, RecordingState};
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
+use whis_core::settings::ContinuousSettings;
 use whis_core::{
     AutotypeBackend, ClipboardMethod, DEFAULT_POST_PROCESSING_PROMPT, OutputMethod,
-    PostProcessConfig, PostProcessor, TranscriptionProvider, autotype_text, copy_to_clipboard,
-    ollama, post_process, warn,
+    PostProcessConfig, PostProcessor, Preset, TranscriptionProvider, TranscriptionResult,
+    autotype_text, copy_to_clipboard, fill_language_placeholder, ollama, post_process,
+    translate_transcript, warn,
 };
 #[cfg(feature = "local-transcription")]
 use whis_core::{unload_parakeet, whisper_unload_model};
 
-/// Output text based on configured output method
-fn output_text(
-    text: &str,
-    output_method: &OutputMethod,
-    clipboard_method: &ClipboardMethod,
-    autotype_backend: &AutotypeBackend,
-    autotype_delay_ms: Option<u32>,
-) -> Result<(), String> {
-    match output_method {
-        OutputMethod::Clipboard => {
-            copy_to_clipboard(text, clipboard_method.clone()).map_err(|e| e.to_string())?;
+/// Payload of the `transcription-complete` event
+#[derive(Clone, serde::Serialize)]
+struct TranscriptionComplete<'a> {
+    /// Final text (after translation and post-processing)
+    text: &'a str,
+    /// Spoken language reported by the provider (ISO 639-1)
+    detected_language: Option<&'a str>,
+    language_confidence: Option<f64>,
+    confidence: Option<f64>,
+}
+
+impl<'a> TranscriptionComplete<'a> {
+    fn new(text: &'a str, result: &'a TranscriptionResult) -> Self {
+        Self {
+            text,
+            detected_language: result.detected_language.as_deref(),
+            language_confidence: result.language_confidence,
+            confidence: result.confidence,
         }
-        OutputMethod::Autotype => {
-            autotype_text(text, autotype_backend.clone(), autotype_delay_ms)
+    }
+}
+
+/// Where finished text goes
+struct OutputSettings {
+    output_method: OutputMethod,
+    clipboard_method: ClipboardMethod,
+    autotype_backend: AutotypeBackend,
+    autotype_delay_ms: Option<u32>,
+    /// Set for continuous dictation utterances
+    continuous: Option<ContinuousSettings>,
+}
+
+impl OutputSettings {
+    /// Output text based on configured output method
+    ///
+    /// Continuous utterances are typed with a trailing space, so consecutive
+    /// ones don't run together, and appended to the output file if set.
+    fn output(&self, text: &str) -> Result<(), String> {
+        let typed = match self.continuous {
+            Some(_) => format!("{} ", text.trim()),
+            None => text.to_string(),
+        };
+        match self.output_method {
+            OutputMethod::Clipboard => {
+                copy_to_clipboard(text, self.clipboard_method.clone())
+                    .map_err(|e| e.to_string())?;
+            }
+            OutputMethod::Autotype => {
+                autotype_text(
+                    &typed,
+                    self.autotype_backend.clone(),
+                    self.autotype_delay_ms,
+                )
                 .map_err(|e| e.to_string())?;
-        }
-        OutputMethod::Both => {
-            copy_to_clipboard(text, clipboard_method.clone()).map_err(|e| e.to_string())?;
-            autotype_text(text, autotype_backend.clone(), autotype_delay_ms)
+            }
+            OutputMethod::Both => {
+                copy_to_clipboard(text, self.clipboard_method.clone())
+                    .map_err(|e| e.to_string())?;
+                autotype_text(
+                    &typed,
+                    self.autotype_backend.clone(),
+                    self.autotype_delay_ms,
+                )
                 .map_err(|e| e.to_string())?;
+            }
+        }
+        if let Some(continuous) = &self.continuous {
+            continuous.append(text).map_err(|e| format!("{e:#}"))?;
         }
+        Ok(())
     }
-    Ok(())
 }
 
 /// Stop recording and run the full transcription pipeline (progressive mode)
 /// Guarantees state cleanup on both success and failure
 pub async fn stop_and_transcribe(app: &AppHandle) -> Result<(), String> {
     let state = app.state::<AppState>();
+    let continuous_done = state.continuous_done.lock().unwrap().take();
 
     // Stop recording (closes audio stream, signals chunker/transcription to finish)
     {
         let mut recorder = state.recorder.lock().unwrap().take();
         if let Some(ref mut rec) = recorder {
-            rec.stop_recording().map_err(|e| e.to_string())?;
+            let stopped = rec.stop_recording();
+            // A continuous session without any speech has nothing to flush
+            if continuous_done.is_none() {
+                stopped.map_err(|e| e.to_string())?;
+            }
         }
     }
 
@@ -60,6 +118,14 @@ pub async fn stop_and_transcribe(app: &AppHandle) -> Result<(), String> {
     {
         *state.state.lock().unwrap() = RecordingState::Transcribing;
     }
+
+    // Continuous dictation: wait for the remaining utterances to be delivered
+    if let Some(done) = continuous_done {
+        println!("Finishing continuous dictation...");
+        let _ = done.await;
+        *state.state.lock().unwrap() = RecordingState::Idle;
+        return Ok(());
+    }
     println!("Transcribing...");
 
     // Run transcription with guaranteed state cleanup on any error
@@ -84,18 +150,90 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
     };
 
     // Wait for transcription to complete (rx_guard dropped, so this is Send-safe)
-    let transcription = rx
+    let result = rx
         .await
         .map_err(|_| "Transcription task dropped unexpectedly".to_string())?
         .map_err(|e| format!("Transcription failed: {e}"))?;
 
+    deliver(app, state, &result, false).await?;
+
+    // Schedule idle model unload (if configured)
+    schedule_idle_model_unload(app, state);
+
+    Ok(())
+}
+
+/// Output one utterance of a continuous dictation session
+///
+/// Failures are reported to the UI (`post-process-warning`) instead of
+/// ending the session.
+pub(super) async fn deliver_utterance(
+    app: &AppHandle,
+    result: Result<TranscriptionResult, String>,
+) {
+    let state = app.state::<AppState>();
+    let outcome = match result {
+        Ok(result) if result.text.trim().is_empty() => Ok(()),
+        Ok(result) => deliver(app, &state, &result, true).await,
+        Err(e) => Err(format!("Transcription failed: {e}")),
+    };
+    if let Err(e) = outcome {
+        warn!("Continuous dictation: {e}");
+        let _ = app.emit("post-process-warning", &e);
+    }
+}
+
+/// Called when a continuous dictation session delivered its last utterance
+pub(super) fn finish_continuous(app: &AppHandle) {
+    schedule_idle_model_unload(app, &app.state::<AppState>());
+}
+
+/// Translate and post-process a transcription, output it and notify the UI
+async fn deliver(
+    app: &AppHandle,
+    state: &AppState,
+    result: &TranscriptionResult,
+    continuous: bool,
+) -> Result<(), String> {
+    let mut transcription = result.text.clone();
+
+    // Translate with the post-processor if the provider couldn't do it natively
+    let translate = state
+        .transcription_config
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|config| config.translate);
+    if translate && !result.translated {
+        println!("Translating...");
+        let settings = state.settings.lock().unwrap().clone();
+        // The active preset may choose the post-processor used for translation
+        let preset = settings
+            .ui
+            .active_preset
+            .as_deref()
+            .and_then(|name| Preset::load(name).ok())
+            .map(|(preset, _)| preset);
+        match translate_transcript(&transcription, &preset, &settings).await {
+            Ok(translated) => transcription = translated,
+            Err(e) => {
+                let warning = format!("Translation: {e:#}");
+                warn!("{warning}");
+                let _ = app.emit("post-process-warning", &warning);
+            }
+        }
+    }
+
     // Extract post-processing config and output settings from settings
-    let (post_process_config, clipboard_method, output_method, autotype_backend, autotype_delay_ms) = {
+    let (post_process_config, output) = {
         let settings = state.settings.lock().unwrap();
-        let clipboard_method = settings.ui.clipboard_backend.clone();
-        let output_method = settings.ui.output_method.clone();
-        let autotype_backend = settings.ui.autotype_backend.clone();
-        let autotype_delay_ms = settings.ui.autotype_delay_ms;
+        let output = OutputSettings {
+            output_method: settings.ui.output_method.clone(),
+            clipboard_method: settings.ui.clipboard_backend.clone(),
+            autotype_backend: settings.ui.autotype_backend.clone(),
+            autotype_delay_ms: settings.ui.autotype_delay_ms,
+            continuous: continuous.then(|| settings.ui.continuous.clone()),
+        };
         let post_process_config = if settings.post_processing.enabled
             && settings.post_processing.processor != PostProcessor::None
         {
@@ -133,13 +271,7 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
         } else {
             None
         };
-        (
-            post_process_config,
-            clipboard_method,
-            output_method,
-            autotype_backend,
-            autotype_delay_ms,
-        )
+        (post_process_config, output)
     };
 
     // Apply post-processing if configured
@@ -158,19 +290,16 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
                 let _ = app.emit("post-process-warning", &warning);
 
                 // Output based on configured method
-                output_text(
-                    &transcription,
-                    &output_method,
-                    &clipboard_method,
-                    &autotype_backend,
-                    autotype_delay_ms,
-                )?;
+                output.output(&transcription)?;
 
                 println!(
                     "Done (unprocessed): {}",
                     &transcription[..transcription.len().min(50)]
                 );
-                let _ = app.emit("transcription-complete", &transcription);
+                let _ = app.emit(
+                    "transcription-complete",
+                    TranscriptionComplete::new(&transcription, result),
+                );
                 return Ok(());
             }
 
@@ -194,11 +323,12 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
             None
         };
 
+        let prompt = fill_language_placeholder(&config.prompt, result.detected_language.as_deref());
         match post_process(
             &transcription,
             &config.processor,
             &config.api_key_or_url,
-            &config.prompt,
+            &prompt,
             model,
         )
         .await
@@ -216,21 +346,15 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
     };
 
     // Output based on configured method
-    output_text(
-        &final_text,
-        &output_method,
-        &clipboard_method,
-        &autotype_backend,
-        autotype_delay_ms,
-    )?;
+    output.output(&final_text)?;
 
     println!("Done: {}", &final_text[..final_text.len().min(50)]);
 
     // Emit event to frontend
-    let _ = app.emit("transcription-complete", &final_text);
-
-    // Schedule idle model unload (if configured)
-    schedule_idle_model_unload(app, state);
+    let _ = app.emit(
+        "transcription-complete",
+        TranscriptionComplete::new(&final_text, result),
+    );
 
     Ok(())
 }
@@ -2,16 +2,30 @@ use std::sync::Mutex;
 use tauri::menu::MenuItem;
 use tokio::sync::oneshot;
 pub use whis_core::RecordingState;
-use whis_core::{AudioRecorder, Settings, TranscriptionProvider};
+use whis_core::settings::CustomEndpointConfig;
+use whis_core::{
+    AudioRecorder, FallbackProvider, Settings, TranscriptionProvider, TranscriptionResult,
+};
 
 #[cfg(target_os = "linux")]
 use crate::shortcuts::RdevGrabGuard;
 
-/// Cached transcription configuration (provider + API key + language)
+/// Cached transcription configuration (provider + API key + language + vocabulary)
 pub struct TranscriptionConfig {
     pub provider: TranscriptionProvider,
     pub api_key: String,
     pub language: Option<String>,
+    pub vocabulary: Vec<String>,
+    /// Model override for a cloud provider (None = the provider's default)
+    pub model: Option<String>,
+    /// Providers tried per chunk when the primary provider fails
+    pub fallbacks: Vec<FallbackProvider>,
+    /// Endpoint for the custom provider (set when it's the primary or a fallback)
+    pub custom_endpoint: Option<CustomEndpointConfig>,
+    /// Translate to English (setting or active preset)
+    pub translate: bool,
+    /// Stream local providers (sliding-window decoding) instead of chunking
+    pub local_streaming: bool,
 }
 
 /// Active model download state (persists across window close/reopen)
@@ -38,7 +52,10 @@ pub struct AppState {
     /// Active model download (if any)
     pub active_download: Mutex<Option<DownloadState>>,
     /// Progressive transcription result receiver (if progressive mode active)
-    pub transcription_rx: Mutex<Option<oneshot::Receiver<Result<String, String>>>>,
+    pub transcription_rx: Mutex<Option<oneshot::Receiver<Result<TranscriptionResult, String>>>>,
+    /// Completes when a continuous dictation session delivered its last utterance
+    /// (None when not in continuous mode)
+    pub continuous_done: Mutex<Option<oneshot::Receiver<()>>>,
     /// JoinHandle for pending idle model unload task (if any)
     /// Used to cancel the unload when a new recording starts
     pub idle_unload_handle: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
@@ -63,6 +80,7 @@ impl AppState {
             tray_available: Mutex::new(tray_available),
             active_download: Mutex::new(None),
             transcription_rx: Mutex::new(None),
+            continuous_done: Mutex::new(None),
             idle_unload_handle: Mutex::new(None),
             #[cfg(target_os = "linux")]
             rdev_guard: Mutex::new(None),
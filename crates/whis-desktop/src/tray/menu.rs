@@ -10,6 +10,15 @@ use crate::state::{AppState, RecordingState};
 use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
 use tauri::{AppHandle, Manager};
 
+/// Point at a silent microphone while recording
+///
+/// The tooltip goes back to normal on the next `update_tray`.
+pub fn show_silent_mic_warning(app: &AppHandle) {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let _ = tray.set_tooltip(Some("Whis - Microphone appears silent (muted?)"));
+    }
+}
+
 /// Update tray menu and icon for new recording state
 pub fn update_tray(app: &AppHandle, new_state: RecordingState) {
     // Rebuild menu on macOS (workaround for menu item updates not reflecting)
@@ -1,4 +1,4 @@
-import type { AutotypeBackend, AutotypeToolStatus, BackendInfo, CliShortcutMode, OutputMethod, PostProcessor, Provider, Settings, ShortcutPathMismatch } from '../types'
+import type { AutotypeBackend, AutotypeToolStatus, BackendInfo, CliShortcutMode, CustomEndpointConfig, OutputMethod, PostProcessor, Provider, Settings, ShortcutPathMismatch, UploadFormat, VadEngine } from '../types'
 import { invoke } from '@tauri-apps/api/core'
 import { nextTick, reactive, readonly, watch } from 'vue'
 
@@ -11,6 +11,13 @@ interface Defaults {
   desktop_key: string
   vad_enabled: boolean
   vad_threshold: number
+  vad_prefill_frames: number
+  vad_onset_frames: number
+  vad_hangover_frames: number
+  denoise_enabled: boolean
+  denoise_strength: number
+  auto_stop_enabled: boolean
+  auto_stop_silence_secs: number
 }
 
 // Debounce utility with cancel support
@@ -46,6 +53,13 @@ let defaults: Defaults = {
   desktop_key: 'Ctrl+Alt+W',
   vad_enabled: false,
   vad_threshold: 0.5,
+  vad_prefill_frames: 15,
+  vad_onset_frames: 2,
+  vad_hangover_frames: 15,
+  denoise_enabled: false,
+  denoise_strength: 1.0,
+  auto_stop_enabled: false,
+  auto_stop_silence_secs: 1.5,
 }
 
 // Get default settings using cached defaults
@@ -55,10 +69,23 @@ function getDefaultSettings(): Settings {
       provider: defaults.provider,
       language: null,
       api_keys: {},
+      models: {},
       local_models: {
         whisper_path: null,
         parakeet_path: null,
+        streaming: false,
       },
+      custom_endpoint: {
+        base_url: null,
+        model: null,
+        auth: 'bearer',
+        auth_header: null,
+        timestamps: false,
+      },
+      vocabulary: [],
+      fallback_providers: [],
+      translate: false,
+      upload_format: null,
     },
     post_processing: {
       enabled: false,
@@ -82,12 +109,30 @@ function getDefaultSettings(): Settings {
       clipboard_backend: 'auto',
       microphone_device: null,
       chunk_duration_secs: 90,
+      chunk_concurrency: 3,
       output_method: 'clipboard' as OutputMethod,
       autotype_backend: 'auto' as AutotypeBackend,
       autotype_delay_ms: null,
       vad: {
         enabled: defaults.vad_enabled,
+        engine: 'auto' as VadEngine,
         threshold: defaults.vad_threshold,
+        prefill_frames: defaults.vad_prefill_frames,
+        onset_frames: defaults.vad_onset_frames,
+        hangover_frames: defaults.vad_hangover_frames,
+      },
+      denoise: {
+        enabled: defaults.denoise_enabled,
+        strength: defaults.denoise_strength,
+      },
+      auto_stop: {
+        enabled: defaults.auto_stop_enabled,
+        silence_secs: defaults.auto_stop_silence_secs,
+        no_speech_timeout_secs: null,
+      },
+      continuous: {
+        enabled: false,
+        output_file: null,
       },
       active_preset: null,
       bubble: {
@@ -190,10 +235,23 @@ async function load() {
       provider: settings.transcription.provider || defaults.provider,
       language: settings.transcription.language,
       api_keys: settings.transcription.api_keys || {},
+      models: settings.transcription.models ?? {},
       local_models: {
         whisper_path: settings.transcription.local_models.whisper_path,
         parakeet_path: settings.transcription.local_models.parakeet_path,
+        streaming: settings.transcription.local_models.streaming ?? false,
+      },
+      custom_endpoint: {
+        base_url: settings.transcription.custom_endpoint?.base_url ?? null,
+        model: settings.transcription.custom_endpoint?.model ?? null,
+        auth: settings.transcription.custom_endpoint?.auth ?? 'bearer',
+        auth_header: settings.transcription.custom_endpoint?.auth_header ?? null,
+        timestamps: settings.transcription.custom_endpoint?.timestamps ?? false,
       },
+      vocabulary: settings.transcription.vocabulary ?? [],
+      fallback_providers: settings.transcription.fallback_providers ?? [],
+      translate: settings.transcription.translate ?? false,
+      upload_format: settings.transcription.upload_format ?? null,
     }
     state.post_processing = {
       enabled: settings.post_processing.enabled ?? false,
@@ -217,12 +275,30 @@ async function load() {
       clipboard_backend: settings.ui.clipboard_backend,
       microphone_device: settings.ui.microphone_device,
       chunk_duration_secs: Math.max(10, Math.min(300, settings.ui.chunk_duration_secs ?? 90)),
+      chunk_concurrency: Math.max(1, Math.min(8, settings.ui.chunk_concurrency ?? 3)),
       output_method: settings.ui.output_method ?? 'clipboard',
       autotype_backend: settings.ui.autotype_backend ?? 'auto',
       autotype_delay_ms: settings.ui.autotype_delay_ms ?? null,
       vad: {
         enabled: settings.ui.vad.enabled ?? defaults.vad_enabled,
+        engine: settings.ui.vad.engine ?? 'auto',
         threshold: settings.ui.vad.threshold ?? defaults.vad_threshold,
+        prefill_frames: settings.ui.vad.prefill_frames ?? defaults.vad_prefill_frames,
+        onset_frames: settings.ui.vad.onset_frames ?? defaults.vad_onset_frames,
+        hangover_frames: settings.ui.vad.hangover_frames ?? defaults.vad_hangover_frames,
+      },
+      denoise: {
+        enabled: settings.ui.denoise?.enabled ?? defaults.denoise_enabled,
+        strength: settings.ui.denoise?.strength ?? defaults.denoise_strength,
+      },
+      auto_stop: {
+        enabled: settings.ui.auto_stop?.enabled ?? defaults.auto_stop_enabled,
+        silence_secs: settings.ui.auto_stop?.silence_secs ?? defaults.auto_stop_silence_secs,
+        no_speech_timeout_secs: settings.ui.auto_stop?.no_speech_timeout_secs ?? null,
+      },
+      continuous: {
+        enabled: settings.ui.continuous?.enabled ?? false,
+        output_file: settings.ui.continuous?.output_file ?? null,
       },
       active_preset: settings.ui.active_preset,
       bubble: {
@@ -410,6 +486,14 @@ function setApiKey(provider: string, key: string) {
   state.transcription.api_keys = { ...state.transcription.api_keys, [provider]: key }
 }
 
+// Set a provider's model override (null clears it, falling back to the default)
+function setModel(provider: string, model: string | null) {
+  const models = Object.fromEntries(
+    Object.entries(state.transcription.models).filter(([key]) => key !== provider),
+  )
+  state.transcription.models = model ? { ...models, [provider]: model } : models
+}
+
 function setWhisperModelPath(value: string | null) {
   state.transcription.local_models.whisper_path = value
 }
@@ -418,6 +502,30 @@ function setParakeetModelPath(value: string | null) {
   state.transcription.local_models.parakeet_path = value
 }
 
+function setLocalStreaming(value: boolean) {
+  state.transcription.local_models.streaming = value
+}
+
+function setCustomEndpoint(value: Partial<CustomEndpointConfig>) {
+  state.transcription.custom_endpoint = { ...state.transcription.custom_endpoint, ...value }
+}
+
+function setVocabulary(value: string[]) {
+  state.transcription.vocabulary = value
+}
+
+function setFallbackProviders(value: Provider[]) {
+  state.transcription.fallback_providers = value
+}
+
+function setTranslate(value: boolean) {
+  state.transcription.translate = value
+}
+
+function setUploadFormat(value: UploadFormat | null) {
+  state.transcription.upload_format = value
+}
+
 function setPostProcessor(value: PostProcessor) {
   state.post_processing.processor = value
 }
@@ -446,6 +554,56 @@ function setMicrophoneDevice(value: string | null) {
   state.ui.microphone_device = value
 }
 
+function setVadEnabled(value: boolean) {
+  state.ui.vad.enabled = value
+}
+
+function setVadEngine(value: VadEngine) {
+  state.ui.vad.engine = value
+}
+
+function setVadThreshold(value: number) {
+  // Clamp to valid range (0 = everything is speech, 1 = nothing is)
+  state.ui.vad.threshold = Math.max(0, Math.min(1, value))
+}
+
+function setVadPrefill(value: number) {
+  // Clamp to valid range (0-60 frames of 32ms)
+  state.ui.vad.prefill_frames = Math.max(0, Math.min(60, Math.round(value)))
+}
+
+function setVadOnset(value: number) {
+  // Clamp to valid range (1-10 frames of 32ms)
+  state.ui.vad.onset_frames = Math.max(1, Math.min(10, Math.round(value)))
+}
+
+function setVadHangover(value: number) {
+  // Clamp to valid range (0-60 frames of 32ms)
+  state.ui.vad.hangover_frames = Math.max(0, Math.min(60, Math.round(value)))
+}
+
+function setDenoiseEnabled(value: boolean) {
+  state.ui.denoise.enabled = value
+}
+
+function setDenoiseStrength(value: number) {
+  // Clamp to valid range (0 = original audio, 1 = fully denoised)
+  state.ui.denoise.strength = Math.max(0, Math.min(1, value))
+}
+
+function setAutoStopEnabled(value: boolean) {
+  state.ui.auto_stop.enabled = value
+}
+
+function setAutoStopSilence(value: number) {
+  // Clamp to valid range (0.5-10 seconds)
+  state.ui.auto_stop.silence_secs = Math.max(0.5, Math.min(10, value))
+}
+
+function setAutoStopTimeout(value: number | null) {
+  state.ui.auto_stop.no_speech_timeout_secs = value && value > 0 ? value : null
+}
+
 function setBubbleEnabled(value: boolean) {
   state.ui.bubble.enabled = value
 }
@@ -455,6 +613,11 @@ function setChunkDuration(value: number) {
   state.ui.chunk_duration_secs = Math.max(10, Math.min(300, value))
 }
 
+function setChunkConcurrency(value: number) {
+  // Clamp to valid range (1-8 chunks in flight)
+  state.ui.chunk_concurrency = Math.max(1, Math.min(8, value))
+}
+
 function setKeepModelLoaded(value: boolean) {
   state.ui.model_memory.keep_model_loaded = value
 }
@@ -471,6 +634,14 @@ function setOutputMethod(value: OutputMethod) {
   state.ui.output_method = value
 }
 
+function setContinuousEnabled(value: boolean) {
+  state.ui.continuous.enabled = value
+}
+
+function setContinuousOutputFile(value: string | null) {
+  state.ui.continuous.output_file = value
+}
+
 // Post-processing orchestration methods
 function enablePostProcessing() {
   state.post_processing.enabled = true
@@ -564,8 +735,15 @@ export const settingsStore = {
   setProvider,
   setLanguage,
   setApiKey,
+  setModel,
   setWhisperModelPath,
   setParakeetModelPath,
+  setLocalStreaming,
+  setCustomEndpoint,
+  setVocabulary,
+  setFallbackProviders,
+  setTranslate,
+  setUploadFormat,
   setPostProcessor,
   setOllamaUrl,
   setOllamaModel,
@@ -573,8 +751,22 @@ export const settingsStore = {
   setDesktopKey,
   setPortalShortcut,
   setMicrophoneDevice,
+  setVadEnabled,
+  setVadEngine,
+  setVadThreshold,
+  setVadPrefill,
+  setVadOnset,
+  setVadHangover,
+  setDenoiseEnabled,
+  setDenoiseStrength,
+  setAutoStopEnabled,
+  setAutoStopSilence,
+  setAutoStopTimeout,
+  setContinuousEnabled,
+  setContinuousOutputFile,
   setBubbleEnabled,
   setChunkDuration,
+  setChunkConcurrency,
   setKeepModelLoaded,
   setUnloadAfterMinutes,
   setOllamaKeepAlive,
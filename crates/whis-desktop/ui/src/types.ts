@@ -14,6 +14,7 @@ export type Provider
     | 'deepgram'
     | 'deepgram-realtime'
     | 'elevenlabs'
+    | 'custom'
     | 'local-whisper'
     | 'local-parakeet'
 
@@ -23,19 +24,44 @@ export type TranscriptionMethod = 'standard' | 'streaming'
 // Text post-processing providers
 export type PostProcessor = 'none' | 'openai' | 'mistral' | 'ollama'
 
+// Auth header style for custom OpenAI-compatible endpoints
+export type CustomAuthStyle = 'bearer' | 'header' | 'none'
+
+// Custom OpenAI-compatible endpoint configuration
+export interface CustomEndpointConfig {
+  base_url: string | null
+  model: string | null
+  auth: CustomAuthStyle
+  auth_header: string | null
+  timestamps: boolean
+}
+
 // CLI shortcut mode
 export type CliShortcutMode = 'system' | 'direct'
 
+// Audio format for cloud uploads (null = pick per provider)
+export type UploadFormat = 'mp3' | 'opus' | 'flac'
+
+// Speech detector (auto = Silero when available, else energy)
+export type VadEngine = 'auto' | 'silero' | 'energy'
+
 // All settings from the backend (nested structure)
 export interface Settings {
   transcription: {
     provider: Provider
     language: string | null
     api_keys: Record<string, string>
+    models: Record<string, string>
     local_models: {
       whisper_path: string | null
       parakeet_path: string | null
+      streaming: boolean
     }
+    custom_endpoint: CustomEndpointConfig
+    vocabulary: string[]
+    fallback_providers: Provider[]
+    translate: boolean
+    upload_format: UploadFormat | null
   }
   post_processing: {
     enabled: boolean
@@ -59,12 +85,30 @@ export interface Settings {
     clipboard_backend: string
     microphone_device: string | null
     chunk_duration_secs: number
+    chunk_concurrency: number
     output_method: OutputMethod
     autotype_backend: AutotypeBackend
     autotype_delay_ms: number | null
     vad: {
       enabled: boolean
+      engine: VadEngine
       threshold: number
+      prefill_frames: number
+      onset_frames: number
+      hangover_frames: number
+    }
+    denoise: {
+      enabled: boolean
+      strength: number
+    }
+    auto_stop: {
+      enabled: boolean
+      silence_secs: number
+      no_speech_timeout_secs: number | null
+    }
+    continuous: {
+      enabled: boolean
+      output_file: string | null
     }
     active_preset: string | null
     bubble: {
@@ -111,6 +155,42 @@ export interface StatusResponse {
   config_valid: boolean
 }
 
+// Input level while recording ('audio-level')
+export interface AudioLevel {
+  rms: number
+  peak: number
+  silent_secs: number
+}
+
+// Live transcript event from realtime providers ('live-transcript')
+export interface LiveTranscriptEvent {
+  kind: 'partial' | 'final'
+  text: string
+}
+
+// Model choices for a cloud provider (from get_cloud_providers)
+export interface ProviderModels {
+  provider: string
+  default_model: string
+  known: string[]
+}
+
+// Cloud provider option (from get_cloud_providers)
+export interface CloudProviderOption {
+  value: string
+  label: string
+  models: ProviderModels | null
+  realtime_models: ProviderModels | null
+}
+
+// Payload of the transcription-complete event
+export interface TranscriptionCompleteEvent {
+  text: string
+  detected_language: string | null
+  language_confidence: number | null
+  confidence: number | null
+}
+
 // Response when saving settings
 export interface SaveSettingsResponse {
   needs_restart: boolean
@@ -147,9 +227,20 @@ export interface PresetDetails {
   prompt: string
   post_processor: string | null
   model: string | null
+  vocabulary: string[]
+  translate: boolean
+  vad: PresetVad | null
   is_builtin: boolean
 }
 
+// VAD tuning overrides of a preset (null = use the VAD settings)
+export interface PresetVad {
+  threshold?: number | null
+  prefill_frames?: number | null
+  onset_frames?: number | null
+  hangover_frames?: number | null
+}
+
 // Cloud provider configuration
 export interface CloudProviderInfo {
   value: Provider
@@ -146,6 +146,9 @@ pub fn create_preset(
         prompt: input.prompt,
         post_processor: None,
         model: None,
+        vocabulary: Vec::new(),
+        translate: false,
+        vad: None,
     };
 
     preset.save_to(&presets_dir)?;
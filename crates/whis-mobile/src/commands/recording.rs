@@ -97,6 +97,11 @@ async fn transcribe_audio_inner(
         language: language.clone(),
         filename: filename.to_string(),
         mime_type: mime_type.clone(),
+        diarize: false,
+        vocabulary: Vec::new(),
+        translate: false,
+        model: None,
+        custom_endpoint: None,
         progress: None,
     };
 
@@ -180,10 +185,11 @@ pub async fn transcribe_streaming_start(
         // Dispatch to correct streaming provider
         let result = match provider_for_task.as_str() {
             "openai" | "openai-realtime" => {
-                OpenAIRealtimeProvider::transcribe_stream(&api_key, audio_rx, language).await
+                OpenAIRealtimeProvider::transcribe_stream(&api_key, audio_rx, language, None).await
             }
             "deepgram" | "deepgram-realtime" => {
-                DeepgramRealtimeProvider::transcribe_stream(&api_key, audio_rx, language).await
+                DeepgramRealtimeProvider::transcribe_stream(&api_key, audio_rx, language, None)
+                    .await
             }
             _ => Err(anyhow::anyhow!(
                 "Streaming not supported for {}",
@@ -283,13 +289,18 @@ pub async fn transcribe_streaming_stop(state: State<'_, AppState>) -> Result<(),
 // ========== Progressive Transcription ==========
 //
 // Progressive transcription matches the CLI/desktop architecture:
-// - Audio samples are chunked every ~90 seconds
+// - Audio samples are chunked every ~90 seconds, cut at pauses found by the
+//   energy VAD (pure Rust, no ONNX Runtime needed on mobile)
 // - Chunks are transcribed in parallel (cloud providers)
 // - Results are combined when recording stops
 
 use crate::recording::config::load_transcription_config;
-use tokio::sync::{mpsc, oneshot};
-use whis_core::{ChunkerConfig, ProgressiveChunker, progressive_transcribe_cloud};
+use tokio::sync::{mpsc, oneshot, watch};
+use whis_core::audio::{VadProcessor, VadState};
+use whis_core::{
+    ChunkerConfig, CloudTranscriptionOptions, ProgressiveChunker, VadConfig, VadEngine,
+    progressive_transcribe_cloud,
+};
 
 /// Default chunk duration in seconds for progressive transcription.
 const DEFAULT_CHUNK_DURATION_SECS: u64 = 90;
@@ -322,6 +333,14 @@ pub async fn start_recording(
         *recording_state = RecordingState::Recording;
     }
 
+    // Energy VAD in detect-only mode: all audio is kept, the chunker just
+    // learns where the pauses are
+    let vad = VadProcessor::monitor(&VadConfig {
+        engine: VadEngine::Energy,
+        ..VadConfig::enabled_with_threshold(whis_core::DEFAULT_VAD_THRESHOLD)
+    })
+    .map_err(|e| e.to_string())?;
+
     // Create unbounded channel for audio samples from frontend
     let (audio_tx, audio_rx) = mpsc::unbounded_channel::<Vec<f32>>();
 
@@ -334,20 +353,25 @@ pub async fn start_recording(
     // Create channel for chunker output
     let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
 
-    // Create chunker config (no VAD on mobile)
+    // Run the frontend's 16kHz samples through the VAD on their way to the chunker
+    let (audio_rx, vad_state_rx) = spawn_vad(vad, audio_rx);
+
+    // Create chunker config (cuts at VAD pauses between min and max duration)
     let chunker_config = ChunkerConfig {
         target_duration_secs: DEFAULT_CHUNK_DURATION_SECS,
         min_duration_secs: DEFAULT_CHUNK_DURATION_SECS * 2 / 3,
         max_duration_secs: DEFAULT_CHUNK_DURATION_SECS * 4 / 3,
-        vad_aware: false, // No VAD on mobile
+        vad_aware: true,
     };
 
     // Spawn chunker task with error handling
     // When chunker fails, it emits error event and drops chunk_tx, closing the channel
-    let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
+    // Chunks are encoded while they're recorded, ready to upload when cut
+    let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx)
+        .with_streaming_encoder(whis_core::audio::upload_format_for(&provider, None));
     let chunker_app = app.clone();
     tokio::spawn(async move {
-        if let Err(e) = chunker.consume_stream(audio_rx, None).await {
+        if let Err(e) = chunker.consume_stream(audio_rx, Some(vad_state_rx)).await {
             // Log error - chunker failures are critical but rare
             // The error event notifies the frontend immediately
             // Dropping chunk_tx (on task exit) will close the channel and let
@@ -366,10 +390,15 @@ pub async fn start_recording(
     // Spawn transcription task
     // This task will complete when chunk_rx closes (either chunker finishes or fails)
     tokio::spawn(async move {
-        let result =
-            progressive_transcribe_cloud(&provider, &api_key, language.as_deref(), chunk_rx, None)
-                .await
-                .map_err(|e| e.to_string());
+        let options = CloudTranscriptionOptions {
+            language,
+            concurrency: whis_core::defaults::DEFAULT_CHUNK_CONCURRENCY,
+            ..Default::default()
+        };
+        let result = progressive_transcribe_cloud(&provider, &api_key, options, chunk_rx, None)
+            .await
+            .map(|result| result.text)
+            .map_err(|e| e.to_string());
 
         if result_tx.send(result).is_err() {
             warn!("Failed to send transcription result - receiver dropped");
@@ -388,6 +417,33 @@ pub async fn start_recording(
     Ok(())
 }
 
+/// Run incoming samples through `vad` and forward them unchanged.
+///
+/// Returns the forwarded samples and a watch of the VAD state, updated before
+/// the samples it describes are sent on.
+fn spawn_vad(
+    mut vad: VadProcessor,
+    mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+) -> (mpsc::UnboundedReceiver<Vec<f32>>, watch::Receiver<VadState>) {
+    let (samples_tx, samples_rx) = mpsc::unbounded_channel();
+    let (state_tx, state_rx) = watch::channel(vad.state());
+    tokio::spawn(async move {
+        while let Some(samples) = audio_rx.recv().await {
+            let samples = vad.process(&samples);
+            let state = vad.state();
+            state_tx.send_if_modified(|current| {
+                let changed = *current != state;
+                *current = state;
+                changed
+            });
+            if samples_tx.send(samples).is_err() {
+                break;
+            }
+        }
+    });
+    (samples_rx, state_rx)
+}
+
 /// Send audio samples to the progressive transcription pipeline.
 ///
 /// Frontend should call this repeatedly with audio samples from Web Audio API.
@@ -18,7 +18,8 @@
 //! ## Feature Differences from Desktop
 //!
 //! - **No local transcription** - Mobile uses cloud providers only
-//! - **No VAD** - Voice Activity Detection requires ONNX Runtime (no Android binaries)
+//! - **Energy VAD only** - Silero needs ONNX Runtime (no Android binaries), so
+//!   chunks are cut at pauses found by the pure-Rust energy detector
 //! - **No system tray** - Mobile has different navigation paradigm
 //! - **Tauri store** - Uses Tauri plugin for settings (not whis-core::Settings)
 